@@ -0,0 +1,26 @@
+use na::{Isometry2, Point2, Vector2};
+use parry2d::query::{KeyframeMotion, RigidMotion};
+
+#[test]
+fn keyframe_motion_clockwise_velocity_bounds() {
+    // A clockwise rotation by one radian per unit of time.
+    let motion = KeyframeMotion::new(vec![
+        (0.0f32, Isometry2::identity()),
+        (1.0, Isometry2::new(Vector2::new(1.0, 0.0), -1.0)),
+    ]);
+
+    let (linvel, angvel) = motion.velocity_bounds(0.0, 1.0);
+    assert!((linvel - 1.0).abs() < 1.0e-5);
+    assert!((angvel - 1.0).abs() < 1.0e-5);
+
+    // The velocity of a point at a distance 2 from the local origin is bounded.
+    let local_pt = Point2::new(2.0, 0.0);
+    let h = 1.0e-3;
+    for i in 1..10 {
+        let t = i as f32 * 0.1;
+        let vel = (motion.position_at_time(t + h) * local_pt
+            - motion.position_at_time(t - h) * local_pt)
+            / (2.0 * h);
+        assert!(vel.norm() <= linvel + angvel * 2.0 + 1.0e-2);
+    }
+}
@@ -1,5 +1,7 @@
 mod ball_ball_toi;
 mod ball_cuboid_contact;
 mod epa2;
+mod keyframe_motion2;
 mod ray_cast;
+mod scaled_mass_properties2;
 mod time_of_impact2;
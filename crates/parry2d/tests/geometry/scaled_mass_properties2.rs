@@ -0,0 +1,38 @@
+use na::{Point2, Vector2};
+use parry2d::mass_properties::MassProperties;
+use parry2d::shape::{Scaled, Shape, Triangle};
+
+// A triangle far from being symmetric, with its center of mass away from the origin.
+fn triangle() -> Triangle<f32> {
+    Triangle::new(
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(0.0, 1.0),
+    )
+}
+
+fn assert_mass_properties_eq(mprops: MassProperties<f32>, expected: MassProperties<f32>) {
+    assert!((mprops.mass() - expected.mass()).abs() < 1.0e-4 * expected.mass());
+    assert!((mprops.local_com - expected.local_com).norm() < 1.0e-4);
+    assert!(
+        (mprops.principal_inertia() - expected.principal_inertia()).abs()
+            < 1.0e-3 * expected.principal_inertia()
+    );
+}
+
+#[test]
+fn scaled_triangle_mass_properties() {
+    for scale in [Vector2::new(2.0, 0.5), Vector2::new(-2.0, 2.0)] {
+        let tri = triangle();
+        let scaled_vertices = [
+            tri.a.coords.component_mul(&scale).into(),
+            tri.b.coords.component_mul(&scale).into(),
+            tri.c.coords.component_mul(&scale).into(),
+        ];
+        let scaled_tri = Triangle::new(scaled_vertices[0], scaled_vertices[1], scaled_vertices[2]);
+        let expected = scaled_tri.mass_properties(2.0);
+
+        let scaled = Scaled::new(tri, scale);
+        assert_mass_properties_eq(scaled.mass_properties(2.0), expected);
+    }
+}
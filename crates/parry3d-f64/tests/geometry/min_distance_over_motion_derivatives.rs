@@ -0,0 +1,74 @@
+use ad_trait::forward_ad::adfn::adfn;
+use ad_trait::AD;
+use na::{Isometry3, Point3, Vector3};
+use parry3d_f64::query::{self, NonlinearRigidMotion};
+use parry3d_f64::shape::Ball;
+
+// The parameters are the lateral offset of the second ball, the radius of both balls, and the
+// vertical velocity of the first ball.
+fn min_distance<T: AD>(p: [T; 3]) -> (T, T) {
+    let ball = Ball::new(p[1]);
+    let motion1 = NonlinearRigidMotion::new(
+        Isometry3::translation(T::constant(-5.0), T::zero(), T::zero()),
+        Point3::origin(),
+        Vector3::new(T::constant(10.0), T::zero(), p[2]),
+        Vector3::zeros(),
+    );
+    let motion2 =
+        NonlinearRigidMotion::constant_position(Isometry3::translation(T::zero(), p[0], T::zero()));
+
+    let result = query::min_distance_over_motion(
+        &motion1,
+        &ball,
+        &motion2,
+        &ball,
+        T::zero(),
+        T::one(),
+        T::constant(1.0e-6),
+    )
+    .unwrap()
+    .unwrap();
+    (result.time, result.distance)
+}
+
+// The closed-form minimum of the distance between the ball centers, at
+// `(10 * t - 5, -y, vz * t)`, minus both radii.
+fn expected_min_distance(p: [f64; 3]) -> (f64, f64) {
+    let (y, radius, vz) = (p[0], p[1], p[2]);
+    let time = 50.0 / (100.0 + vz * vz);
+    let center_dist = (y * y + 25.0 * vz * vz / (100.0 + vz * vz)).sqrt();
+    (time, center_dist - 2.0 * radius)
+}
+
+#[test]
+fn min_distance_over_motion_derivatives() {
+    let params = [2.0, 0.5, 1.0];
+    let seeded: [adfn<3>; 3] = std::array::from_fn(|i| {
+        let mut tangent = [0.0; 3];
+        tangent[i] = 1.0;
+        adfn::new(params[i], tangent)
+    });
+    let (time, distance) = min_distance(seeded);
+    let (expected_time, expected_distance) = expected_min_distance(params);
+    assert!((time.value() - expected_time).abs() < 1.0e-3);
+    assert!((distance.value() - expected_distance).abs() < 1.0e-5);
+
+    // The distance is minimal at `time`, so its derivatives there are the derivatives of
+    // the minimum.
+    let step = 1.0e-6;
+
+    for i in 0..3 {
+        let (mut params1, mut params2) = (params, params);
+        params1[i] += step;
+        params2[i] -= step;
+        let ddist =
+            (expected_min_distance(params1).1 - expected_min_distance(params2).1) / (2.0 * step);
+        assert!(
+            (distance.tangent()[i] - ddist).abs() < 1.0e-3,
+            "distance derivative {}: {} != {}",
+            i,
+            distance.tangent()[i],
+            ddist
+        );
+    }
+}
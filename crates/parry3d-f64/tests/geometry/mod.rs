@@ -0,0 +1,2 @@
+mod min_distance_over_motion_derivatives;
+mod ray_cast_derivatives;
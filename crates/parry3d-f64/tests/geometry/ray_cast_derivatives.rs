@@ -0,0 +1,161 @@
+use ad_trait::forward_ad::adfn::adfn;
+use ad_trait::AD;
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use parry3d_f64::query::{Ray, RayCast};
+use parry3d_f64::shape::{Compound, Cylinder, HeightField, SharedShape, Triangle};
+
+/// Checks the time of impact and normal computed by `f_ad`, and their derivatives, against
+/// the reference `f` and its central finite differences.
+fn assert_derivatives<const N: usize>(
+    f_ad: fn([adfn<N>; N]) -> (adfn<N>, Vector3<adfn<N>>),
+    f: fn([f64; N]) -> (f64, Vector3<f64>),
+    params: [f64; N],
+    tolerance: f64,
+) {
+    let seeded = std::array::from_fn(|i| {
+        let mut tangent = [0.0; N];
+        tangent[i] = 1.0;
+        adfn::new(params[i], tangent)
+    });
+    let (toi, normal) = f_ad(seeded);
+    let (expected_toi, expected_normal) = f(params);
+    assert!((toi.value() - expected_toi).abs() < tolerance);
+    assert!((normal.map(|e| e.value()) - expected_normal).norm() < tolerance);
+
+    let step = 1.0e-6;
+
+    for i in 0..N {
+        let (mut params1, mut params2) = (params, params);
+        params1[i] += step;
+        params2[i] -= step;
+        let (toi1, normal1) = f(params1);
+        let (toi2, normal2) = f(params2);
+
+        let dtoi = (toi1 - toi2) / (2.0 * step);
+        let dnormal = (normal1 - normal2) / (2.0 * step);
+
+        assert!(
+            (toi.tangent()[i] - dtoi).abs() < tolerance,
+            "toi derivative {}: {} != {}",
+            i,
+            toi.tangent()[i],
+            dtoi
+        );
+
+        for k in 0..3 {
+            assert!(
+                (normal[k].tangent()[i] - dnormal[k]).abs() < tolerance,
+                "normal derivative {}, {}: {} != {}",
+                i,
+                k,
+                normal[k].tangent()[i],
+                dnormal[k]
+            );
+        }
+    }
+}
+
+// The parameters are the cylinder radius, and the x and y coordinates of the ray origin.
+fn cast_on_cylinder<T: AD>(p: [T; 3]) -> (T, Vector3<T>) {
+    let cylinder = Cylinder::new(T::constant(1.0), p[0]);
+    let ray = Ray::new(Point3::new(p[1], p[2], T::constant(-5.0)), Vector3::z());
+    let hit = cylinder
+        .cast_local_ray_and_get_normal(&ray, T::constant(f64::MAX), true)
+        .unwrap();
+    (hit.toi, hit.normal)
+}
+
+// The ray hits the side of the cylinder, so the exact result is known in closed-form.
+fn cylinder_side_hit(p: [f64; 3]) -> (f64, Vector3<f64>) {
+    let (radius, x) = (p[0], p[1]);
+    let depth = (radius * radius - x * x).sqrt();
+    (5.0 - depth, Vector3::new(x, 0.0, -depth) / radius)
+}
+
+#[test]
+fn support_map_ray_cast_derivatives() {
+    assert_derivatives(
+        cast_on_cylinder::<adfn<3>>,
+        cylinder_side_hit,
+        [1.0, 0.3, 0.2],
+        1.0e-3,
+    );
+}
+
+// The parameters are the height of the third vertex, and the direction of the ray.
+fn cast_on_triangle<T: AD>(p: [T; 3]) -> (T, Vector3<T>) {
+    let triangle = Triangle::new(
+        Point3::new(T::constant(-1.0), T::zero(), T::constant(-1.0)),
+        Point3::new(T::constant(1.0), T::zero(), T::constant(-1.0)),
+        Point3::new(T::zero(), p[0], T::constant(1.0)),
+    );
+    let ray = Ray::new(
+        Point3::new(T::zero(), T::constant(3.0), T::zero()),
+        Vector3::new(p[1], T::constant(-1.0), p[2]),
+    );
+    let hit = triangle
+        .cast_local_ray_and_get_normal(&ray, T::constant(f64::MAX), true)
+        .unwrap();
+    (hit.toi, hit.normal)
+}
+
+#[test]
+fn triangle_ray_cast_derivatives() {
+    assert_derivatives(
+        cast_on_triangle::<adfn<3>>,
+        cast_on_triangle::<f64>,
+        [0.5, 0.1, -0.2],
+        1.0e-6,
+    );
+}
+
+// The parameters are the height of one heightfield vertex, and the origin of the ray.
+fn cast_on_heightfield<T: AD>(p: [T; 3]) -> (T, Vector3<T>) {
+    let mut heights = DMatrix::repeat(3, 3, T::zero());
+    heights[(1, 1)] = p[0];
+    let heightfield = HeightField::new(heights, Vector3::repeat(T::constant(2.0)));
+    let ray = Ray::new(Point3::new(p[1], T::constant(5.0), p[2]), -Vector3::y());
+    let hit = heightfield
+        .cast_local_ray_and_get_normal(&ray, T::constant(f64::MAX), true)
+        .unwrap();
+    (hit.toi, hit.normal)
+}
+
+#[test]
+fn heightfield_ray_cast_derivatives() {
+    assert_derivatives(
+        cast_on_heightfield::<adfn<3>>,
+        cast_on_heightfield::<f64>,
+        [0.4, 0.2, 0.3],
+        1.0e-6,
+    );
+}
+
+// The parameters are the position of the cylinder part, and the radius of the cylinder.
+fn cast_on_compound<T: AD>(p: [T; 3]) -> (T, Vector3<T>) {
+    let cylinder = Cylinder::new(T::constant(1.0), p[2]);
+    let compound = Compound::new(vec![(
+        Isometry3::translation(p[0], p[1], T::zero()),
+        SharedShape::new(cylinder),
+    )]);
+    let ray = Ray::new(
+        Point3::new(T::constant(0.3), T::zero(), T::constant(-5.0)),
+        Vector3::z(),
+    );
+    let hit = compound
+        .cast_local_ray_and_get_normal(&ray, T::constant(f64::MAX), true)
+        .unwrap();
+    (hit.toi, hit.normal)
+}
+
+#[test]
+fn composite_shape_ray_cast_derivatives() {
+    let compound_side_hit = |p: [f64; 3]| cylinder_side_hit([p[2], 0.3 - p[0], 0.0]);
+
+    assert_derivatives(
+        cast_on_compound::<adfn<3>>,
+        compound_side_hit,
+        [0.1, 0.2, 1.0],
+        1.0e-3,
+    );
+}
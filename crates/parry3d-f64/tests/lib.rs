@@ -0,0 +1,4 @@
+extern crate nalgebra as na;
+extern crate parry3d_f64;
+
+mod geometry;
@@ -0,0 +1,90 @@
+use na::Point3;
+use parry3d::bounding_volume::Aabb;
+use parry3d::partitioning::{BroadPhase, BroadPhaseBvh, BroadPhasePairEvent, BroadPhaseSap};
+
+fn unit_aabb(x: f32) -> Aabb<f32> {
+    Aabb::new(Point3::new(x, 0.0, 0.0), Point3::new(x + 1.0, 1.0, 1.0))
+}
+
+fn check_pair_events(broad_phase: &mut dyn BroadPhase<f32>) {
+    let mut events = vec![];
+
+    // Objects 0 and 1 overlap, object 2 is far away.
+    broad_phase.update(
+        &[
+            (0, unit_aabb(0.0)),
+            (1, unit_aabb(0.5)),
+            (2, unit_aabb(10.0)),
+        ],
+        &[],
+        &mut events,
+    );
+    assert_eq!(events, vec![BroadPhasePairEvent::AddPair(0, 1)]);
+
+    // Moving object 2 within the margin doesn’t change anything.
+    events.clear();
+    broad_phase.update(&[(2, unit_aabb(10.05))], &[], &mut events);
+    assert!(events.is_empty());
+
+    // Object 1 moves from object 0 to object 2.
+    events.clear();
+    broad_phase.update(&[(1, unit_aabb(9.5))], &[], &mut events);
+    events.sort_by_key(|e| format!("{:?}", e));
+    assert_eq!(
+        events,
+        vec![
+            BroadPhasePairEvent::AddPair(1, 2),
+            BroadPhasePairEvent::DeletePair(0, 1),
+        ]
+    );
+
+    // Removing object 2 removes its pair.
+    events.clear();
+    broad_phase.update(&[], &[2], &mut events);
+    assert_eq!(events, vec![BroadPhasePairEvent::DeletePair(1, 2)]);
+    assert!(broad_phase.enlarged_aabb(2).is_none());
+
+    let mut num_pairs = 0;
+    broad_phase.for_each_pair(&mut |_, _| num_pairs += 1);
+    assert_eq!(num_pairs, 0);
+}
+
+#[test]
+fn broad_phase_bvh_pair_events() {
+    check_pair_events(&mut BroadPhaseBvh::new(0.1));
+}
+
+#[test]
+fn broad_phase_sap_pair_events() {
+    check_pair_events(&mut BroadPhaseSap::new(0.1));
+}
+
+fn sorted_pairs(broad_phase: &dyn BroadPhase<f32>) -> Vec<(u32, u32)> {
+    let mut pairs = vec![];
+    broad_phase.for_each_pair(&mut |i, j| pairs.push((i.min(j), i.max(j))));
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn broad_phase_sap_bulk_and_incremental_insertions() {
+    let mut sap = BroadPhaseSap::new(0.1);
+    let mut bvh = BroadPhaseBvh::new(0.1);
+    let mut events = vec![];
+
+    // Many objects at once, in reverse order along the sweep axis.
+    let bulk: Vec<_> = (0..100u32)
+        .map(|i| (i, unit_aabb((100 - i) as f32 * 0.7)))
+        .collect();
+    sap.update(&bulk, &[], &mut events);
+    bvh.update(&bulk, &[], &mut events);
+    assert_eq!(sorted_pairs(&sap), sorted_pairs(&bvh));
+
+    // Then a few objects, one at a time.
+    for i in 100..105u32 {
+        let aabb = [(i, unit_aabb((i - 100) as f32 * 13.3))];
+        sap.update(&aabb, &[], &mut events);
+        bvh.update(&aabb, &[], &mut events);
+        assert_eq!(sorted_pairs(&sap), sorted_pairs(&bvh));
+    }
+}
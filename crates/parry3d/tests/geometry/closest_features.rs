@@ -0,0 +1,34 @@
+use na::{Isometry3, Point3};
+use parry3d::query::{self, ClosestPoints};
+use parry3d::shape::{Ball, FeatureId, TriMesh};
+
+#[test]
+fn ball_trimesh_closest_features() {
+    let vertices = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let mesh = TriMesh::<f32>::new(vertices, vec![[0, 1, 2], [0, 2, 3]]);
+    let ball = Ball::new(0.5);
+
+    // The ball is right above the interior of the second triangle.
+    let ball_pos = Isometry3::translation(0.2, 1.0, 0.7);
+    let (pts, features) =
+        query::closest_points_with_features(&Isometry3::identity(), &mesh, &ball_pos, &ball, 1.0)
+            .unwrap();
+
+    assert!(matches!(pts, ClosestPoints::WithinMargin(..)));
+    let features = features.unwrap();
+    assert_eq!(features.subshape1, Some(1));
+    assert!(matches!(features.fid1, FeatureId::Face(_)));
+    assert_eq!(features.subshape2, None);
+
+    let (_, features) =
+        query::contact_with_features(&ball_pos, &ball, &Isometry3::identity(), &mesh, 1.0)
+            .unwrap()
+            .unwrap();
+    assert_eq!(features.subshape1, None);
+    assert_eq!(features.subshape2, Some(1));
+}
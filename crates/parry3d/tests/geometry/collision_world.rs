@@ -0,0 +1,156 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::pipeline::{CollisionWorld, ContactEvent, InteractionGroups};
+use parry3d::query::Ray;
+use parry3d::shape::{Ball, SharedShape};
+
+#[test]
+fn collision_world_contact_events() {
+    let mut world = CollisionWorld::new(0.1f32, 0.1);
+    let ball = SharedShape::ball(0.5);
+    let h1 = world.insert(
+        ball.clone(),
+        Isometry3::identity(),
+        InteractionGroups::all(),
+        (),
+    );
+    let h2 = world.insert(
+        ball.clone(),
+        Isometry3::translation(0.9, 0.0, 0.0),
+        InteractionGroups::all(),
+        (),
+    );
+    let h3 = world.insert(
+        ball,
+        Isometry3::translation(-0.9, 0.0, 0.0),
+        InteractionGroups::none(),
+        (),
+    );
+
+    world.update();
+    assert_eq!(world.contact_events(), &[ContactEvent::Started(h1, h2)]);
+    assert!(world.contact_pair(h1, h2).unwrap().has_contact());
+    assert!(!world.contact_pair(h1, h3).unwrap().has_contact());
+
+    world.set_position(h2, Isometry3::translation(3.0, 0.0, 0.0));
+    world.update();
+    assert_eq!(world.contact_events(), &[ContactEvent::Stopped(h1, h2)]);
+
+    let _ = world.remove(h1);
+    world.update();
+    assert!(world.contact_events().is_empty());
+    assert_eq!(world.contact_pairs().count(), 0);
+}
+
+#[test]
+fn collision_world_scene_queries() {
+    let mut world = CollisionWorld::new(0.0f32, 0.1);
+    let ball = SharedShape::ball(0.5);
+    let near = world.insert(
+        ball.clone(),
+        Isometry3::translation(2.0, 0.0, 0.0),
+        InteractionGroups::new(0b01, 0b11),
+        (),
+    );
+    let far = world.insert(
+        ball,
+        Isometry3::translation(5.0, 0.0, 0.0),
+        InteractionGroups::new(0b10, 0b11),
+        (),
+    );
+    world.update();
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    let (hit, inter) = world
+        .cast_ray(&ray, 100.0, true, InteractionGroups::all())
+        .unwrap();
+    assert_eq!(hit, near);
+    assert!((inter.toi - 1.5).abs() < 1.0e-5);
+
+    let only_far = InteractionGroups::new(0b10, 0b10);
+    let (hit, _) = world.cast_ray(&ray, 100.0, true, only_far).unwrap();
+    assert_eq!(hit, far);
+
+    let (proj_handle, proj) = world
+        .project_point(&Point3::new(6.0, 0.0, 0.0), true, InteractionGroups::all())
+        .unwrap();
+    assert_eq!(proj_handle, far);
+    assert!((proj.point - Point3::new(5.5, 0.0, 0.0)).norm() < 1.0e-5);
+
+    let probe = Ball::new(1.0);
+    let hits = world.intersections_with_shape(
+        &Isometry3::translation(4.0, 0.0, 0.0),
+        &probe,
+        InteractionGroups::all(),
+    );
+    assert_eq!(hits, vec![far]);
+
+    let (hit, toi) = world
+        .cast_shape(
+            &Isometry3::identity(),
+            &Vector3::x(),
+            &probe,
+            10.0,
+            true,
+            InteractionGroups::all(),
+        )
+        .unwrap();
+    assert_eq!(hit, near);
+    assert!((toi.toi - 0.5).abs() < 1.0e-4);
+}
+
+#[test]
+fn collision_world_queries_see_moved_objects() {
+    let mut world = CollisionWorld::new(0.0f32, 0.1);
+    let ball = SharedShape::ball(0.5);
+    let moved = world.insert(
+        ball.clone(),
+        Isometry3::translation(2.0, 0.0, 0.0),
+        InteractionGroups::all(),
+        (),
+    );
+    let still = world.insert(
+        ball,
+        Isometry3::translation(0.0, 5.0, 0.0),
+        InteractionGroups::all(),
+        (),
+    );
+    world.update();
+
+    // Move an object far away without updating the world.
+    world.set_position(moved, Isometry3::translation(0.0, -5.0, 0.0));
+
+    let ray = Ray::new(Point3::origin(), Vector3::x());
+    assert!(world
+        .cast_ray(&ray, 100.0, true, InteractionGroups::all())
+        .is_none());
+
+    let ray = Ray::new(Point3::origin(), -Vector3::y());
+    let (hit, inter) = world
+        .cast_ray(&ray, 100.0, true, InteractionGroups::all())
+        .unwrap();
+    assert_eq!(hit, moved);
+    assert!((inter.toi - 4.5).abs() < 1.0e-5);
+
+    let (proj_handle, _) = world
+        .project_point(&Point3::new(0.0, -4.0, 0.0), true, InteractionGroups::all())
+        .unwrap();
+    assert_eq!(proj_handle, moved);
+    let (proj_handle, _) = world
+        .project_point(&Point3::new(0.0, 4.0, 0.0), true, InteractionGroups::all())
+        .unwrap();
+    assert_eq!(proj_handle, still);
+
+    let probe = Ball::new(1.0);
+    let hits = world.intersections_with_shape(
+        &Isometry3::translation(2.0, 0.0, 0.0),
+        &probe,
+        InteractionGroups::all(),
+    );
+    assert!(hits.is_empty());
+    let hits = world.intersections_with_shape(
+        &Isometry3::translation(0.0, -5.0, 0.0),
+        &probe,
+        InteractionGroups::all(),
+    );
+    assert_eq!(hits, vec![moved]);
+}
@@ -0,0 +1,46 @@
+use na::{Isometry3, Vector3};
+use parry3d::query::details::{
+    time_of_impacts_composite_shape_shape, touched_parts_composite_shape_shape,
+};
+use parry3d::query::DefaultQueryDispatcher;
+use parry3d::shape::{Ball, Compound, Cuboid, SharedShape};
+
+#[test]
+fn compound_shape_toi_reports_every_touched_part() {
+    let cube = SharedShape::new(Cuboid::new(Vector3::repeat(0.5f32)));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(2.0, 0.0, 0.0), cube.clone()),
+        (Isometry3::translation(5.0, 0.0, 0.0), cube.clone()),
+        (Isometry3::translation(5.0, 10.0, 0.0), cube),
+    ]);
+    let ball = Ball::new(0.5f32);
+    let pos12 = Isometry3::identity();
+    let vel12 = Vector3::x();
+
+    let hits = time_of_impacts_composite_shape_shape(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &vel12,
+        &compound,
+        &ball,
+        f32::MAX,
+        true,
+    );
+
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].0, 0);
+    assert_eq!(hits[1].0, 1);
+    assert!((hits[0].1.toi - 1.0).abs() < 1.0e-4);
+    assert!((hits[1].1.toi - 4.0).abs() < 1.0e-4);
+
+    let mut touched = touched_parts_composite_shape_shape(
+        &DefaultQueryDispatcher,
+        &pos12,
+        &vel12,
+        &compound,
+        &ball,
+        2.0,
+    );
+    touched.sort();
+    assert_eq!(touched, vec![0]);
+}
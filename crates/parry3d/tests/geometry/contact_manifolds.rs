@@ -0,0 +1,61 @@
+use na::{Isometry3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Compound, Cuboid, SharedShape};
+
+fn two_balls() -> Compound<f32> {
+    Compound::new(vec![
+        (
+            Isometry3::translation(-1.0, 0.0, 0.0),
+            SharedShape::new(Ball::new(0.5)),
+        ),
+        (
+            Isometry3::translation(1.0, 0.0, 0.0),
+            SharedShape::new(Ball::new(0.5)),
+        ),
+    ])
+}
+
+#[test]
+fn contact_manifolds_compound_cuboid() {
+    let compound = two_balls();
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let manifolds = query::contact_manifolds(
+        &Isometry3::identity(),
+        &compound,
+        &Isometry3::translation(0.0, -0.9, 0.0),
+        &cuboid,
+        0.0,
+    )
+    .unwrap();
+
+    assert_eq!(manifolds.len(), 2);
+
+    let mut subshapes: Vec<_> = manifolds.iter().map(|m| m.subshape1).collect();
+    subshapes.sort();
+    assert_eq!(subshapes, vec![0, 1]);
+
+    for manifold in &manifolds {
+        assert!(!manifold.points.is_empty());
+        assert!(manifold.subshape_pos1.is_some());
+
+        for point in &manifold.points {
+            assert!((point.dist + 0.1).abs() < 1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn contact_manifolds_prediction() {
+    let compound = two_balls();
+    let cuboid = Cuboid::new(Vector3::new(2.0, 0.5, 2.0));
+    let pos2 = Isometry3::translation(0.0, -1.2, 0.0);
+
+    // The shapes are 0.2 apart.
+    let manifolds =
+        query::contact_manifolds(&Isometry3::identity(), &compound, &pos2, &cuboid, 0.1).unwrap();
+    assert!(manifolds.is_empty());
+
+    let manifolds =
+        query::contact_manifolds(&Isometry3::identity(), &compound, &pos2, &cuboid, 0.3).unwrap();
+    assert_eq!(manifolds.len(), 2);
+}
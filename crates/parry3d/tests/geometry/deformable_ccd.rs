@@ -0,0 +1,109 @@
+use na::Point3;
+use parry3d::query::deformable_ccd::{
+    point_triangle_time_of_impact, segment_segment_time_of_impact,
+    time_of_impacts_deforming_trimeshes, DeformingTriMesh,
+};
+use parry3d::shape::FeatureId;
+
+#[test]
+fn point_falling_through_moving_triangle() {
+    // The triangle moves up by 1 while the point falls down by 3: they meet at t = 0.25.
+    let a = [
+        Point3::new(-1.0f32, 0.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+    ];
+    let b = [Point3::new(1.0, 0.0, -1.0), Point3::new(1.0, 1.0, -1.0)];
+    let c = [Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 1.0, 1.0)];
+    let pt = [Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, -2.0, 0.0)];
+
+    let toi = point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).unwrap();
+    assert!((toi - 0.25).abs() < 1.0e-3);
+
+    // Moving the point sideways makes it miss the triangle.
+    let pt = [Point3::new(5.0, 1.0, 0.0), Point3::new(5.0, -2.0, 0.0)];
+    assert!(point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).is_none());
+}
+
+#[test]
+fn point_sliding_in_the_plane_of_a_triangle() {
+    // The point stays coplanar with the triangle during the whole motion, and enters it at
+    // x = -0.5, i.e., at t = 0.625.
+    let a = [Point3::new(-1.0f32, 0.0, -1.0); 2];
+    let b = [Point3::new(1.0, 0.0, -1.0); 2];
+    let c = [Point3::new(0.0, 0.0, 1.0); 2];
+    let pt = [Point3::new(-3.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+
+    let toi = point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).unwrap();
+    assert!((toi - 0.625).abs() < 1.0e-3);
+
+    // Sliding next to the triangle never hits it.
+    let pt = [Point3::new(-3.0, 0.0, 5.0), Point3::new(1.0, 0.0, 5.0)];
+    assert!(point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).is_none());
+}
+
+#[test]
+fn point_exactly_touching_a_triangle() {
+    // The point reaches the triangle exactly at the end of the motion.
+    let a = [Point3::new(-1.0f32, 0.0, -1.0); 2];
+    let b = [Point3::new(1.0, 0.0, -1.0); 2];
+    let c = [Point3::new(0.0, 0.0, 1.0); 2];
+    let pt = [Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 0.0)];
+
+    let toi = point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).unwrap();
+    assert!((toi - 1.0).abs() < 1.0e-3);
+
+    // Already touching at the beginning of the motion.
+    let pt = [Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)];
+    let toi = point_triangle_time_of_impact(&pt, &a, &b, &c, 1.0e-4).unwrap();
+    assert!(toi < 1.0e-3);
+}
+
+#[test]
+#[should_panic]
+fn point_triangle_zero_thickness() {
+    let a = [Point3::new(-1.0f32, 0.0, -1.0); 2];
+    let b = [Point3::new(1.0, 0.0, -1.0); 2];
+    let c = [Point3::new(0.0, 0.0, 1.0); 2];
+    let pt = [Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 0.0)];
+
+    let _ = point_triangle_time_of_impact(&pt, &a, &b, &c, 0.0);
+}
+
+#[test]
+fn crossing_edges() {
+    let a1 = [Point3::new(-1.0f32, 1.0, 0.0), Point3::new(-1.0, -1.0, 0.0)];
+    let b1 = [Point3::new(1.0, 1.0, 0.0), Point3::new(1.0, -1.0, 0.0)];
+    let a2 = [Point3::new(0.0, 0.0, -1.0); 2];
+    let b2 = [Point3::new(0.0, 0.0, 1.0); 2];
+
+    let toi = segment_segment_time_of_impact(&a1, &b1, &a2, &b2, 1.0e-4).unwrap();
+    assert!((toi - 0.5).abs() < 1.0e-3);
+}
+
+#[test]
+fn deforming_trimeshes_earliest_impacts() {
+    let indices = vec![[0, 1, 2]];
+    let floor = vec![
+        Point3::new(-1.0f32, 0.0, -1.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, -1.0),
+    ];
+    let mesh1 = DeformingTriMesh::new(indices.clone(), floor.clone(), floor, 1.0e-3);
+
+    let start: Vec<_> = [[0.0, 2.0, 0.0], [0.5, 3.0, 0.0], [-0.5, 3.0, 0.0]]
+        .iter()
+        .map(|p| Point3::new(p[0], p[1], p[2]))
+        .collect();
+    let end: Vec<_> = start.iter().map(|p| p - na::Vector3::y() * 4.0).collect();
+    let mut mesh2 = DeformingTriMesh::new(indices, start.clone(), end, 1.0e-3);
+
+    let impacts = time_of_impacts_deforming_trimeshes(&mesh1, &mesh2, 1.0e-3);
+    assert_eq!(impacts.len(), 1);
+    assert!((impacts[0].toi - 0.5).abs() < 1.0e-3);
+    assert_eq!(impacts[0].feature1, FeatureId::Face(0));
+    assert_eq!(impacts[0].feature2, FeatureId::Vertex(0));
+
+    // Once the second mesh stays still, nothing hits anymore.
+    mesh2.set_vertices(start.clone(), start);
+    assert!(time_of_impacts_deforming_trimeshes(&mesh1, &mesh2, 1.0e-3).is_empty());
+}
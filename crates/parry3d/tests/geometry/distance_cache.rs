@@ -0,0 +1,78 @@
+use na::{Isometry3, Point3, Unit, Vector3};
+use parry3d::query::{self, DistanceCache};
+use parry3d::shape::{Cuboid, Cylinder, SupportMap};
+use std::cell::Cell;
+
+/// A shape counting the evaluations of its support function, i.e., the GJK iterations.
+struct CountingSupportMap<S> {
+    shape: S,
+    num_calls: Cell<usize>,
+}
+
+impl<S: SupportMap<f32>> SupportMap<f32> for CountingSupportMap<S> {
+    fn local_support_point(&self, dir: &Vector3<f32>) -> Point3<f32> {
+        self.num_calls.set(self.num_calls.get() + 1);
+        self.shape.local_support_point(dir)
+    }
+
+    fn local_support_point_toward(&self, dir: &Unit<Vector3<f32>>) -> Point3<f32> {
+        self.num_calls.set(self.num_calls.get() + 1);
+        self.shape.local_support_point_toward(dir)
+    }
+}
+
+#[test]
+fn warm_started_distance_matches_distance() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.5));
+    let cylinder = Cylinder::new(0.5f32, 0.3);
+    let mut cache = DistanceCache::new();
+
+    for i in 0..100 {
+        let t = i as f32 * 0.05;
+        let pos12 = Isometry3::new(Vector3::new(3.0 - t, t.sin(), 0.2), Vector3::y() * t);
+
+        let expected = query::details::distance_support_map_support_map(&pos12, &cuboid, &cylinder);
+        let dist = query::details::distance_support_map_support_map_with_cache(
+            &pos12, &cuboid, &cylinder, &mut cache,
+        );
+
+        assert!((dist - expected).abs() < 1.0e-4, "{} != {}", dist, expected);
+
+        if dist > 1.0e-3 {
+            assert!(cache.separating_axis().is_some());
+        }
+    }
+}
+
+#[test]
+fn warm_start_reduces_gjk_iterations() {
+    let cuboid = CountingSupportMap {
+        shape: Cuboid::new(Vector3::new(1.0f32, 0.5, 0.5)),
+        num_calls: Cell::new(0),
+    };
+    let cylinder = Cylinder::new(0.5f32, 0.3);
+    let mut cache = DistanceCache::new();
+    let (mut cold_calls, mut warm_calls) = (0, 0);
+
+    for i in 0..100 {
+        let t = i as f32 * 0.01;
+        let pos12 = Isometry3::new(Vector3::new(3.0 - t, t.sin(), 0.2), Vector3::y() * t);
+
+        cuboid.num_calls.set(0);
+        let _ = query::details::distance_support_map_support_map(&pos12, &cuboid, &cylinder);
+        cold_calls += cuboid.num_calls.get();
+
+        cuboid.num_calls.set(0);
+        let _ = query::details::distance_support_map_support_map_with_cache(
+            &pos12, &cuboid, &cylinder, &mut cache,
+        );
+        warm_calls += cuboid.num_calls.get();
+    }
+
+    assert!(
+        warm_calls < cold_calls,
+        "warm-started: {} support calls, from scratch: {}",
+        warm_calls,
+        cold_calls
+    );
+}
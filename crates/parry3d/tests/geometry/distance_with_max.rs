@@ -0,0 +1,58 @@
+use na::{Isometry3, Vector3};
+use parry3d::query;
+use parry3d::shape::{Ball, Compound, Cuboid, Cylinder, SharedShape};
+
+#[test]
+fn support_map_distance_with_max() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.5));
+    let cylinder = Cylinder::new(0.5f32, 0.3);
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(3.0, 0.0, 0.0);
+
+    let dist = query::distance(&pos1, &cuboid, &pos2, &cylinder).unwrap();
+    let near = query::distance_with_max(&pos1, &cuboid, &pos2, &cylinder, 2.0).unwrap();
+    let far = query::distance_with_max(&pos1, &cuboid, &pos2, &cylinder, 1.0).unwrap();
+
+    assert!((near.unwrap() - dist).abs() < 1.0e-5);
+    assert_eq!(far, None);
+}
+
+#[test]
+fn support_map_distance_with_tight_max_matches_distance() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.5));
+    let cylinder = Cylinder::new(0.5f32, 0.3);
+    let pos1 = Isometry3::identity();
+
+    for i in 0..50 {
+        let angle = i as f32 * 0.37;
+        let pos2 = Isometry3::new(
+            Vector3::new(2.5 * angle.cos(), 0.3 * angle.sin(), 2.0 * angle.sin()),
+            Vector3::new(angle, 0.5 * angle, -0.2 * angle),
+        );
+        let dist = query::distance(&pos1, &cuboid, &pos2, &cylinder).unwrap();
+
+        // A bound barely above the actual distance must never be reported as exceeded.
+        let near = query::distance_with_max(&pos1, &cuboid, &pos2, &cylinder, dist + 1.0e-3)
+            .unwrap()
+            .expect("the distance is smaller than the bound");
+        assert!((near - dist).abs() < 1.0e-4);
+    }
+}
+
+#[test]
+fn compound_distance_with_max() {
+    let cube = SharedShape::new(Cuboid::new(Vector3::repeat(0.5f32)));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(2.0, 0.0, 0.0), cube.clone()),
+        (Isometry3::translation(5.0, 0.0, 0.0), cube),
+    ]);
+    let ball = Ball::new(0.5f32);
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(-1.0, 0.0, 0.0);
+
+    let near = query::distance_with_max(&pos1, &compound, &pos2, &ball, 3.0).unwrap();
+    let far = query::distance_with_max(&pos1, &compound, &pos2, &ball, 1.0).unwrap();
+
+    assert!((near.unwrap() - 2.0).abs() < 1.0e-5);
+    assert_eq!(far, None);
+}
@@ -0,0 +1,80 @@
+use na::{Isometry3, Vector3};
+use parry3d::query::{self, IntersectionWitness};
+use parry3d::shape::{Compound, Cuboid, SharedShape};
+
+#[test]
+fn cuboid_cuboid_intersection_witness() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+    let pos1 = Isometry3::identity();
+    let pos2 = Isometry3::translation(3.0, 0.0, 0.0);
+
+    let witness =
+        query::intersection_test_with_witness(&pos1, &cuboid, &pos2, &cuboid, None).unwrap();
+    let axis = match witness {
+        IntersectionWitness::Separated { axis } => axis,
+        _ => panic!("The cuboids should be separated."),
+    };
+    assert!(axis.x > 0.99);
+
+    // The cached axis still separates the cuboids after a small motion.
+    let pos2 = Isometry3::translation(2.9, 0.1, 0.0);
+    let witness =
+        query::intersection_test_with_witness(&pos1, &cuboid, &pos2, &cuboid, Some(&witness))
+            .unwrap();
+    assert_eq!(witness.axis(), Some(axis));
+
+    let pos2 = Isometry3::translation(1.5, 0.0, 0.0);
+    let witness =
+        query::intersection_test_with_witness(&pos1, &cuboid, &pos2, &cuboid, Some(&witness))
+            .unwrap();
+    match witness {
+        IntersectionWitness::Penetrating { contact, .. } => {
+            assert!((contact.dist + 0.5).abs() < 1.0e-5);
+            assert!(contact.normal1.x > 0.99);
+        }
+        _ => panic!("The cuboids should intersect."),
+    }
+}
+
+#[test]
+fn compound_cuboid_intersection_witness() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f32, 0.5, 0.5));
+    let compound = Compound::new(vec![
+        (
+            Isometry3::translation(-1.0, 0.0, 0.0),
+            SharedShape::new(cuboid),
+        ),
+        (
+            Isometry3::translation(1.0, 0.0, 0.0),
+            SharedShape::new(cuboid),
+        ),
+    ]);
+    let pos1 = Isometry3::identity();
+
+    let pos2 = Isometry3::translation(0.0, 3.0, 0.0);
+    let witness =
+        query::intersection_test_with_witness(&pos1, &compound, &pos2, &cuboid, None).unwrap();
+    match witness {
+        IntersectionWitness::Separated { axis } => assert!(axis.y > 0.99),
+        _ => panic!("The shapes should be separated."),
+    }
+
+    // The cached axis still separates the bounding boxes after a small motion.
+    let pos2 = Isometry3::translation(0.1, 2.9, 0.0);
+    let witness =
+        query::intersection_test_with_witness(&pos1, &compound, &pos2, &cuboid, Some(&witness))
+            .unwrap();
+    assert!(!witness.intersects());
+
+    let pos2 = Isometry3::translation(1.0, 0.75, 0.0);
+    let witness =
+        query::intersection_test_with_witness(&pos1, &compound, &pos2, &cuboid, Some(&witness))
+            .unwrap();
+    match witness {
+        IntersectionWitness::Penetrating { contact, .. } => {
+            assert!((contact.dist + 0.25).abs() < 1.0e-5);
+            assert!(contact.normal1.y > 0.99);
+        }
+        _ => panic!("The shapes should intersect."),
+    }
+}
@@ -0,0 +1,189 @@
+use na::{Isometry3, Vector3};
+use parry3d::pipeline::{
+    certify_joint_path, CubicJointPath, JointPath, JointPathCertificate, LinearJointPath,
+};
+use parry3d::query;
+use parry3d::shape::SharedShape;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+// A single prismatic joint translating a ball along the x axis.
+fn forward_kinematics(q: &[f32]) -> Vec<Isometry3<f32>> {
+    vec![Isometry3::translation(q[0], 0.0, 0.0)]
+}
+
+// A single revolute joint rotating, about the z axis, a bar extending from the joint axis up to
+// a distance of 2 along its local x axis.
+fn revolute_forward_kinematics(q: &[f32]) -> Vec<Isometry3<f32>> {
+    vec![Isometry3::rotation(Vector3::z() * q[0]) * Isometry3::translation(1.0, 0.0, 0.0)]
+}
+
+#[test]
+fn joint_path_free_and_contact() {
+    let links = [SharedShape::ball(0.5)];
+    let obstacles = [(
+        Isometry3::translation(3.0, 0.0, 0.0),
+        SharedShape::ball(0.5),
+    )];
+    let tolerance = 0.01;
+
+    let free_path = LinearJointPath::new(vec![0.0], vec![1.0]);
+    let result = certify_joint_path(
+        &free_path,
+        &forward_kinematics,
+        &links,
+        &[1.0],
+        &obstacles,
+        tolerance,
+    )
+    .unwrap();
+    assert!(result.is_free());
+
+    let blocked_path = LinearJointPath::new(vec![0.0], vec![2.5]);
+    let result = certify_joint_path(
+        &blocked_path,
+        &forward_kinematics,
+        &links,
+        &[1.0],
+        &obstacles,
+        tolerance,
+    )
+    .unwrap();
+
+    match result {
+        JointPathCertificate::Contact(contact) => {
+            assert_eq!((contact.link, contact.obstacle), (0, 0));
+            assert!(contact.distance < tolerance);
+            assert!(contact.configuration[0] > 1.99 - 1.0e-4);
+            assert!(contact.configuration[0] <= 2.0 + 1.0e-4);
+        }
+        JointPathCertificate::Free => panic!("The path should hit the obstacle."),
+    }
+}
+
+#[test]
+fn joint_path_revolute_contact() {
+    let links = [SharedShape::cuboid(1.0, 0.1, 0.1)];
+    let obstacles = [(
+        Isometry3::translation(0.0, 1.5, 0.0),
+        SharedShape::ball(0.25),
+    )];
+    // The tip of the bar travels twice as far as the joint value changes, so the displacement
+    // bound is the reach of the bar, not 1.
+    let displacement_bounds = [2.0];
+    let tolerance = 0.01;
+
+    // The ball touches the side of the bar when its center is at a distance of `0.1 + 0.25` from
+    // the bar axis.
+    let contact_angle = (0.35f32 / 1.5).acos();
+
+    let free_path = LinearJointPath::new(vec![0.0], vec![contact_angle - 0.1]);
+    let result = certify_joint_path(
+        &free_path,
+        &revolute_forward_kinematics,
+        &links,
+        &displacement_bounds,
+        &obstacles,
+        tolerance,
+    )
+    .unwrap();
+    assert!(result.is_free());
+
+    // Both paths rotate the bar at a constant speed from 0 to π/2.
+    let linear_path = LinearJointPath::new(vec![0.0], vec![FRAC_PI_2]);
+    let cubic_path = CubicJointPath::new(
+        vec![vec![0.0], vec![FRAC_PI_2]],
+        vec![vec![FRAC_PI_2], vec![FRAC_PI_2]],
+    );
+    let paths: [&dyn JointPath<f32>; 2] = [&linear_path, &cubic_path];
+
+    for path in paths {
+        let result = certify_joint_path(
+            path,
+            &revolute_forward_kinematics,
+            &links,
+            &displacement_bounds,
+            &obstacles,
+            tolerance,
+        )
+        .unwrap();
+
+        match result {
+            JointPathCertificate::Contact(contact) => {
+                assert_eq!((contact.link, contact.obstacle), (0, 0));
+                assert!(contact.distance < tolerance);
+                assert!(contact.configuration[0] > contact_angle - 1.0e-2);
+                assert!(contact.configuration[0] <= contact_angle + 1.0e-3);
+                assert!((contact.time - contact.configuration[0] / FRAC_PI_2).abs() < 1.0e-4);
+            }
+            JointPathCertificate::Free => panic!("The bar should hit the obstacle."),
+        }
+    }
+}
+
+#[test]
+fn joint_path_thin_obstacle() {
+    let links = [SharedShape::cuboid(1.0, 0.1, 0.1)];
+    // A thin plate below the negative x axis, crossed by the bar around the angle π.
+    let obstacles = [(
+        Isometry3::translation(-1.5, 0.0, 0.0),
+        SharedShape::cuboid(0.5, 0.01, 0.5),
+    )];
+    let tolerance = 0.01;
+
+    // Sampling this path at ten evenly spaced parameters puts the bar at the angles `0.9 * π`
+    // and `1.05 * π` on both sides of the plate, without ever touching it.
+    let path = LinearJointPath::new(vec![0.0], vec![1.5 * PI]);
+    for s in [0.6, 0.7] {
+        let pose = revolute_forward_kinematics(&path.configuration_at(s))[0];
+        let dist = query::distance(&pose, &*links[0], &obstacles[0].0, &*obstacles[0].1).unwrap();
+        assert!(dist > tolerance);
+    }
+
+    let result = certify_joint_path(
+        &path,
+        &revolute_forward_kinematics,
+        &links,
+        &[2.0],
+        &obstacles,
+        tolerance,
+    )
+    .unwrap();
+
+    match result {
+        JointPathCertificate::Contact(contact) => {
+            assert!(contact.distance < tolerance);
+            // The lower side of the bar reaches the plate at about `π - 0.11`.
+            assert!(contact.configuration[0] > PI - 0.2);
+            assert!(contact.configuration[0] < PI - 0.05);
+        }
+        JointPathCertificate::Free => panic!("The bar should hit the thin plate."),
+    }
+}
+
+#[test]
+fn cubic_joint_path_waypoints_and_speed() {
+    let waypoints = vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![0.0, 1.0]];
+    let tangents = vec![vec![1.0, 0.0], vec![0.0, -1.0], vec![2.0, 0.0]];
+    let path = CubicJointPath::new(waypoints.clone(), tangents);
+
+    for (i, waypoint) in waypoints.iter().enumerate() {
+        let configuration = path.configuration_at(i as f32 * 0.5);
+        for (q, expected) in configuration.iter().zip(waypoint.iter()) {
+            assert!((q - expected).abs() < 1.0e-5);
+        }
+    }
+
+    // The joint speed bound holds on the whole path and on the second segment alone.
+    let h = 1.0e-3;
+    for (s0, s1) in [(0.0, 1.0), (0.5, 1.0)] {
+        let max_speed = path.max_joint_speed(s0, s1);
+        for i in 0..=20 {
+            let s = s0 + (s1 - s0) * i as f32 / 20.0;
+            let (a, b) = ((s - h).max(s0), (s + h).min(s1));
+            let (qa, qb) = (path.configuration_at(a), path.configuration_at(b));
+            for (qa, qb) in qa.iter().zip(qb.iter()) {
+                assert!(((qb - qa) / (b - a)).abs() <= max_speed + 1.0e-2);
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use na::{Isometry3, Vector3};
+use parry3d::query::{self, FnRigidMotion, KeyframeMotion, NonlinearRigidMotion};
+use parry3d::shape::Ball;
+
+#[test]
+fn keyframe_motion_toi() {
+    let ball = Ball::new(0.5f32);
+    let motion1 = KeyframeMotion::new(vec![
+        (0.0, Isometry3::identity()),
+        (1.0, Isometry3::identity()),
+        (2.0, Isometry3::translation(10.0, 0.0, 0.0)),
+    ]);
+    let motion2 = NonlinearRigidMotion::constant_position(Isometry3::translation(5.0, 0.0, 0.0));
+
+    let toi = query::nonlinear_time_of_impact(&motion1, &ball, &motion2, &ball, 0.0, 2.0, true)
+        .unwrap()
+        .expect("No time of impact found.");
+
+    assert!((toi.toi - 1.4).abs() < 1.0e-2, "Wrong toi: {}", toi.toi);
+}
+
+#[test]
+fn fn_rigid_motion_toi() {
+    let ball = Ball::new(0.5f32);
+    // A point on a circle of radius 2 around the origin, rotating at 1 rad/s.
+    let motion1 = FnRigidMotion::new(
+        |t: f32| {
+            Isometry3::new(
+                Vector3::new(2.0 * t.cos(), 2.0 * t.sin(), 0.0),
+                Vector3::z() * t,
+            )
+        },
+        2.0,
+        1.0,
+    );
+    let motion2 = NonlinearRigidMotion::constant_position(Isometry3::translation(-2.0, 0.0, 0.0));
+
+    let toi = query::nonlinear_time_of_impact(&motion1, &ball, &motion2, &ball, 0.0, 4.0, true)
+        .unwrap()
+        .expect("No time of impact found.");
+
+    // The balls touch when their centers are 1 apart, i.e., when the chord angle is 2 * asin(1/4).
+    let expected = std::f32::consts::PI - 2.0 * (0.25f32).asin();
+    assert!(
+        (toi.toi - expected).abs() < 1.0e-2,
+        "Wrong toi: {}",
+        toi.toi
+    );
+}
@@ -0,0 +1,24 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, NonlinearRigidMotion};
+use parry3d::shape::Ball;
+
+#[test]
+fn min_distance_of_passing_balls() {
+    let ball = Ball::new(0.5f32);
+    let motion1 = NonlinearRigidMotion::new(
+        Isometry3::translation(-5.0, 0.0, 0.0),
+        Point3::origin(),
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector3::zeros(),
+    );
+    let motion2 = NonlinearRigidMotion::constant_position(Isometry3::translation(0.0, 2.0, 0.0));
+
+    let result =
+        query::min_distance_over_motion(&motion1, &ball, &motion2, &ball, 0.0, 1.0, 1.0e-4)
+            .unwrap()
+            .unwrap();
+
+    assert!((result.distance - 1.0).abs() < 1.0e-3);
+    assert!((result.time - 0.5).abs() < 1.0e-2);
+    assert!((result.witness1 - Point3::new(0.0, 0.5, 0.0)).norm() < 1.0e-1);
+}
@@ -0,0 +1,83 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, PointQuery, Ray, RayCast};
+use parry3d::shape::{
+    Ball, Cuboid, MinkowskiDifference, MinkowskiSum, Shape, ShapeType, TypedShape,
+};
+
+#[test]
+fn minkowski_sum_aabb_and_queries() {
+    let sum = MinkowskiSum::new(
+        Isometry3::translation(1.0, 0.0, 0.0),
+        Cuboid::new(Vector3::repeat(1.0)),
+        Ball::new(0.5),
+    );
+
+    let aabb = sum.compute_local_aabb();
+    assert!((aabb.mins - Point3::new(-1.5, -1.5, -1.5)).norm() < 1.0e-5);
+    assert!((aabb.maxs - Point3::new(2.5, 1.5, 1.5)).norm() < 1.0e-5);
+
+    let proj = sum.project_local_point(&Point3::new(5.0, 0.0, 0.0), false);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(2.5, 0.0, 0.0)).norm() < 1.0e-4);
+    assert!(sum.contains_local_point(&Point3::new(2.4, 0.0, 0.0)));
+
+    let ray = Ray::new(Point3::new(5.0, 0.0, 0.0), -Vector3::x());
+    let toi = sum.cast_local_ray(&ray, f32::MAX, true).unwrap();
+    assert!((toi - 2.5).abs() < 1.0e-4);
+}
+
+#[test]
+fn minkowski_difference_contains_origin_iff_intersecting() {
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let ball = Ball::new(0.5);
+
+    for x in [0.0, 1.2, 1.4, 1.6, 2.0, 3.0] {
+        let pos12 = Isometry3::translation(x, 0.0, 0.0);
+        let difference = MinkowskiDifference::new(pos12, cuboid, ball);
+        let intersecting =
+            query::intersection_test(&Isometry3::identity(), &cuboid, &pos12, &ball).unwrap();
+
+        assert_eq!(
+            difference.contains_local_point(&Point3::origin()),
+            intersecting
+        );
+    }
+
+    let difference = MinkowskiDifference::new(Isometry3::translation(1.0, 0.0, 0.0), cuboid, ball);
+    let aabb = difference.compute_local_aabb();
+    assert!((aabb.mins - Point3::new(-2.5, -1.5, -1.5)).norm() < 1.0e-5);
+    assert!((aabb.maxs - Point3::new(0.5, 1.5, 1.5)).norm() < 1.0e-5);
+}
+
+#[test]
+fn minkowski_shapes_typed_shapes() {
+    let pos12 = Isometry3::translation(1.0, 0.0, 0.0);
+    let cuboid = Cuboid::new(Vector3::repeat(1.0));
+    let ball = Ball::new(0.5);
+
+    match MinkowskiSum::new(pos12, cuboid, ball).as_typed_shape() {
+        TypedShape::MinkowskiSum {
+            pos12: typed_pos12,
+            shape1,
+            shape2,
+        } => {
+            assert_eq!(typed_pos12, pos12);
+            assert_eq!(shape1.shape_type(), ShapeType::Cuboid);
+            assert_eq!(shape2.shape_type(), ShapeType::Ball);
+        }
+        _ => panic!("A Minkowski sum must be typed as `TypedShape::MinkowskiSum`."),
+    }
+
+    match MinkowskiDifference::new(pos12, cuboid, ball).as_typed_shape() {
+        TypedShape::MinkowskiDifference {
+            pos12: typed_pos12,
+            shape1,
+            shape2,
+        } => {
+            assert_eq!(typed_pos12, pos12);
+            assert_eq!(shape1.shape_type(), ShapeType::Cuboid);
+            assert_eq!(shape2.shape_type(), ShapeType::Ball);
+        }
+        _ => panic!("A Minkowski difference must be typed as `TypedShape::MinkowskiDifference`."),
+    }
+}
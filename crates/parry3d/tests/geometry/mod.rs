@@ -1,11 +1,35 @@
 mod ball_ball_toi;
 mod ball_triangle_toi;
+mod broad_phase;
+mod closest_features;
+mod collision_world;
+mod compound_toi_all_hits;
+mod contact_manifolds;
 mod convex_hull;
 mod cuboid_ray_cast;
 mod cylinder_cuboid_contact;
+mod deformable_ccd;
+mod distance_cache;
+mod distance_with_max;
 mod epa3;
+mod intersection_test_with_witness;
+mod joint_path_certification;
+mod keyframe_motion_toi;
+mod min_distance_over_motion;
+mod minkowski_sum;
+mod nonlinear_collision_intervals;
+mod ray_sensor;
+mod round_composite_shapes;
+mod scaled_shape;
+mod self_collision_model;
+mod spline_motions;
 mod still_objects_toi;
+mod tetmesh;
 mod time_of_impact3;
 mod trimesh_connected_components;
 mod trimesh_intersection;
+mod trimesh_ray_cast_all;
+mod trimesh_signed_distance;
 mod trimesh_trimesh_toi;
+mod trimesh_winding_number;
+mod voxels_shape;
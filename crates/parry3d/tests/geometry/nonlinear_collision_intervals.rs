@@ -0,0 +1,25 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, NonlinearRigidMotion};
+use parry3d::shape::Ball;
+
+#[test]
+fn ball_crossing_ball_collision_intervals() {
+    let ball = Ball::new(0.5f32);
+    let motion1 = NonlinearRigidMotion::new(
+        Isometry3::translation(-5.0, 0.0, 0.0),
+        Point3::origin(),
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector3::zeros(),
+    );
+    let motion2 = NonlinearRigidMotion::identity();
+
+    let intervals =
+        query::nonlinear_collision_intervals(&motion1, &ball, &motion2, &ball, 0.0, 1.0, 1.0e-3)
+            .unwrap();
+
+    assert_eq!(intervals.len(), 1);
+    assert!((intervals[0].enter_time - 0.4).abs() < 1.0e-3);
+    assert!((intervals[0].exit_time - 0.6).abs() < 1.0e-3);
+    assert!(intervals[0].enter_normal1.x > 0.99);
+    assert!(intervals[0].exit_normal1.x < -0.99);
+}
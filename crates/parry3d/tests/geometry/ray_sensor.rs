@@ -0,0 +1,38 @@
+use na::{Isometry3, Vector3};
+use parry3d::partitioning::Qbvh;
+use parry3d::query::{cast_ray_batch, cast_ray_batch_with_qbvh, RayCast, RaySensor};
+use parry3d::shape::Cuboid;
+
+#[test]
+fn ray_sensor_depth_image() {
+    let sensor = RaySensor::new(Isometry3::identity(), 1.0f32, 4, 3);
+    let rays = sensor.rays();
+    assert_eq!(rays.len(), sensor.num_rays());
+
+    // A wall in front of the sensor: every pixel has a depth of 4.5.
+    let wall = Cuboid::new(Vector3::new(100.0, 100.0, 0.5));
+    let wall_pos = Isometry3::translation(0.0, 0.0, 5.0);
+    let mut hits = vec![None; rays.len()];
+    cast_ray_batch(&wall_pos, &wall, &rays, f32::MAX, true, &mut hits);
+
+    for hit in &hits {
+        assert!((hit.unwrap().toi - 4.5).abs() < 1.0e-4);
+    }
+
+    // The same wall, stored in a Qbvh.
+    let mut qbvh = Qbvh::new();
+    qbvh.clear_and_rebuild(std::iter::once((0u32, wall.aabb(&wall_pos))), 0.0);
+    let mut scene_hits = vec![None; rays.len()];
+    cast_ray_batch_with_qbvh(
+        &qbvh,
+        &rays,
+        f32::MAX,
+        |_, ray, max_toi| wall.cast_ray_and_get_normal(&wall_pos, ray, max_toi, true),
+        &mut scene_hits,
+    );
+
+    for (hit, scene_hit) in hits.iter().zip(scene_hits.iter()) {
+        assert_eq!(scene_hit.unwrap().0, 0);
+        assert_eq!(hit.unwrap().toi, scene_hit.unwrap().1.toi);
+    }
+}
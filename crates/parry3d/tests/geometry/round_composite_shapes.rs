@@ -0,0 +1,122 @@
+use na::{DMatrix, Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{HeightField, RoundHeightField, RoundShape, RoundTriMesh, Shape, TriMesh};
+
+fn round_square(border_radius: f32) -> RoundTriMesh<f32> {
+    let vertices = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let mesh = TriMesh::new(vertices, vec![[0, 2, 1], [0, 3, 2]]);
+    RoundTriMesh::new(mesh, border_radius)
+}
+
+fn round_flat_heightfield(border_radius: f32) -> RoundHeightField<f32> {
+    let heightfield = HeightField::new(DMatrix::zeros(3, 3), Vector3::repeat(1.0));
+    RoundHeightField::new(heightfield, border_radius)
+}
+
+#[test]
+fn round_trimesh_point_projection_and_ray_cast() {
+    let shape = round_square(0.1);
+
+    let proj = shape.project_local_point(&Point3::new(0.5, 1.0, 0.5), false);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(0.5, 0.1, 0.5)).norm() < 1.0e-5);
+    assert!(shape.contains_local_point(&Point3::new(0.5, 0.05, 0.5)));
+
+    let ray = Ray::new(Point3::new(0.5, 1.0, 0.5), -Vector3::y());
+    let toi = shape.cast_local_ray(&ray, f32::MAX, true).unwrap();
+    assert!((toi - 0.9).abs() < 1.0e-5);
+}
+
+#[test]
+fn round_trimesh_non_solid_ray_from_inside() {
+    let shape = round_square(0.1);
+
+    // The ray starts inside of the second triangle and crosses the first one before leaving
+    // the shape.
+    let ray = Ray::new(Point3::new(0.2, 0.0, 0.5), Vector3::x());
+    let hit = shape
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert!((hit.toi - 0.9).abs() < 1.0e-3);
+    assert!(hit.normal.x > 0.99);
+
+    assert!(shape.cast_local_ray(&ray, 0.5, false).is_none());
+    assert_eq!(shape.cast_local_ray(&ray, 0.5, true), Some(0.0));
+}
+
+#[test]
+fn round_heightfield_queries() {
+    let shape = round_flat_heightfield(0.1);
+
+    let proj = shape.project_local_point(&Point3::new(0.0, 1.0, 0.0), false);
+    assert!((proj.point - Point3::new(0.0, 0.1, 0.0)).norm() < 1.0e-5);
+
+    // Far from the heightfield, only the closest triangles must be considered.
+    let proj = shape.project_local_point(&Point3::new(3.0, 0.0, 0.0), false);
+    assert!((proj.point - Point3::new(0.6, 0.0, 0.0)).norm() < 1.0e-5);
+
+    let ray = Ray::new(Point3::new(0.1, 1.0, 0.2), -Vector3::y());
+    let toi = shape.cast_local_ray(&ray, f32::MAX, true).unwrap();
+    assert!((toi - 0.9).abs() < 1.0e-5);
+
+    let ray = Ray::new(Point3::new(-0.3, 0.0, 0.1), Vector3::x());
+    let toi = shape.cast_local_ray(&ray, f32::MAX, false).unwrap();
+    assert!((toi - 0.9).abs() < 1.0e-3);
+}
+
+#[test]
+fn round_heightfield_long_ray_cast_matches_brute_force() {
+    let heights = DMatrix::from_fn(41, 41, |i, j| {
+        ((i as f32) * 0.7).sin() * ((j as f32) * 0.3).cos()
+    });
+    let heightfield = HeightField::new(heights, Vector3::new(40.0, 2.0, 40.0));
+    let border_radius = 0.25;
+    let shape = RoundHeightField::new(heightfield.clone(), border_radius);
+
+    let rays = [
+        Ray::new(Point3::new(-30.0, 0.5, -25.0), Vector3::new(1.0, 0.0, 0.9)),
+        Ray::new(Point3::new(-25.0, 1.2, 3.1), Vector3::x()),
+        Ray::new(
+            Point3::new(18.0, 5.0, -22.0),
+            Vector3::new(-1.0, -0.15, 1.0),
+        ),
+        Ray::new(Point3::new(2.3, 10.0, 1.7), -Vector3::y()),
+    ];
+
+    for ray in &rays {
+        let brute_force = heightfield
+            .triangles()
+            .filter_map(|triangle| {
+                RoundShape {
+                    inner_shape: triangle,
+                    border_radius,
+                }
+                .cast_local_ray(ray, f32::MAX, true)
+            })
+            .fold(None, |best: Option<f32>, toi| {
+                Some(best.map(|best| best.min(toi)).unwrap_or(toi))
+            });
+        let toi = shape.cast_local_ray(ray, f32::MAX, true);
+
+        match (toi, brute_force) {
+            (Some(toi), Some(expected)) => assert!((toi - expected).abs() < 1.0e-3),
+            (toi, expected) => assert_eq!(toi, expected),
+        }
+    }
+}
+
+#[test]
+fn round_heightfield_mass_properties() {
+    let shape = round_flat_heightfield(0.1);
+    let mprops = shape.mass_properties(2.0);
+
+    // A 1x1 slab of thickness 0.2.
+    assert!((mprops.mass() - 0.4).abs() < 1.0e-5);
+    assert!(mprops.local_com.coords.norm() < 1.0e-5);
+    assert!(mprops.principal_inertia().iter().all(|i| *i > 0.0));
+}
@@ -0,0 +1,79 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{Ball, Cuboid, Scaled, Shape, ShapeType, SupportMap, TypedShape};
+
+#[test]
+fn scaled_cuboid_aabb_and_support_points() {
+    let scaled = Scaled::new(
+        Cuboid::new(Vector3::repeat(1.0f32)),
+        Vector3::new(2.0, 0.5, -1.0),
+    );
+
+    let aabb = scaled.local_aabb();
+    assert!((aabb.mins - Point3::new(-2.0, -0.5, -1.0)).norm() < 1.0e-5);
+    assert!((aabb.maxs - Point3::new(2.0, 0.5, 1.0)).norm() < 1.0e-5);
+
+    let aabb = scaled.compute_aabb(&Isometry3::translation(1.0, 2.0, 3.0));
+    assert!((aabb.mins - Point3::new(-1.0, 1.5, 2.0)).norm() < 1.0e-5);
+
+    let support = scaled.local_support_point(&Vector3::new(1.0, 1.0, 1.0));
+    assert!((support - Point3::new(2.0, 0.5, 1.0)).norm() < 1.0e-5);
+
+    assert_eq!(scaled.shape_type(), ShapeType::Scaled);
+    match scaled.as_typed_shape() {
+        TypedShape::Scaled { inner_shape, scale } => {
+            assert_eq!(inner_shape.shape_type(), ShapeType::Cuboid);
+            assert_eq!(scale, Vector3::new(2.0, 0.5, -1.0));
+        }
+        _ => panic!("A scaled shape must be typed as `TypedShape::Scaled`."),
+    }
+}
+
+#[test]
+fn scaled_ball_ray_cast() {
+    // An ellipsoid with the semi-axes 2, 0.5, and 1.
+    let scaled = Scaled::new(Ball::new(1.0f32), Vector3::new(2.0, 0.5, 1.0));
+
+    let ray = Ray::new(Point3::new(-5.0, 0.0, 0.0), Vector3::x());
+    let hit = scaled
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, true)
+        .unwrap();
+    assert!((hit.toi - 3.0).abs() < 1.0e-4);
+    assert!((hit.normal - -Vector3::x()).norm() < 1.0e-4);
+
+    let ray = Ray::new(Point3::new(0.0, -5.0, 0.0), Vector3::y());
+    let toi = scaled.cast_local_ray(&ray, f32::MAX, true).unwrap();
+    assert!((toi - 4.5).abs() < 1.0e-4);
+
+    // From the inside, a solid cast hits immediately and a non-solid one hits the boundary.
+    let ray = Ray::new(Point3::origin(), Vector3::z());
+    assert_eq!(scaled.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+    let toi = scaled.cast_local_ray(&ray, f32::MAX, false).unwrap();
+    assert!((toi - 1.0).abs() < 1.0e-4);
+
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::x());
+    assert_eq!(scaled.cast_local_ray(&ray, f32::MAX, true), None);
+}
+
+#[test]
+fn scaled_ball_point_projection() {
+    let scaled = Scaled::new(Ball::new(1.0f32), Vector3::new(2.0, 0.5, 1.0));
+
+    let proj = scaled.project_local_point(&Point3::new(4.0, 0.0, 0.0), true);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(2.0, 0.0, 0.0)).norm() < 1.0e-3);
+
+    let proj = scaled.project_local_point(&Point3::new(0.0, 3.0, 0.0), true);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(0.0, 0.5, 0.0)).norm() < 1.0e-3);
+
+    let inside = Point3::new(0.5, 0.1, 0.0);
+    let proj = scaled.project_local_point(&inside, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, inside);
+    assert!(scaled.contains_local_point(&inside));
+
+    let proj = scaled.project_local_point(&Point3::new(0.0, 0.25, 0.0), false);
+    assert!(proj.is_inside);
+    assert!((proj.point - Point3::new(0.0, 0.5, 0.0)).norm() < 1.0e-3);
+}
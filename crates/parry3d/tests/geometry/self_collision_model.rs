@@ -0,0 +1,108 @@
+use na::{Isometry3, UnitQuaternion, Vector3};
+use parry3d::pipeline::SelfCollisionModel;
+use parry3d::shape::SharedShape;
+
+fn chain_poses(spacing: f32) -> Vec<Isometry3<f32>> {
+    (0..3)
+        .map(|i| Isometry3::translation(i as f32 * spacing, 0.0, 0.0))
+        .collect()
+}
+
+fn chain_model() -> SelfCollisionModel<f32> {
+    let link = SharedShape::cuboid(0.5, 0.5, 0.5);
+    SelfCollisionModel::new(vec![link.clone(), link.clone(), link])
+}
+
+#[test]
+fn self_collision_adjacent_links_excluded() {
+    let mut model = chain_model();
+    let poses = chain_poses(0.9);
+
+    assert_eq!(model.colliding_pairs(&poses).unwrap().len(), 2);
+
+    model.exclude_adjacent_links(&[None, Some(0), Some(1)]);
+    assert!(model.is_pair_excluded(1, 0));
+    assert!(model.colliding_pairs(&poses).unwrap().is_empty());
+
+    // The first and last links are 0.8 apart.
+    model.set_pair_margin(0, 2, 1.0);
+    let colliding = model.colliding_pairs(&poses).unwrap();
+    assert_eq!(colliding.len(), 1);
+    assert_eq!((colliding[0].link1, colliding[0].link2), (0, 2));
+    assert!((colliding[0].distance - 0.8).abs() < 1.0e-5);
+}
+
+#[test]
+fn self_collision_calibration() {
+    let mut model = chain_model();
+    let samples = vec![chain_poses(0.9), chain_poses(0.95), chain_poses(0.5)];
+
+    // Only the adjacent links collide in all the samples.
+    assert_eq!(model.exclude_always_colliding_pairs(&samples).unwrap(), 2);
+    assert!(model.is_pair_excluded(0, 1));
+    assert!(model.is_pair_excluded(1, 2));
+    assert!(!model.is_pair_excluded(0, 2));
+}
+
+#[test]
+fn self_collision_distance_gradients() {
+    let mut model = chain_model();
+    model.exclude_adjacent_links(&[None, Some(0), Some(1)]);
+    let mut poses = chain_poses(0.9);
+
+    let proximity = model.pair_distances(&poses, 10.0).unwrap()[0];
+    assert!((proximity.gradient1.linear + Vector3::x()).norm() < 1.0e-5);
+    assert!((proximity.gradient2.linear - Vector3::x()).norm() < 1.0e-5);
+
+    // Compare with finite differences.
+    let eps = 1.0e-3;
+    poses[2].append_translation_mut(&Vector3::new(eps, 0.0, 0.0).into());
+    let moved = model.pair_distances(&poses, 10.0).unwrap()[0];
+    let expected = proximity.distance + proximity.gradient2.linear.x * eps;
+    assert!((moved.distance - expected).abs() < 1.0e-4);
+}
+
+#[test]
+fn self_collision_rotation_gradients() {
+    // A flat bar below a ball attached half a unit away from the origin of its link.
+    let bar = SharedShape::cuboid(1.0, 0.1, 0.1);
+    let ball = SharedShape::compound(vec![(
+        Isometry3::translation(-0.5, 0.0, 0.0),
+        SharedShape::ball(0.2),
+    )]);
+    let model = SelfCollisionModel::new(vec![bar, ball]);
+    let poses = vec![Isometry3::identity(), Isometry3::translation(1.0, 1.0, 0.0)];
+
+    let proximity = model.pair_distances(&poses, 10.0).unwrap()[0];
+    assert!((proximity.distance - 0.7).abs() < 1.0e-5);
+    // Rotating any link around z brings the ball closer to the bar.
+    assert!((proximity.gradient1.angular - Vector3::new(0.0, 0.0, -0.5)).norm() < 1.0e-4);
+    assert!((proximity.gradient2.angular - Vector3::new(0.0, 0.0, -0.5)).norm() < 1.0e-4);
+
+    // Compare with finite differences, rotating each link around its origin.
+    let eps = 1.0e-3;
+
+    for link in 0..2 {
+        for k in 0..3 {
+            let mut rotated = poses.clone();
+            let rotation = UnitQuaternion::from_scaled_axis(Vector3::ith(k, eps));
+            rotated[link].append_rotation_wrt_center_mut(&rotation);
+
+            let gradient = if link == 0 {
+                proximity.gradient1
+            } else {
+                proximity.gradient2
+            };
+            let moved = model.pair_distances(&rotated, 10.0).unwrap()[0];
+            let expected = proximity.distance + gradient.angular[k] * eps;
+            assert!(
+                (moved.distance - expected).abs() < 5.0e-5,
+                "link {}, axis {}: {} != {}",
+                link,
+                k,
+                moved.distance,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{BSplineMotion, HermiteKeyframe, HermiteMotion, RigidMotion};
+
+fn assert_velocity_matches_finite_differences(motion: &dyn RigidMotion<f32>, times: &[f32]) {
+    let local_pt = Point3::new(0.3, -0.2, 0.5);
+    let h = 1.0e-3;
+
+    for t in times {
+        let fd = (motion.position_at_time(t + h) * local_pt
+            - motion.position_at_time(t - h) * local_pt)
+            / (2.0 * h);
+        let vel = motion.local_point_velocity(*t, &local_pt);
+        assert!(
+            (vel - fd).norm() < 1.0e-2 * fd.norm().max(1.0),
+            "Wrong velocity at t = {}: {} instead of {}",
+            t,
+            vel,
+            fd
+        );
+    }
+}
+
+#[test]
+fn bspline_motion_knots_and_velocity() {
+    let translations = [
+        Vector3::new(0.0f32, 0.0, 0.0),
+        Vector3::new(1.0, 0.5, 0.0),
+        Vector3::new(2.0, 0.0, 1.0),
+        Vector3::new(3.0, -1.0, 1.0),
+        Vector3::new(4.0, 0.0, 0.0),
+    ];
+    let rotations = [
+        Vector3::new(0.0f32, 0.0, 0.0),
+        Vector3::new(0.3, 0.1, 0.0),
+        Vector3::new(0.5, -0.2, 0.4),
+        Vector3::new(0.2, 0.0, 0.8),
+        Vector3::new(0.0, 0.3, 1.0),
+    ];
+    let poses: Vec<_> = translations
+        .iter()
+        .zip(rotations.iter())
+        .map(|(t, r)| Isometry3::new(*t, *r))
+        .collect();
+    let motion = BSplineMotion::new(1.0, 0.5, &poses);
+    assert_eq!(motion.end_time(), 2.0);
+
+    // At the knots, the uniform cubic B-spline is at `(P[k] + 4 * P[k + 1] + P[k + 2]) / 6`.
+    for k in 0..3 {
+        let pose = motion.position_at_time(1.0 + k as f32 * 0.5);
+        let translation = (translations[k] + translations[k + 1] * 4.0 + translations[k + 2]) / 6.0;
+        let rotation = (rotations[k] + rotations[k + 1] * 4.0 + rotations[k + 2]) / 6.0;
+        assert!((pose.translation.vector - translation).norm() < 1.0e-5);
+        assert!((pose.rotation.scaled_axis() - rotation).norm() < 1.0e-5);
+    }
+
+    assert_velocity_matches_finite_differences(&motion, &[1.1, 1.3, 1.5, 1.75, 1.9]);
+}
+
+#[test]
+fn hermite_motion_keyframes_and_velocity() {
+    let keyframes = vec![
+        HermiteKeyframe::new(
+            0.0f32,
+            Isometry3::identity(),
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ),
+        HermiteKeyframe::new(
+            1.0,
+            Isometry3::new(Vector3::new(1.0, 1.0, 0.0), Vector3::new(0.2, 0.0, 0.8)),
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector3::new(0.5, 0.0, 0.0),
+        ),
+        HermiteKeyframe::new(
+            3.0,
+            Isometry3::new(Vector3::new(0.0, 2.0, 1.0), Vector3::new(0.4, -0.3, 1.0)),
+            Vector3::new(-1.0, 0.0, 0.5),
+            Vector3::new(0.0, 0.1, 0.0),
+        ),
+    ];
+    let motion = HermiteMotion::new(keyframes.clone());
+
+    // The keyframe poses and linear velocities are interpolated.
+    for keyframe in &keyframes {
+        let pose = motion.position_at_time(keyframe.time);
+        assert!((pose.translation.vector - keyframe.pose.translation.vector).norm() < 1.0e-5);
+        assert!(pose.rotation.angle_to(&keyframe.pose.rotation) < 1.0e-5);
+        let vel = motion.local_point_velocity(keyframe.time, &Point3::origin());
+        assert!((vel - keyframe.linvel).norm() < 1.0e-4);
+    }
+
+    assert_velocity_matches_finite_differences(&motion, &[0.2, 0.5, 0.9, 1.5, 2.5]);
+
+    // The speed bounds hold.
+    let (max_linvel, _) = motion.velocity_bounds(0.0, 3.0);
+    for i in 0..=30 {
+        let t = i as f32 * 0.1;
+        let vel = motion.local_point_velocity(t, &Point3::origin());
+        assert!(vel.norm() <= max_linvel + 1.0e-4);
+    }
+}
@@ -0,0 +1,105 @@
+use na::{Point3, Vector3};
+use parry3d::query::{PointQuery, Ray, RayCast};
+use parry3d::shape::{FeatureId, Shape, TetMesh};
+
+// The unit cube split into five tetrahedra: four corners, and a central one.
+fn unit_cube() -> TetMesh<f32> {
+    let vertices = (0..8)
+        .map(|i| Point3::new((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32))
+        .collect();
+    let indices = vec![
+        [0, 1, 2, 4],
+        [3, 1, 2, 7],
+        [5, 1, 4, 7],
+        [6, 2, 4, 7],
+        [1, 2, 4, 7],
+    ];
+    TetMesh::new(vertices, indices)
+}
+
+fn assert_boundary_face(mesh: &TetMesh<f32>, feature: FeatureId) {
+    match feature {
+        FeatureId::Face(id) => assert!(mesh.adjacent_tetrahedron(id / 4, id % 4).is_none()),
+        _ => panic!("Expected a face, got {:?}.", feature),
+    }
+}
+
+#[test]
+fn tetmesh_ray_cast_from_outside() {
+    let mesh = unit_cube();
+    let ray = Ray::new(Point3::new(0.3, 0.4, -1.0), Vector3::z());
+
+    for solid in [true, false] {
+        let hit = mesh
+            .cast_local_ray_and_get_normal(&ray, f32::MAX, solid)
+            .unwrap();
+        assert!((hit.toi - 1.0).abs() < 1.0e-5);
+        assert!((hit.normal - -Vector3::z()).norm() < 1.0e-5);
+        assert_boundary_face(&mesh, hit.feature);
+    }
+
+    assert!(mesh.cast_local_ray(&ray, 0.5, true).is_none());
+
+    let ray = Ray::new(Point3::new(2.0, 0.4, -1.0), Vector3::z());
+    assert!(mesh.cast_local_ray(&ray, f32::MAX, true).is_none());
+}
+
+#[test]
+fn tetmesh_ray_cast_from_inside() {
+    let mesh = unit_cube();
+
+    // The ray crosses several tetrahedra before leaving the cube through its `x = 1` face.
+    let ray = Ray::new(Point3::new(0.05, 0.5, 0.5), Vector3::new(1.0, 0.3, 0.2));
+    assert_eq!(mesh.cast_local_ray(&ray, f32::MAX, true), Some(0.0));
+
+    let hit = mesh
+        .cast_local_ray_and_get_normal(&ray, f32::MAX, false)
+        .unwrap();
+    assert!((hit.toi - 0.95).abs() < 1.0e-5);
+    assert!((hit.normal - -Vector3::x()).norm() < 1.0e-5);
+    assert_boundary_face(&mesh, hit.feature);
+
+    assert!(mesh.cast_local_ray(&ray, 0.5, false).is_none());
+}
+
+#[test]
+fn tetmesh_point_projection() {
+    let mesh = unit_cube();
+
+    let proj = mesh.project_local_point(&Point3::new(0.5, 0.5, -0.5), true);
+    assert!(!proj.is_inside);
+    assert!((proj.point - Point3::new(0.5, 0.5, 0.0)).norm() < 1.0e-5);
+
+    // Points on the boundary are inside of the mesh.
+    let boundary_pt = Point3::new(0.5, 0.2, 0.0);
+    let proj = mesh.project_local_point(&boundary_pt, false);
+    assert!(proj.is_inside);
+    assert!((proj.point - boundary_pt).norm() < 1.0e-5);
+    assert!(mesh.contains_local_point(&boundary_pt));
+
+    // A point on a face shared by two tetrahedra is projected on the boundary, not on that face.
+    let inner_pt = Point3::new(0.25, 0.35, 0.4);
+    let (proj, feature) = mesh.project_local_point_and_get_feature(&inner_pt);
+    assert!(proj.is_inside);
+    assert!((proj.point - Point3::new(0.0, 0.35, 0.4)).norm() < 1.0e-5);
+    assert_boundary_face(&mesh, feature);
+
+    let proj = mesh.project_local_point(&inner_pt, true);
+    assert!(proj.is_inside);
+    assert_eq!(proj.point, inner_pt);
+}
+
+#[test]
+fn tetmesh_to_trimesh() {
+    let mesh = unit_cube();
+    assert!((mesh.volume() - 1.0).abs() < 1.0e-5);
+
+    let trimesh = mesh.to_trimesh();
+    assert_eq!(trimesh.vertices().len(), 8);
+    assert_eq!(trimesh.indices().len(), 12);
+
+    // The boundary triangles are oriented outward, so they enclose a positive volume.
+    let mprops = trimesh.mass_properties(1.0);
+    assert!((mprops.mass() - 1.0).abs() < 1.0e-4);
+    assert!((mprops.local_com - Point3::new(0.5, 0.5, 0.5)).norm() < 1.0e-4);
+}
@@ -0,0 +1,41 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::Ray;
+use parry3d::shape::{Compound, Cuboid, SharedShape, TriMesh};
+
+#[test]
+fn trimesh_cast_ray_all_hits_both_sides() {
+    let (vtx, idx) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    let mesh = TriMesh::<f32>::new(vtx, idx);
+    let ray = Ray::new(Point3::new(-3.0, 0.1, 0.2), Vector3::x());
+
+    let hits = mesh.cast_local_ray_all(&ray, f32::MAX);
+    assert_eq!(hits.len(), 2);
+    assert!((hits[0].intersection.toi - 2.0).abs() < 1.0e-5);
+    assert!((hits[1].intersection.toi - 4.0).abs() < 1.0e-5);
+    assert!(hits[0].is_entering);
+    assert!(!hits[1].is_entering);
+    assert!(mesh.cast_local_ray_all(&ray, 3.0).len() == 1);
+}
+
+#[test]
+fn compound_cast_ray_all_hits_each_part() {
+    let cube = SharedShape::new(Cuboid::new(Vector3::repeat(0.5f32)));
+    let compound = Compound::new(vec![
+        (Isometry3::translation(0.0, 0.0, 0.0), cube.clone()),
+        (Isometry3::translation(3.0, 0.0, 0.0), cube),
+    ]);
+    let ray = Ray::new(Point3::new(-2.0, 0.0, 0.0), Vector3::x());
+
+    let hits = compound.cast_local_ray_all(&ray, f32::MAX);
+    let tois: Vec<_> = hits.iter().map(|h| h.intersection.toi).collect();
+    let expected = [1.5, 2.5, 4.5, 5.5];
+    assert_eq!(tois.len(), expected.len());
+
+    for (hit, toi) in hits.iter().zip(expected) {
+        assert!((hit.intersection.toi - toi).abs() < 1.0e-5);
+    }
+
+    assert_eq!(hits[0].subshape, 0);
+    assert_eq!(hits[3].subshape, 1);
+    assert!(hits[2].is_entering && !hits[3].is_entering);
+}
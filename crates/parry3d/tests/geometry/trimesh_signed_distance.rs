@@ -0,0 +1,68 @@
+use na::{Isometry3, Point3, Vector3};
+use parry3d::query::{self, DefaultQueryDispatcher};
+use parry3d::shape::{Ball, Cuboid, TriMesh, TriMeshFlags};
+
+fn cube(flags: TriMeshFlags) -> TriMesh<f32> {
+    let (vtx, idx) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    TriMesh::with_flags(vtx, idx, flags)
+}
+
+#[test]
+fn trimesh_signed_distance() {
+    let mesh = cube(TriMeshFlags::ORIENTED);
+    let signed_distance = |pt: Point3<f32>| mesh.signed_distance(&pt).unwrap();
+    assert!((signed_distance(Point3::origin()) + 1.0).abs() < 1.0e-5);
+    assert!((signed_distance(Point3::new(0.0, 0.5, 0.0)) + 0.5).abs() < 1.0e-5);
+    assert!((signed_distance(Point3::new(0.0, 3.0, 0.0)) - 2.0).abs() < 1.0e-5);
+
+    let pts = [Point3::origin(), Point3::new(0.0, 3.0, 0.0)];
+    assert_eq!(mesh.signed_distances(&pts).unwrap().len(), 2);
+}
+
+#[test]
+fn trimesh_signed_distance_requires_inside_test() {
+    let mesh = cube(TriMeshFlags::empty());
+    assert_eq!(mesh.signed_distance(&Point3::origin()), None);
+    assert_eq!(mesh.signed_distances(&[Point3::origin()]), None);
+}
+
+#[test]
+fn ball_inside_solid_trimesh_penetration() {
+    let mesh = cube(TriMeshFlags::ORIENTED);
+    let ball = Ball::new(0.25);
+
+    // The ball is fully enclosed by the mesh, so the surfaces don’t touch.
+    let pos12 = Isometry3::translation(0.0, 0.5, 0.0);
+    let dispatcher = DefaultQueryDispatcher;
+    let dist = query::details::distance_solid_trimesh_shape(&dispatcher, &pos12, &mesh, &ball);
+    assert!((dist + 0.75).abs() < 1.0e-3);
+
+    let contact =
+        query::details::contact_solid_trimesh_shape(&dispatcher, &pos12, &mesh, &ball, 0.0)
+            .unwrap();
+    assert!((contact.dist + 0.75).abs() < 1.0e-3);
+    assert!((contact.normal1.y - 1.0).abs() < 1.0e-3);
+}
+
+#[test]
+fn solid_trimesh_flag_in_default_dispatcher() {
+    let ball = Ball::new(0.25);
+    let ball_pos = Isometry3::translation(0.0, 0.5, 0.0);
+
+    // Without the flag, only the mesh surface is considered.
+    let surface = cube(TriMeshFlags::ORIENTED);
+    let dist = query::distance(&Isometry3::identity(), &surface, &ball_pos, &ball).unwrap();
+    assert!((dist - 0.25).abs() < 1.0e-3);
+
+    let solid = cube(TriMeshFlags::SOLID);
+    assert!(solid.flags().contains(TriMeshFlags::ORIENTED));
+
+    let dist = query::distance(&Isometry3::identity(), &solid, &ball_pos, &ball).unwrap();
+    assert_eq!(dist, 0.0);
+
+    let contact = query::contact(&ball_pos, &ball, &Isometry3::identity(), &solid, 0.0)
+        .unwrap()
+        .unwrap();
+    assert!((contact.dist + 0.75).abs() < 1.0e-3);
+    assert!((contact.normal1.y + 1.0).abs() < 1.0e-3);
+}
@@ -0,0 +1,61 @@
+use na::{Point3, Vector3};
+use parry3d::query::PointQuery;
+use parry3d::shape::{Cuboid, TriMesh, TriMeshFlags};
+
+#[test]
+fn open_trimesh_winding_number_containment() {
+    let (vtx, mut idx) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    // Punch a hole in the cube by removing one of its triangles.
+    let _ = idx.pop();
+    let mesh = TriMesh::<f32>::with_flags(vtx, idx, TriMeshFlags::WINDING_NUMBERS);
+
+    let inside = Point3::new(0.1, -0.2, 0.3);
+    let outside = Point3::new(3.0, 0.0, 0.0);
+
+    assert!((mesh.winding_number(&inside) - 0.9).abs() < 0.1);
+    assert!(mesh.winding_number(&outside).abs() < 0.1);
+    assert!((mesh.fast_winding_number(&inside) - mesh.winding_number(&inside)).abs() < 0.05);
+    assert!(mesh.contains_local_point(&inside));
+    assert!(!mesh.contains_local_point(&outside));
+}
+
+#[test]
+fn fast_winding_number_dipole_at_centroid() {
+    // The centroid of this triangle is far from the center of its Aabb, so the far-field
+    // approximation is only accurate if the dipole is placed at the centroid.
+    let vtx = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+        Point3::new(0.0, 4.0, 0.0),
+    ];
+    let mesh = TriMesh::<f32>::with_flags(vtx, vec![[0, 1, 2]], TriMeshFlags::WINDING_NUMBERS);
+
+    for pt in [Point3::new(7.0, 7.0, 3.0), Point3::new(-4.0, -4.0, 1.0)] {
+        let exact = mesh.winding_number(&pt);
+        let fast = mesh.fast_winding_number(&pt);
+        assert!((fast - exact).abs() < exact.abs() * 0.05);
+    }
+}
+
+#[test]
+fn set_flags_updates_winding_numbers_with_new_flags() {
+    let (vtx, mut idx) = Cuboid::new(Vector3::repeat(1.0)).to_trimesh();
+    // A duplicate triangle, removed by `DELETE_DUPLICATE_TRIANGLES`, forcing a Qbvh rebuild.
+    idx.push(idx[0]);
+
+    let mut mesh =
+        TriMesh::<f32>::with_flags(vtx.clone(), idx.clone(), TriMeshFlags::WINDING_NUMBERS);
+    assert!(mesh.winding_numbers().is_some());
+    mesh.set_flags(TriMeshFlags::DELETE_DUPLICATE_TRIANGLES)
+        .unwrap();
+    assert_eq!(mesh.indices().len(), 12);
+    assert!(mesh.winding_numbers().is_none());
+
+    let mut mesh = TriMesh::<f32>::new(vtx, idx);
+    assert!(mesh.winding_numbers().is_none());
+    mesh.set_flags(TriMeshFlags::DELETE_DUPLICATE_TRIANGLES | TriMeshFlags::WINDING_NUMBERS)
+        .unwrap();
+    assert_eq!(mesh.indices().len(), 12);
+    assert!(mesh.winding_numbers().is_some());
+    assert!((mesh.winding_number(&Point3::origin()) - 1.0).abs() < 1.0e-3);
+}
@@ -0,0 +1,85 @@
+use na::{Isometry3, Point3};
+use parry3d::query::{self, ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher};
+use parry3d::shape::{Ball, Voxels};
+
+fn two_voxels() -> Voxels<f32> {
+    Voxels::new(
+        Point3::origin(),
+        1.0,
+        &[Point3::new(0, 0, 0), Point3::new(1, 0, 0)],
+    )
+}
+
+#[test]
+fn voxels_shape_intersection() {
+    let voxels = two_voxels();
+    let ball = Ball::new(0.4);
+    let identity = Isometry3::identity();
+
+    // The ball dips into the top of the second voxel.
+    let touching = Isometry3::translation(1.0, 0.0, 0.8);
+    assert!(query::intersection_test(&identity, &voxels, &touching, &ball).unwrap());
+    assert!(query::intersection_test(&touching, &ball, &identity, &voxels).unwrap());
+
+    // The ball is above both voxels, within their Aabb along x and y.
+    let above = Isometry3::translation(0.5, 0.0, 1.2);
+    assert!(!query::intersection_test(&identity, &voxels, &above, &ball).unwrap());
+    assert!(!query::intersection_test(&above, &ball, &identity, &voxels).unwrap());
+}
+
+#[test]
+fn voxels_shape_contact_manifolds() {
+    let voxels = two_voxels();
+    let ball = Ball::new(0.5);
+    // The ball penetrates both voxels by 0.1.
+    let pos2 = Isometry3::translation(0.5, 0.0, 0.9);
+
+    let manifolds =
+        query::contact_manifolds(&Isometry3::identity(), &voxels, &pos2, &ball, 0.0).unwrap();
+    assert_eq!(manifolds.len(), 2);
+
+    let mut keys: Vec<_> = manifolds
+        .iter()
+        .map(|m| voxels.voxel_key(m.subshape1))
+        .collect();
+    keys.sort_by_key(|k| k.x);
+    assert_eq!(keys, vec![Point3::new(0, 0, 0), Point3::new(1, 0, 0)]);
+
+    for manifold in &manifolds {
+        assert!(!manifold.points.is_empty());
+        for point in &manifold.points {
+            assert!((point.dist + 0.1).abs() < 1.0e-4);
+        }
+    }
+}
+
+#[test]
+fn voxels_shape_contact_manifolds_after_domain_growth() {
+    let mut voxels = two_voxels();
+    let ball = Ball::new(0.5);
+    let pos12 = Isometry3::translation(0.5, 0.0, 0.9);
+    let mut manifolds: Vec<ContactManifold<(), (), f32>> = vec![];
+    let mut workspace = None;
+
+    DefaultQueryDispatcher
+        .contact_manifolds(&pos12, &voxels, &ball, 0.0, &mut manifolds, &mut workspace)
+        .unwrap();
+    assert_eq!(manifolds.len(), 2);
+    let old_id = voxels.voxel_id(&Point3::new(1, 0, 0)).unwrap();
+
+    // Growing the domain changes the identifiers of the existing voxels.
+    assert!(voxels.insert_voxel(Point3::new(-5, 0, 0)));
+    assert_ne!(voxels.voxel_id(&Point3::new(1, 0, 0)), Some(old_id));
+
+    DefaultQueryDispatcher
+        .contact_manifolds(&pos12, &voxels, &ball, 0.0, &mut manifolds, &mut workspace)
+        .unwrap();
+    assert_eq!(manifolds.len(), 2);
+
+    for manifold in &manifolds {
+        let key = voxels.voxel_key(manifold.subshape1);
+        assert!(key == Point3::new(0, 0, 0) || key == Point3::new(1, 0, 0));
+        let pos1 = manifold.subshape_pos1.unwrap();
+        assert_eq!(pos1.translation.vector, voxels.voxel_center(&key).coords);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::bounding_volume;
+use crate::bounding_volume::Aabb;
+use crate::math::Isometry;
+use crate::shape::{MinkowskiDifference, MinkowskiSum, SupportMap};
+use ad_trait::AD;
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiSum<A, B, T> {
+    /// Computes the world-space Aabb of this Minkowski sum, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space Aabb of this Minkowski sum.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb<T> {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiDifference<A, B, T> {
+    /// Computes the world-space Aabb of this Minkowski difference, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space Aabb of this Minkowski difference.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb<T> {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
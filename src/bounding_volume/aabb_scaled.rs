@@ -0,0 +1,19 @@
+use crate::bounding_volume;
+use crate::bounding_volume::Aabb;
+use crate::math::Isometry;
+use crate::shape::{Scaled, SupportMap};
+use ad_trait::AD;
+
+impl<S: SupportMap<T>, T: AD> Scaled<S, T> {
+    /// Computes the world-space Aabb of this scaled shape, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        bounding_volume::details::support_map_aabb(pos, self)
+    }
+
+    /// Computes the local-space Aabb of this scaled shape.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb<T> {
+        bounding_volume::details::local_support_map_aabb(self)
+    }
+}
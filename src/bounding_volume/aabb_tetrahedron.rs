@@ -0,0 +1,33 @@
+use crate::{
+    bounding_volume::Aabb,
+    math::{Isometry, Point, DIM},
+    shape::Tetrahedron,
+};
+use ad_trait::AD;
+
+impl<T: AD> Tetrahedron<T> {
+    /// Computes the world-space Aabb of this tetrahedron, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        self.transformed(pos).local_aabb()
+    }
+
+    /// Computes the local-space Aabb of this tetrahedron.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb<T> {
+        let a = self.a.coords;
+        let b = self.b.coords;
+        let c = self.c.coords;
+        let d = self.d.coords;
+
+        let mut min = Point::origin();
+        let mut max = Point::origin();
+
+        for k in 0..DIM {
+            min.coords[k] = a[k].min(b[k]).min(c[k]).min(d[k]);
+            max.coords[k] = a[k].max(b[k]).max(c[k]).max(d[k]);
+        }
+
+        Aabb::new(min, max)
+    }
+}
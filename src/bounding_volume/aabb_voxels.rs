@@ -0,0 +1,18 @@
+use crate::bounding_volume::Aabb;
+use crate::math::Isometry;
+use crate::shape::Voxels;
+use ad_trait::AD;
+
+impl<T: AD> Voxels<T> {
+    /// Computes the world-space Aabb of this set of voxels, transformed by `pos`.
+    #[inline]
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        self.root_aabb().transform_by(pos)
+    }
+
+    /// Computes the local-space Aabb of this set of voxels.
+    #[inline]
+    pub fn local_aabb(&self) -> Aabb<T> {
+        self.root_aabb()
+    }
+}
@@ -0,0 +1,32 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::Isometry;
+use crate::shape::{MinkowskiDifference, MinkowskiSum, SupportMap};
+use ad_trait::AD;
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiSum<A, B, T> {
+    /// Computes the world-space bounding sphere of this Minkowski sum, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<T>) -> BoundingSphere<T> {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this Minkowski sum.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_aabb().bounding_sphere()
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiDifference<A, B, T> {
+    /// Computes the world-space bounding sphere of this Minkowski difference, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<T>) -> BoundingSphere<T> {
+        self.local_bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this Minkowski difference.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_aabb().bounding_sphere()
+    }
+}
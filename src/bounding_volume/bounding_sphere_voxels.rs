@@ -0,0 +1,18 @@
+use crate::bounding_volume::BoundingSphere;
+use crate::math::Isometry;
+use crate::shape::Voxels;
+use ad_trait::AD;
+
+impl<T: AD> Voxels<T> {
+    /// Computes the world-space bounding sphere of this set of voxels, transformed by `pos`.
+    #[inline]
+    pub fn bounding_sphere(&self, pos: &Isometry<T>) -> BoundingSphere<T> {
+        self.local_aabb().bounding_sphere().transform_by(pos)
+    }
+
+    /// Computes the local-space bounding sphere of this set of voxels.
+    #[inline]
+    pub fn local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_aabb().bounding_sphere()
+    }
+}
@@ -24,9 +24,16 @@ mod aabb_convex_polyhedron;
 mod aabb_cuboid;
 mod aabb_halfspace;
 mod aabb_heightfield;
+mod aabb_minkowski_sum;
+mod aabb_scaled;
 mod aabb_support_map;
+#[cfg(feature = "dim3")]
+mod aabb_tetrahedron;
 mod aabb_triangle;
 mod aabb_utils;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod aabb_voxels;
 
 mod aabb_capsule;
 #[doc(hidden)]
@@ -46,13 +53,23 @@ mod bounding_sphere_cuboid;
 mod bounding_sphere_cylinder;
 mod bounding_sphere_halfspace;
 mod bounding_sphere_heightfield;
+mod bounding_sphere_minkowski_sum;
 #[cfg(feature = "std")]
 mod bounding_sphere_polyline;
+mod bounding_sphere_scaled;
 mod bounding_sphere_segment;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod bounding_sphere_tetmesh;
+#[cfg(feature = "dim3")]
+mod bounding_sphere_tetrahedron;
 mod bounding_sphere_triangle;
 #[cfg(feature = "std")]
 mod bounding_sphere_trimesh;
 mod bounding_sphere_utils;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod bounding_sphere_voxels;
 mod simd_aabb;
 
 /// Free functions for some special cases of bounding-volume computation.
@@ -68,6 +68,8 @@ pub extern crate simba;
 pub mod bounding_volume;
 pub mod mass_properties;
 pub mod partitioning;
+#[cfg(feature = "std")]
+pub mod pipeline;
 pub mod query;
 pub mod shape;
 #[cfg(feature = "std")]
@@ -218,6 +218,52 @@ impl<T: AD> MassProperties<T> {
         }
     }
 
+    /// Computes the mass properties of the shape obtained by scaling `self` along
+    /// its local coordinate axes, assuming its density is left unchanged.
+    #[cfg(feature = "dim3")]
+    pub fn scaled(&self, scale: &Vector<T>) -> Self {
+        let volume_ratio = (scale.x * scale.y * scale.z).abs();
+        let inertia = self.reconstruct_inertia_matrix();
+        // The inertia tensor is `tr(C) * Id - C` where `C` is the (mass-weighted)
+        // covariance matrix of the shape. The covariance matrix scales as `Λ C Λ`.
+        let covariance =
+            Matrix3::from_diagonal_element(inertia.trace() * T::constant(0.5)) - inertia;
+        let scale_matrix = Matrix3::from_diagonal(scale);
+        let scaled_covariance = scale_matrix * covariance * scale_matrix * volume_ratio;
+        let scaled_inertia =
+            Matrix3::from_diagonal_element(scaled_covariance.trace()) - scaled_covariance;
+
+        Self::with_inertia_matrix(
+            self.local_com.coords.component_mul(scale).into(),
+            self.mass() * volume_ratio,
+            scaled_inertia,
+        )
+    }
+
+    /// Computes the mass properties of the shape obtained by scaling `self` uniformly
+    /// along its local coordinate axes, assuming its density is left unchanged.
+    ///
+    /// In 2D, the angular inertia of a shape scaled non-uniformly can’t be deduced from its
+    /// mass properties, so the scaling factors may only differ by their signs.
+    ///
+    /// # Panics
+    /// Panics if the absolute values of the components of `scale` aren’t all equal.
+    #[cfg(feature = "dim2")]
+    pub fn scaled(&self, scale: &Vector<T>) -> Self {
+        assert!(
+            scale.x.abs() == scale.y.abs(),
+            "The scaling must be uniform to scale 2D mass properties."
+        );
+        let volume_ratio = (scale.x * scale.y).abs();
+        let scaled_inertia = self.principal_inertia() * volume_ratio * scale.x * scale.x;
+
+        Self::new(
+            self.local_com.coords.component_mul(scale).into(),
+            self.mass() * volume_ratio,
+            scaled_inertia,
+        )
+    }
+
     /// Changes the mass on these mass-properties.
     ///
     /// The `adjust_angular_inertia` argument should always be `true`, unless
@@ -511,6 +557,8 @@ mod test {
     use crate::math::Point;
     #[cfg(feature = "dim3")]
     use crate::math::{Rotation, Vector};
+    #[cfg(feature = "dim3")]
+    use crate::shape::Cuboid;
     use crate::shape::{Ball, Capsule, Shape};
     use approx::assert_relative_eq;
     use num::Zero;
@@ -586,4 +634,18 @@ mod test {
         #[cfg(feature = "dim3")]
         assert!(!mp.local_com.z.is_nan());
     }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn mass_properties_scaled_cuboid() {
+        let scale = Vector::new(2.0, 0.5, -3.0);
+        let cuboid = Cuboid::new(Vector::new(1.0, 2.0, 3.0));
+        let scaled_cuboid = Cuboid::new(cuboid.half_extents.component_mul(&scale).abs());
+
+        assert_relative_eq!(
+            cuboid.mass_properties(2.0).scaled(&scale),
+            scaled_cuboid.mass_properties(2.0),
+            epsilon = 1.0e-6
+        );
+    }
 }
@@ -0,0 +1,59 @@
+use crate::mass_properties::MassProperties;
+use crate::math::{Matrix, Point, Vector};
+use crate::shape::HeightField;
+use ad_trait::AD;
+
+impl<T: AD> MassProperties<T> {
+    /// Computes the mass properties of a heightfield dilated by a sphere of radius `border_radius`.
+    ///
+    /// Each triangle is approximated by a slab of thickness `2 * border_radius`, so the rounded
+    /// borders of the heightfield, and the overlaps between adjacent slabs, are neglected.
+    pub fn from_round_heightfield(
+        density: T,
+        heightfield: &HeightField<T>,
+        border_radius: T,
+    ) -> Self {
+        let thickness = border_radius * T::constant(2.0);
+        let twelve = T::constant(12.0);
+        let mut mass = T::zero();
+        let mut first_moment = Vector::<T>::zeros();
+        let mut second_moment = Matrix::<T>::zeros();
+
+        for triangle in heightfield.triangles() {
+            let area = triangle.area();
+            let tri_mass = density * area * thickness;
+            let center = triangle.center();
+
+            // Second moment of the slab with respect to its center.
+            let mut tri_second_moment = Matrix::<T>::zeros();
+
+            for pt in [triangle.a, triangle.b, triangle.c] {
+                let d = pt - center;
+                tri_second_moment += d * d.transpose();
+            }
+
+            tri_second_moment *= tri_mass / twelve;
+
+            if let Some(normal) = triangle.normal() {
+                tri_second_moment += normal.into_inner()
+                    * normal.transpose()
+                    * (tri_mass * thickness * thickness / twelve);
+            }
+
+            mass += tri_mass;
+            first_moment += center.coords * tri_mass;
+            second_moment +=
+                tri_second_moment + center.coords * center.coords.transpose() * tri_mass;
+        }
+
+        if mass <= T::zero() {
+            return MassProperties::zero();
+        }
+
+        let com = Point::from(first_moment / mass);
+        let centered_second_moment = second_moment - com.coords * com.coords.transpose() * mass;
+        let inertia = Matrix::identity() * centered_second_moment.trace() - centered_second_moment;
+
+        MassProperties::with_inertia_matrix(com, mass, inertia)
+    }
+}
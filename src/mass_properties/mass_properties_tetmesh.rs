@@ -0,0 +1,88 @@
+use super::mass_properties_trimesh3d::tetrahedron_unit_inertia_tensor_wrt_point;
+use crate::mass_properties::MassProperties;
+use crate::math::{Matrix, Point};
+use crate::shape::Tetrahedron;
+use ad_trait::AD;
+
+impl<T: AD> MassProperties<T> {
+    /// Computes the mass properties of a tetrahedral mesh.
+    ///
+    /// The tetrahedra may have any orientation.
+    pub fn from_tetmesh(
+        density: T,
+        vertices: &[Point<T>],
+        indices: &[[u32; 4]],
+    ) -> MassProperties<T> {
+        let tetrahedra = indices.iter().map(|idx| {
+            Tetrahedron::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+                vertices[idx[3] as usize],
+            )
+        });
+
+        let mut volume = T::zero();
+        let mut com = Point::origin();
+
+        for tetrahedron in tetrahedra.clone() {
+            let vol = tetrahedron.volume();
+            com += tetrahedron.center().coords * vol;
+            volume += vol;
+        }
+
+        if volume.is_zero() {
+            return MassProperties::zero();
+        }
+
+        com /= volume;
+        let mut itot = Matrix::zeros();
+
+        for tetrahedron in tetrahedra {
+            let ipart = tetrahedron_unit_inertia_tensor_wrt_point(
+                &com,
+                &tetrahedron.a,
+                &tetrahedron.b,
+                &tetrahedron.c,
+                &tetrahedron.d,
+            );
+            itot += ipart * tetrahedron.volume();
+        }
+
+        Self::with_inertia_matrix(com, volume * density, itot * density)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::mass_properties::MassProperties;
+    use crate::math::{Point, Vector};
+    use crate::shape::{Cuboid, Shape};
+
+    #[test]
+    fn tetmesh_cube_mprops() {
+        // A unit cube split into five tetrahedra.
+        let vertices = vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(0.0, 1.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+            Point::new(1.0, 0.0, 1.0),
+            Point::new(1.0, 1.0, 1.0),
+            Point::new(0.0, 1.0, 1.0),
+        ];
+        let indices = [[0, 1, 3, 4], [1, 2, 3, 6], [1, 4, 5, 6], [3, 4, 6, 7], [1, 3, 4, 6]];
+
+        let mprops = MassProperties::from_tetmesh(2.0, &vertices, &indices);
+        let expected = Cuboid::new(Vector::repeat(0.5)).mass_properties(2.0);
+
+        assert_relative_eq!(mprops.mass(), expected.mass(), epsilon = 1.0e-5);
+        assert_relative_eq!(mprops.local_com, Point::new(0.5, 0.5, 0.5), epsilon = 1.0e-5);
+        assert_relative_eq!(
+            mprops.reconstruct_inertia_matrix(),
+            expected.reconstruct_inertia_matrix(),
+            epsilon = 1.0e-5
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::mass_properties::MassProperties;
+use crate::math::Isometry;
+use crate::shape::Voxels;
+use ad_trait::AD;
+
+impl<T: AD> MassProperties<T> {
+    /// Computes the mass properties of a set of voxels.
+    pub fn from_voxels(density: T, voxels: &Voxels<T>) -> Self {
+        let unit = MassProperties::from_cuboid(density, voxels.voxel_cuboid().half_extents);
+        voxels
+            .voxels()
+            .map(|key| unit.transform_by(&Isometry::from(voxels.voxel_center(&key).coords)))
+            .sum()
+    }
+}
@@ -17,6 +17,12 @@ mod mass_properties_convex_polygon;
 mod mass_properties_convex_polyhedron;
 mod mass_properties_cuboid;
 mod mass_properties_cylinder;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod mass_properties_round_heightfield;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod mass_properties_tetmesh;
 #[cfg(feature = "dim2")]
 mod mass_properties_triangle;
 #[cfg(feature = "dim2")]
@@ -25,6 +31,9 @@ mod mass_properties_trimesh2d;
 #[cfg(feature = "dim3")]
 #[cfg(feature = "std")]
 mod mass_properties_trimesh3d;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod mass_properties_voxels;
 
 /// Free functions for some special-cases of mass-properties computation.
 pub mod details {
@@ -0,0 +1,65 @@
+use crate::bounding_volume::{Aabb, BoundingVolume};
+use ad_trait::AD;
+
+/// A change of the set of pairs of objects with overlapping Aabbs.
+///
+/// The handles of a pair are always given in increasing order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BroadPhasePairEvent {
+    /// The Aabbs of both objects started overlapping.
+    AddPair(u32, u32),
+    /// The Aabbs of both objects stopped overlapping, or one of the objects was removed.
+    DeletePair(u32, u32),
+}
+
+/// A broad-phase, tracking the pairs of objects with overlapping Aabbs across frames.
+///
+/// Each object is identified by a user-provided `u32` handle. Its Aabb is enlarged by a
+/// margin when it is inserted, and the broad-phase only needs to do some work for this
+/// object when its Aabb leaves that enlarged Aabb. Pairs are reported when their enlarged
+/// Aabbs overlap, so they may be reported slightly before their actual Aabbs overlap.
+pub trait BroadPhase<T: AD> {
+    /// Updates the broad-phase and reports the changes of the set of overlapping pairs.
+    ///
+    /// # Parameters
+    /// * `aabbs` - The current Aabbs of the objects inserted or moved since the last update.
+    ///   The Aabbs of the objects that didn’t move don’t have to be given again.
+    /// * `removed` - The handles of the objects removed since the last update.
+    /// * `events` - The output buffer where the pair events are pushed.
+    fn update(
+        &mut self,
+        aabbs: &[(u32, Aabb<T>)],
+        removed: &[u32],
+        events: &mut Vec<BroadPhasePairEvent>,
+    );
+
+    /// The enlarged Aabb of the object with the given handle, if it exists.
+    fn enlarged_aabb(&self, handle: u32) -> Option<&Aabb<T>>;
+
+    /// Calls `f` on every pair of objects with overlapping enlarged Aabbs.
+    fn for_each_pair(&self, f: &mut dyn FnMut(u32, u32));
+}
+
+/// Replaces the enlarged Aabb of `handle` by `aabb` loosened by `margin`, if `aabb` left it.
+///
+/// Returns `true` if the enlarged Aabb was modified.
+pub(crate) fn update_enlarged_aabb<T: AD>(
+    enlarged_aabbs: &mut Vec<Option<Aabb<T>>>,
+    handle: u32,
+    aabb: &Aabb<T>,
+    margin: T,
+) -> bool {
+    let i = handle as usize;
+
+    if enlarged_aabbs.len() <= i {
+        enlarged_aabbs.resize(i + 1, None);
+    }
+
+    match &enlarged_aabbs[i] {
+        Some(enlarged) if enlarged.contains(aabb) => false,
+        _ => {
+            enlarged_aabbs[i] = Some(aabb.loosened(margin));
+            true
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use super::broad_phase::update_enlarged_aabb;
+use crate::bounding_volume::{Aabb, BoundingVolume};
+use crate::partitioning::{BroadPhase, BroadPhasePairEvent, Qbvh, QbvhUpdateWorkspace};
+use crate::utils::hashmap::HashMap;
+use crate::utils::SortedPair;
+use ad_trait::AD;
+
+/// A broad-phase based on a dynamic `Qbvh`.
+///
+/// The enlarged Aabbs of the objects are stored in a `Qbvh` which is refitted and rebalanced
+/// at each update. Only the objects that left their enlarged Aabb are queried against the tree,
+/// which makes this broad-phase well suited for scenes where most objects don’t move.
+pub struct BroadPhaseBvh<T: AD> {
+    margin: T,
+    qbvh: Qbvh<u32, T>,
+    workspace: QbvhUpdateWorkspace<T>,
+    enlarged_aabbs: Vec<Option<Aabb<T>>>,
+    pairs: HashMap<SortedPair<u32>, ()>,
+    moved: Vec<u32>,
+}
+
+impl<T: AD> BroadPhaseBvh<T> {
+    /// Creates an empty broad-phase enlarging the Aabbs of the objects by `margin`.
+    pub fn new(margin: T) -> Self {
+        Self {
+            margin,
+            qbvh: Qbvh::new(),
+            workspace: QbvhUpdateWorkspace::default(),
+            enlarged_aabbs: vec![],
+            pairs: HashMap::default(),
+            moved: vec![],
+        }
+    }
+
+    /// The margin the Aabbs of the objects are enlarged by.
+    pub fn margin(&self) -> T {
+        self.margin
+    }
+
+    /// The `Qbvh` containing the enlarged Aabbs of all the objects.
+    pub fn qbvh(&self) -> &Qbvh<u32, T> {
+        &self.qbvh
+    }
+
+    /// Applies the insertions, motions and removals to the `Qbvh`, and collects the objects
+    /// that moved out of their enlarged Aabb into `self.moved`.
+    fn update_qbvh(&mut self, aabbs: &[(u32, Aabb<T>)], removed: &[u32]) {
+        self.moved.clear();
+
+        for handle in removed {
+            if let Some(aabb) = self.enlarged_aabbs.get_mut(*handle as usize) {
+                if aabb.take().is_some() {
+                    let _ = self.qbvh.remove(*handle);
+                }
+            }
+        }
+
+        for (handle, aabb) in aabbs {
+            if update_enlarged_aabb(&mut self.enlarged_aabbs, *handle, aabb, self.margin) {
+                self.qbvh.pre_update_or_insert(*handle);
+                self.moved.push(*handle);
+            }
+        }
+
+        let enlarged_aabbs = &self.enlarged_aabbs;
+        let _ = self.qbvh.refit(T::zero(), &mut self.workspace, |handle| {
+            enlarged_aabbs[*handle as usize].unwrap_or_else(Aabb::new_invalid)
+        });
+
+        if !self.moved.is_empty() {
+            self.qbvh.rebalance(T::zero(), &mut self.workspace);
+        }
+    }
+
+    /// Registers the new overlaps found for the objects that moved, and removes the pairs that
+    /// don’t overlap anymore.
+    fn update_pairs(
+        &mut self,
+        overlaps: impl Iterator<Item = (u32, u32)>,
+        events: &mut Vec<BroadPhasePairEvent>,
+    ) {
+        for (handle1, handle2) in overlaps {
+            if handle1 != handle2 {
+                let pair = SortedPair::new(handle1, handle2);
+
+                if self.pairs.insert(pair, ()).is_none() {
+                    events.push(BroadPhasePairEvent::AddPair(pair.0, pair.1));
+                }
+            }
+        }
+
+        let enlarged_aabbs = &self.enlarged_aabbs;
+        self.pairs.retain(|pair, _| {
+            let aabb1 = enlarged_aabbs[pair.0 as usize];
+            let aabb2 = enlarged_aabbs[pair.1 as usize];
+            let keep = match (aabb1, aabb2) {
+                (Some(aabb1), Some(aabb2)) => aabb1.intersects(&aabb2),
+                _ => false,
+            };
+
+            if !keep {
+                events.push(BroadPhasePairEvent::DeletePair(pair.0, pair.1));
+            }
+
+            keep
+        });
+    }
+
+    /// Updates the broad-phase, querying the moved objects against the `Qbvh` in parallel.
+    ///
+    /// See [`BroadPhase::update`] for details.
+    #[cfg(feature = "parallel")]
+    pub fn par_update(
+        &mut self,
+        aabbs: &[(u32, Aabb<T>)],
+        removed: &[u32],
+        events: &mut Vec<BroadPhasePairEvent>,
+    ) where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        self.update_qbvh(aabbs, removed);
+
+        let qbvh = &self.qbvh;
+        let enlarged_aabbs = &self.enlarged_aabbs;
+        let found: Vec<Vec<u32>> = self
+            .moved
+            .par_iter()
+            .map(|handle1| {
+                let mut found = vec![];
+                if let Some(aabb) = &enlarged_aabbs[*handle1 as usize] {
+                    qbvh.intersect_aabb(aabb, &mut found);
+                }
+                found
+            })
+            .collect();
+        let overlaps: Vec<_> = self
+            .moved
+            .iter()
+            .zip(found.iter())
+            .flat_map(|(handle1, found)| found.iter().map(move |handle2| (*handle1, *handle2)))
+            .collect();
+
+        self.update_pairs(overlaps.into_iter(), events);
+    }
+}
+
+impl<T: AD> BroadPhase<T> for BroadPhaseBvh<T> {
+    fn update(
+        &mut self,
+        aabbs: &[(u32, Aabb<T>)],
+        removed: &[u32],
+        events: &mut Vec<BroadPhasePairEvent>,
+    ) {
+        self.update_qbvh(aabbs, removed);
+
+        let mut overlaps = vec![];
+        let mut found = vec![];
+
+        for handle1 in &self.moved {
+            if let Some(aabb) = &self.enlarged_aabbs[*handle1 as usize] {
+                found.clear();
+                self.qbvh.intersect_aabb(aabb, &mut found);
+                overlaps.extend(found.iter().map(|handle2| (*handle1, *handle2)));
+            }
+        }
+
+        self.update_pairs(overlaps.into_iter(), events);
+    }
+
+    fn enlarged_aabb(&self, handle: u32) -> Option<&Aabb<T>> {
+        self.enlarged_aabbs.get(handle as usize)?.as_ref()
+    }
+
+    fn for_each_pair(&self, f: &mut dyn FnMut(u32, u32)) {
+        for pair in self.pairs.keys() {
+            f(pair.0, pair.1)
+        }
+    }
+}
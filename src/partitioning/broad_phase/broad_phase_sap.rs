@@ -0,0 +1,195 @@
+use super::broad_phase::update_enlarged_aabb;
+use crate::bounding_volume::{Aabb, BoundingVolume};
+use crate::partitioning::{BroadPhase, BroadPhasePairEvent};
+use crate::utils::hashmap::HashMap;
+use crate::utils::SortedPair;
+use ad_trait::AD;
+
+/// An end of the projection of an enlarged Aabb on the sweep axis.
+#[derive(Copy, Clone, Debug)]
+struct SapEndpoint<T: AD> {
+    value: T,
+    handle: u32,
+    is_min: bool,
+}
+
+/// A broad-phase based on sweep-and-prune along the first coordinate axis.
+///
+/// The ends of the enlarged Aabbs projected on the sweep axis are kept sorted with an
+/// insertion sort, which runs in nearly linear time when the objects move coherently between
+/// two updates. A full sort is used instead when many objects are added at once. This broad-phase is well suited for scenes where most objects move a bit at
+/// each update.
+pub struct BroadPhaseSap<T: AD> {
+    margin: T,
+    enlarged_aabbs: Vec<Option<Aabb<T>>>,
+    endpoints: Vec<SapEndpoint<T>>,
+    pairs: HashMap<SortedPair<u32>, ()>,
+    new_pairs: HashMap<SortedPair<u32>, ()>,
+    active: Vec<u32>,
+}
+
+impl<T: AD> BroadPhaseSap<T> {
+    /// Creates an empty broad-phase enlarging the Aabbs of the objects by `margin`.
+    pub fn new(margin: T) -> Self {
+        Self {
+            margin,
+            enlarged_aabbs: vec![],
+            endpoints: vec![],
+            pairs: HashMap::default(),
+            new_pairs: HashMap::default(),
+            active: vec![],
+        }
+    }
+
+    /// The margin the Aabbs of the objects are enlarged by.
+    pub fn margin(&self) -> T {
+        self.margin
+    }
+
+    fn sort_endpoints(&mut self, num_new_endpoints: usize) {
+        // On ties, minimums go first so that touching Aabbs are reported as overlapping.
+        let before = |a: &SapEndpoint<T>, b: &SapEndpoint<T>| {
+            a.value < b.value || (a.value == b.value && a.is_min && !b.is_min)
+        };
+
+        if num_new_endpoints * 4 > self.endpoints.len() {
+            // Many endpoints were just added at the end: they are far from their sorted
+            // position, so the insertion sort would be quadratic.
+            self.endpoints.sort_unstable_by(|a, b| {
+                if before(a, b) {
+                    std::cmp::Ordering::Less
+                } else if before(b, a) {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            });
+            return;
+        }
+
+        // Insertion sort: the endpoints are almost sorted already.
+        for i in 1..self.endpoints.len() {
+            let mut j = i;
+
+            while j > 0 && before(&self.endpoints[j], &self.endpoints[j - 1]) {
+                self.endpoints.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+}
+
+impl<T: AD> BroadPhase<T> for BroadPhaseSap<T> {
+    fn update(
+        &mut self,
+        aabbs: &[(u32, Aabb<T>)],
+        removed: &[u32],
+        events: &mut Vec<BroadPhasePairEvent>,
+    ) {
+        for handle in removed {
+            if let Some(aabb) = self.enlarged_aabbs.get_mut(*handle as usize) {
+                *aabb = None;
+            }
+        }
+
+        let enlarged_aabbs = &self.enlarged_aabbs;
+        self.endpoints
+            .retain(|endpoint| enlarged_aabbs[endpoint.handle as usize].is_some());
+
+        let mut moved = false;
+        let mut num_new_endpoints = 0;
+
+        for (handle, aabb) in aabbs {
+            let is_new = self
+                .enlarged_aabbs
+                .get(*handle as usize)
+                .map(|aabb| aabb.is_none())
+                .unwrap_or(true);
+
+            if update_enlarged_aabb(&mut self.enlarged_aabbs, *handle, aabb, self.margin) {
+                moved = true;
+
+                if is_new {
+                    num_new_endpoints += 2;
+
+                    for is_min in [true, false] {
+                        self.endpoints.push(SapEndpoint {
+                            value: T::zero(),
+                            handle: *handle,
+                            is_min,
+                        });
+                    }
+                }
+            }
+        }
+
+        if !moved && removed.is_empty() {
+            // The overlapping pairs didn’t change.
+            return;
+        }
+
+        for endpoint in &mut self.endpoints {
+            let aabb = self.enlarged_aabbs[endpoint.handle as usize]
+                .as_ref()
+                .unwrap();
+            endpoint.value = if endpoint.is_min {
+                aabb.mins[0]
+            } else {
+                aabb.maxs[0]
+            };
+        }
+
+        self.sort_endpoints(num_new_endpoints);
+
+        // Sweep along the axis, testing each object against all the objects whose
+        // projection contains its minimum.
+        self.active.clear();
+        self.new_pairs.clear();
+
+        for endpoint in &self.endpoints {
+            if endpoint.is_min {
+                let aabb1 = self.enlarged_aabbs[endpoint.handle as usize]
+                    .as_ref()
+                    .unwrap();
+
+                for handle2 in &self.active {
+                    let aabb2 = self.enlarged_aabbs[*handle2 as usize].as_ref().unwrap();
+
+                    if aabb1.intersects(aabb2) {
+                        let _ = self
+                            .new_pairs
+                            .insert(SortedPair::new(endpoint.handle, *handle2), ());
+                    }
+                }
+
+                self.active.push(endpoint.handle);
+            } else if let Some(i) = self.active.iter().position(|h| *h == endpoint.handle) {
+                let _ = self.active.swap_remove(i);
+            }
+        }
+
+        for pair in self.new_pairs.keys() {
+            if !self.pairs.contains_key(pair) {
+                events.push(BroadPhasePairEvent::AddPair(pair.0, pair.1));
+            }
+        }
+
+        for pair in self.pairs.keys() {
+            if !self.new_pairs.contains_key(pair) {
+                events.push(BroadPhasePairEvent::DeletePair(pair.0, pair.1));
+            }
+        }
+
+        std::mem::swap(&mut self.pairs, &mut self.new_pairs);
+    }
+
+    fn enlarged_aabb(&self, handle: u32) -> Option<&Aabb<T>> {
+        self.enlarged_aabbs.get(handle as usize)?.as_ref()
+    }
+
+    fn for_each_pair(&self, f: &mut dyn FnMut(u32, u32)) {
+        for pair in self.pairs.keys() {
+            f(pair.0, pair.1)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Broad-phase collision detection, tracking the pairs of objects with overlapping Aabbs.
+
+pub use self::broad_phase::{BroadPhase, BroadPhasePairEvent};
+pub use self::broad_phase_bvh::BroadPhaseBvh;
+pub use self::broad_phase_sap::BroadPhaseSap;
+
+mod broad_phase;
+mod broad_phase_bvh;
+mod broad_phase_sap;
@@ -1,5 +1,7 @@
 //! Spatial partitioning tools.
 
+#[cfg(feature = "std")]
+pub use self::broad_phase::{BroadPhase, BroadPhaseBvh, BroadPhasePairEvent, BroadPhaseSap};
 #[cfg(feature = "std")]
 pub use self::qbvh::{
     CenterDataSplitter, QbvhDataGenerator, QbvhNonOverlappingDataSplitter, QbvhUpdateWorkspace,
@@ -19,5 +21,7 @@ pub use self::visitor::{
 #[deprecated(note = "Renamed to Qbvh")]
 pub type SimdQbvh<T, A> = Qbvh<T, A>;
 
+#[cfg(feature = "std")]
+mod broad_phase;
 mod qbvh;
 mod visitor;
@@ -401,6 +401,16 @@ impl<LeafData: IndexedData, T: AD, Storage: QbvhStorage<LeafData, T>> GenericQbv
     pub fn root_aabb(&self) -> &Aabb<T> {
         &self.root_aabb
     }
+
+    /// The raw nodes of this BVH, regardless of its storage.
+    pub(crate) fn raw_nodes_storage(&self) -> &Storage::Nodes {
+        &self.nodes
+    }
+
+    /// The raw proxies of this BVH, regardless of its storage.
+    pub(crate) fn raw_proxies_storage(&self) -> &Storage::ArrayProxies {
+        &self.proxies
+    }
 }
 
 #[cfg(test)]
@@ -420,4 +430,26 @@ mod test {
             tree.clear_and_rebuild((0..k).map(|i| (i, aabb)), 0.0);
         }
     }
+
+    #[test]
+    fn knn_and_within_radius() {
+        let points: Vec<Point<f32>> = (0..10).map(|i| Point::splat(i as f32)).collect();
+        let mut tree = Qbvh::new();
+        tree.clear_and_rebuild(
+            points.iter().enumerate().map(|(i, pt)| (i as u32, Aabb::new(*pt, *pt))),
+            0.0,
+        );
+
+        let query = Point::splat(3.2);
+        let dist = |i: &u32| Some(na::distance(&points[*i as usize], &query));
+
+        let knn = tree.knn(&query, 3, dist);
+        let ids: Vec<_> = knn.iter().map(|(i, _)| *i).collect();
+        assert_eq!(ids, vec![3, 4, 2]);
+
+        let radius = Vector::<f32>::repeat(1.0).norm();
+        let within = tree.within_radius(&query, radius, dist);
+        let ids: Vec<_> = within.iter().map(|(i, _)| *i).collect();
+        assert_eq!(ids, vec![3, 4]);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::bounding_volume::{Aabb, SimdAabb};
+use crate::math::Point;
 use crate::partitioning::visitor::SimdSimultaneousVisitStatus;
 use crate::partitioning::{
     GenericQbvh, QbvhStorage, SimdBestFirstVisitStatus, SimdBestFirstVisitor,
@@ -241,6 +242,104 @@ impl<LeafData: IndexedData, T: AD, Storage: QbvhStorage<LeafData, T>> GenericQbv
         }
     }
 
+    /// Finds the `k` leaves closest to `point`.
+    ///
+    /// The distance between `point` and the leaf with the given data is computed by `leaf_dist`,
+    /// which may return `None` to skip this leaf. This distance must not be smaller than the
+    /// distance between `point` and the leaf’s Aabb.
+    ///
+    /// Returns the data of the closest leaves with their distances, sorted by increasing distance.
+    pub fn knn(
+        &self,
+        point: &Point<T>,
+        k: usize,
+        leaf_dist: impl FnMut(&LeafData) -> Option<T>,
+    ) -> Vec<(LeafData, T)> {
+        self.nearest_leaves(point, k, T::constant(f64::max_value()), leaf_dist)
+    }
+
+    /// Finds all the leaves closer than `radius` to `point`.
+    ///
+    /// The distance between `point` and the leaf with the given data is computed by `leaf_dist`,
+    /// with the same requirements as for [`Self::knn`].
+    ///
+    /// Returns the data of the leaves with their distances, sorted by increasing distance.
+    pub fn within_radius(
+        &self,
+        point: &Point<T>,
+        radius: T,
+        leaf_dist: impl FnMut(&LeafData) -> Option<T>,
+    ) -> Vec<(LeafData, T)> {
+        self.nearest_leaves(point, usize::MAX, radius, leaf_dist)
+    }
+
+    /// Best-first search of the (at most) `k` leaves closest to `point` within `radius`.
+    fn nearest_leaves(
+        &self,
+        point: &Point<T>,
+        k: usize,
+        radius: T,
+        mut leaf_dist: impl FnMut(&LeafData) -> Option<T>,
+    ) -> Vec<(LeafData, T)> {
+        if self.nodes.is_empty() || k == 0 {
+            return vec![];
+        }
+
+        // Max-heap of the best results found so far: its top is the furthest one.
+        let mut found: BinaryHeap<WeightedValue<LeafData, T>> = BinaryHeap::new();
+        let mut queue: BinaryHeap<WeightedValue<u32, T>> = BinaryHeap::new();
+        queue.push(WeightedValue::new(0, T::zero()));
+
+        while let Some(entry) = queue.pop() {
+            let bound = match found.peek() {
+                Some(furthest) if found.len() == k => furthest.cost,
+                _ => radius,
+            };
+
+            if -entry.cost > bound {
+                // No node left in the queue can contain a closer leaf.
+                break;
+            }
+
+            let node = &self.nodes[entry.value as usize];
+            let dists: [T; SIMD_WIDTH] = [node.simd_aabb.distance_to_local_point(point)];
+
+            for ii in 0..SIMD_WIDTH {
+                if dists[ii] > bound {
+                    continue;
+                }
+
+                if node.is_leaf() {
+                    // Unfortunately, invalid Aabbs may pass the distance test as well.
+                    if let Some(proxy) = self.proxies.get_at(node.children[ii] as usize) {
+                        if let Some(dist) = leaf_dist(&proxy.data) {
+                            let bound = match found.peek() {
+                                Some(furthest) if found.len() == k => furthest.cost,
+                                _ => radius,
+                            };
+
+                            if dist <= bound {
+                                found.push(WeightedValue::new(proxy.data, dist));
+
+                                if found.len() > k {
+                                    let _ = found.pop();
+                                }
+                            }
+                        }
+                    }
+                } else if (node.children[ii] as usize) < self.nodes.len() {
+                    queue.push(WeightedValue::new(node.children[ii], -dists[ii]));
+                }
+            }
+        }
+
+        found
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| (entry.value, entry.cost))
+            .collect()
+    }
+
     /// Performs a simultaneous traversal of two Qbvh.
     pub fn traverse_bvtt<LeafData2: IndexedData>(
         &self,
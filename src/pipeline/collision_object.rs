@@ -0,0 +1,53 @@
+use crate::math::Isometry;
+use crate::pipeline::InteractionGroups;
+use crate::shape::SharedShape;
+use ad_trait::AD;
+
+/// The handle of an object inserted into a [`CollisionWorld`](crate::pipeline::CollisionWorld).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct CollisionObjectHandle(pub u32);
+
+/// An object of a [`CollisionWorld`](crate::pipeline::CollisionWorld): a shape at some
+/// position, with its interaction groups and some user data.
+#[derive(Clone)]
+pub struct CollisionObject<T: AD, U> {
+    pub(crate) shape: SharedShape<T>,
+    pub(crate) position: Isometry<T>,
+    pub(crate) groups: InteractionGroups,
+    pub(crate) modified: bool,
+    /// The user data associated to this object.
+    pub data: U,
+}
+
+impl<T: AD, U> CollisionObject<T, U> {
+    pub(crate) fn new(
+        shape: SharedShape<T>,
+        position: Isometry<T>,
+        groups: InteractionGroups,
+        data: U,
+    ) -> Self {
+        Self {
+            shape,
+            position,
+            groups,
+            modified: true,
+            data,
+        }
+    }
+
+    /// The shape of this object.
+    pub fn shape(&self) -> &SharedShape<T> {
+        &self.shape
+    }
+
+    /// The position of this object.
+    pub fn position(&self) -> &Isometry<T> {
+        &self.position
+    }
+
+    /// The interaction groups of this object.
+    pub fn groups(&self) -> InteractionGroups {
+        self.groups
+    }
+}
@@ -0,0 +1,637 @@
+use crate::bounding_volume::{Aabb, BoundingVolume, SimdAabb};
+use crate::math::{Isometry, Point, Vector, SIMD_WIDTH};
+use crate::partitioning::{
+    BroadPhase, BroadPhaseBvh, BroadPhasePairEvent, SimdBestFirstVisitStatus, SimdBestFirstVisitor,
+};
+use crate::pipeline::{CollisionObject, CollisionObjectHandle, InteractionGroups};
+use crate::query::contact_manifolds::ContactManifoldsWorkspace;
+use crate::query::visitors::RayIntersectionsVisitor;
+use crate::query::{
+    ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, PointProjection,
+    PointQuery, Ray, RayCast, RayIntersection, TOI,
+};
+use crate::shape::{Shape, SharedShape};
+use crate::utils::hashmap::HashMap;
+use crate::utils::SortedPair;
+use ad_trait::AD;
+use simba::simd::{SimdBool as _, SimdValue};
+
+/// A change of the contact status of a pair of objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContactEvent {
+    /// The objects started touching.
+    Started(CollisionObjectHandle, CollisionObjectHandle),
+    /// The objects stopped touching, or one of them was removed.
+    Stopped(CollisionObjectHandle, CollisionObjectHandle),
+}
+
+/// The contacts between a pair of objects with overlapping Aabbs.
+pub struct ContactPair<T: AD> {
+    /// The first object of the pair.
+    pub handle1: CollisionObjectHandle,
+    /// The second object of the pair.
+    pub handle2: CollisionObjectHandle,
+    /// The contact manifolds between both objects, in their local-spaces.
+    ///
+    /// They also contain the contacts closer than the prediction distance of the world.
+    pub manifolds: Vec<ContactManifold<(), (), T>>,
+    workspace: Option<ContactManifoldsWorkspace<T>>,
+    has_contact: bool,
+}
+
+impl<T: AD> ContactPair<T> {
+    fn new(handle1: CollisionObjectHandle, handle2: CollisionObjectHandle) -> Self {
+        Self {
+            handle1,
+            handle2,
+            manifolds: vec![],
+            workspace: None,
+            has_contact: false,
+        }
+    }
+
+    /// Are both objects touching, i.e., is any of the contacts penetrating or touching?
+    pub fn has_contact(&self) -> bool {
+        self.has_contact
+    }
+
+    /// The contact of this pair with the smallest distance.
+    pub fn deepest_contact(&self) -> Option<(&ContactManifold<(), (), T>, T)> {
+        let mut deepest: Option<(&ContactManifold<(), (), T>, T)> = None;
+
+        for manifold in &self.manifolds {
+            for pt in &manifold.points {
+                if deepest.map(|(_, dist)| pt.dist < dist).unwrap_or(true) {
+                    deepest = Some((manifold, pt.dist));
+                }
+            }
+        }
+
+        deepest
+    }
+}
+
+/// A set of objects with their persistent contacts.
+///
+/// Objects are inserted, moved and removed freely, and the contacts between them are only
+/// recomputed by [`CollisionWorld::update`]: the broad-phase finds the pairs of objects with
+/// overlapping Aabbs, and the narrow-phase computes the contact manifolds of each of these
+/// pairs, re-using the results of the previous update.
+///
+/// The scene queries (ray-casting, point projection, etc.) always use the current positions
+/// and shapes of the objects. They rely on the broad-phase for the objects left untouched
+/// since the last update, and test the objects modified since then one by one. Calling
+/// [`CollisionWorld::update`] after moving many objects thus keeps these queries fast.
+///
+/// Everything is generic over the scalar type, so if `T` is a dual number, the contacts
+/// carry the derivatives of their distances with respect to the positions of the objects.
+pub struct CollisionWorld<T: AD, U = ()> {
+    objects: Vec<Option<CollisionObject<T, U>>>,
+    free_handles: Vec<u32>,
+    removed: Vec<u32>,
+    modified: Vec<u32>,
+    broad_phase: BroadPhaseBvh<T>,
+    broad_phase_events: Vec<BroadPhasePairEvent>,
+    contact_pairs: HashMap<SortedPair<u32>, ContactPair<T>>,
+    contact_events: Vec<ContactEvent>,
+    dispatcher: Box<dyn PersistentQueryDispatcher<T>>,
+    pair_filter: Option<PairFilter<T, U>>,
+    prediction: T,
+}
+
+/// A user-defined filter deciding if two objects of a [`CollisionWorld`] can interact.
+pub type PairFilter<T, U> =
+    Box<dyn Fn(&CollisionObject<T, U>, &CollisionObject<T, U>) -> bool + Send + Sync>;
+
+impl<T: AD, U> CollisionWorld<T, U> {
+    /// Creates an empty world.
+    ///
+    /// The contacts closer than `prediction` are computed too. The Aabbs of the broad-phase
+    /// are enlarged by `margin` to avoid updating them each time an object moves slightly.
+    pub fn new(prediction: T, margin: T) -> Self {
+        Self::with_dispatcher(prediction, margin, DefaultQueryDispatcher)
+    }
+
+    /// Creates an empty world computing the contact manifolds with a custom dispatcher.
+    pub fn with_dispatcher(
+        prediction: T,
+        margin: T,
+        dispatcher: impl PersistentQueryDispatcher<T> + 'static,
+    ) -> Self {
+        Self {
+            objects: vec![],
+            free_handles: vec![],
+            removed: vec![],
+            modified: vec![],
+            broad_phase: BroadPhaseBvh::new(margin),
+            broad_phase_events: vec![],
+            contact_pairs: HashMap::default(),
+            contact_events: vec![],
+            dispatcher: Box::new(dispatcher),
+            pair_filter: None,
+            prediction,
+        }
+    }
+
+    /// The distance under which contacts are computed.
+    pub fn prediction(&self) -> T {
+        self.prediction
+    }
+
+    /// Sets the user-defined filter deciding if two objects can interact.
+    ///
+    /// It is checked, after the interaction groups, for each pair of objects with overlapping
+    /// Aabbs at each update.
+    pub fn set_pair_filter(&mut self, filter: Option<PairFilter<T, U>>) {
+        self.pair_filter = filter;
+    }
+
+    /// Inserts a new object and returns its handle.
+    pub fn insert(
+        &mut self,
+        shape: SharedShape<T>,
+        position: Isometry<T>,
+        groups: InteractionGroups,
+        data: U,
+    ) -> CollisionObjectHandle {
+        let object = CollisionObject::new(shape, position, groups, data);
+
+        let handle = if let Some(handle) = self.free_handles.pop() {
+            self.objects[handle as usize] = Some(object);
+            handle
+        } else {
+            self.objects.push(Some(object));
+            self.objects.len() as u32 - 1
+        };
+
+        self.modified.push(handle);
+        CollisionObjectHandle(handle)
+    }
+
+    /// Removes an object from this world.
+    ///
+    /// Its contact pairs are removed at the next update.
+    pub fn remove(&mut self, handle: CollisionObjectHandle) -> Option<CollisionObject<T, U>> {
+        let object = self.objects.get_mut(handle.0 as usize)?.take()?;
+        self.removed.push(handle.0);
+        Some(object)
+    }
+
+    /// The object with the given handle.
+    pub fn get(&self, handle: CollisionObjectHandle) -> Option<&CollisionObject<T, U>> {
+        self.objects.get(handle.0 as usize)?.as_ref()
+    }
+
+    fn get_modified(
+        &mut self,
+        handle: CollisionObjectHandle,
+    ) -> Option<&mut CollisionObject<T, U>> {
+        let object = self.objects.get_mut(handle.0 as usize)?.as_mut()?;
+
+        if !object.modified {
+            object.modified = true;
+            self.modified.push(handle.0);
+        }
+
+        Some(object)
+    }
+
+    /// The user data of the object with the given handle.
+    pub fn data_mut(&mut self, handle: CollisionObjectHandle) -> Option<&mut U> {
+        Some(&mut self.objects.get_mut(handle.0 as usize)?.as_mut()?.data)
+    }
+
+    /// Iterates through all the objects of this world.
+    pub fn objects(&self) -> impl Iterator<Item = (CollisionObjectHandle, &CollisionObject<T, U>)> {
+        self.objects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, obj)| Some((CollisionObjectHandle(i as u32), obj.as_ref()?)))
+    }
+
+    /// Sets the position of an object.
+    pub fn set_position(&mut self, handle: CollisionObjectHandle, position: Isometry<T>) {
+        if let Some(object) = self.get_modified(handle) {
+            object.position = position;
+        }
+    }
+
+    /// Sets the interaction groups of an object.
+    pub fn set_groups(&mut self, handle: CollisionObjectHandle, groups: InteractionGroups) {
+        if let Some(object) = self.get_modified(handle) {
+            object.groups = groups;
+        }
+    }
+
+    /// Sets the shape of an object.
+    ///
+    /// The persistent contacts of this object are reset.
+    pub fn set_shape(&mut self, handle: CollisionObjectHandle, shape: SharedShape<T>) {
+        if let Some(object) = self.get_modified(handle) {
+            object.shape = shape;
+        } else {
+            return;
+        }
+
+        for pair in self.contact_pairs.values_mut() {
+            if pair.handle1 == handle || pair.handle2 == handle {
+                pair.manifolds.clear();
+                pair.workspace = None;
+            }
+        }
+    }
+
+    /// The pair of objects with overlapping Aabbs made of `handle1` and `handle2`.
+    pub fn contact_pair(
+        &self,
+        handle1: CollisionObjectHandle,
+        handle2: CollisionObjectHandle,
+    ) -> Option<&ContactPair<T>> {
+        self.contact_pairs
+            .get(&SortedPair::new(handle1.0, handle2.0))
+    }
+
+    /// Iterates through all the pairs of objects with overlapping Aabbs.
+    ///
+    /// This includes the pairs filtered out by the interaction groups or the pair filter,
+    /// which have no contact manifold.
+    pub fn contact_pairs(&self) -> impl Iterator<Item = &ContactPair<T>> {
+        self.contact_pairs.values()
+    }
+
+    /// The changes of contact status detected by the last update.
+    pub fn contact_events(&self) -> &[ContactEvent] {
+        &self.contact_events
+    }
+
+    /// Updates the broad-phase and the narrow-phase after objects were inserted, moved, or
+    /// removed.
+    pub fn update(&mut self) {
+        self.contact_events.clear();
+        self.update_broad_phase();
+        self.update_narrow_phase();
+    }
+
+    fn update_broad_phase(&mut self) {
+        let mut aabbs = vec![];
+
+        for handle in self.modified.drain(..) {
+            // The object may have been removed since it was modified.
+            if let Some(object) = self.objects[handle as usize].as_mut() {
+                let aabb = object.shape.compute_aabb(&object.position);
+                aabbs.push((handle, aabb.loosened(self.prediction)));
+                object.modified = false;
+            }
+        }
+
+        self.broad_phase_events.clear();
+        self.broad_phase
+            .update(&aabbs, &self.removed, &mut self.broad_phase_events);
+
+        for event in &self.broad_phase_events {
+            match *event {
+                BroadPhasePairEvent::AddPair(h1, h2) => {
+                    let pair =
+                        ContactPair::new(CollisionObjectHandle(h1), CollisionObjectHandle(h2));
+                    let _ = self.contact_pairs.insert(SortedPair::new(h1, h2), pair);
+                }
+                BroadPhasePairEvent::DeletePair(h1, h2) => {
+                    if let Some(pair) = self.contact_pairs.remove(&SortedPair::new(h1, h2)) {
+                        if pair.has_contact {
+                            self.contact_events
+                                .push(ContactEvent::Stopped(pair.handle1, pair.handle2));
+                        }
+                    }
+                }
+            }
+        }
+
+        // The handles of the removed objects can only be re-used once the broad-phase
+        // forgot about them.
+        self.free_handles.append(&mut self.removed);
+    }
+
+    fn update_narrow_phase(&mut self) {
+        for pair in self.contact_pairs.values_mut() {
+            let (object1, object2) = match (
+                self.objects[pair.handle1.0 as usize].as_ref(),
+                self.objects[pair.handle2.0 as usize].as_ref(),
+            ) {
+                (Some(object1), Some(object2)) => (object1, object2),
+                // One of the objects was removed after the last update.
+                _ => continue,
+            };
+
+            let is_valid = object1.groups.test(object2.groups)
+                && self
+                    .pair_filter
+                    .as_ref()
+                    .map(|filter| filter(object1, object2))
+                    .unwrap_or(true);
+
+            if is_valid {
+                let pos12 = object1.position.inv_mul(&object2.position);
+
+                if self
+                    .dispatcher
+                    .contact_manifolds(
+                        &pos12,
+                        &*object1.shape,
+                        &*object2.shape,
+                        self.prediction,
+                        &mut pair.manifolds,
+                        &mut pair.workspace,
+                    )
+                    .is_err()
+                {
+                    pair.manifolds.clear();
+                }
+            } else {
+                pair.manifolds.clear();
+                pair.workspace = None;
+            }
+
+            let had_contact = pair.has_contact;
+            pair.has_contact = pair
+                .manifolds
+                .iter()
+                .any(|m| m.points.iter().any(|pt| pt.dist <= T::zero()));
+
+            if pair.has_contact && !had_contact {
+                self.contact_events
+                    .push(ContactEvent::Started(pair.handle1, pair.handle2));
+            } else if !pair.has_contact && had_contact {
+                self.contact_events
+                    .push(ContactEvent::Stopped(pair.handle1, pair.handle2));
+            }
+        }
+    }
+
+    /// The objects, among the ones compatible with `groups`, modified since the last update.
+    ///
+    /// Their position in the broad-phase may be outdated, so they have to be tested one by one
+    /// by the scene queries.
+    fn modified_objects(
+        &self,
+        groups: InteractionGroups,
+    ) -> impl Iterator<Item = (CollisionObjectHandle, &CollisionObject<T, U>)> {
+        self.modified.iter().filter_map(move |handle| {
+            let object = self.objects[*handle as usize].as_ref()?;
+            groups
+                .test(object.groups)
+                .then_some((CollisionObjectHandle(*handle), object))
+        })
+    }
+
+    /// The objects, among the ones compatible with `groups`, with an Aabb intersecting `aabb`.
+    ///
+    /// The objects left untouched since the last update are found with the broad-phase, using
+    /// their enlarged Aabbs.
+    fn objects_intersecting_aabb<'a>(
+        &'a self,
+        aabb: &'a Aabb<T>,
+        groups: InteractionGroups,
+    ) -> impl Iterator<Item = (CollisionObjectHandle, &'a CollisionObject<T, U>)> {
+        let mut candidates = vec![];
+        self.broad_phase
+            .qbvh()
+            .intersect_aabb(aabb, &mut candidates);
+        let unmodified = candidates.into_iter().filter_map(move |handle| {
+            let object = self.objects[handle as usize].as_ref()?;
+            (!object.modified && groups.test(object.groups))
+                .then_some((CollisionObjectHandle(handle), object))
+        });
+        let modified = self.modified_objects(groups).filter(move |(_, object)| {
+            object.shape.compute_aabb(&object.position).intersects(aabb)
+        });
+        unmodified.chain(modified)
+    }
+
+    /// Computes the first intersection between a ray and the objects compatible with `groups`.
+    pub fn cast_ray(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+        groups: InteractionGroups,
+    ) -> Option<(CollisionObjectHandle, RayIntersection<T>)> {
+        let mut best: Option<(CollisionObjectHandle, RayIntersection<T>)> = None;
+        let mut cast_on_object = |handle: CollisionObjectHandle, object: &CollisionObject<T, U>| {
+            let max_toi = best.map(|(_, hit)| hit.toi).unwrap_or(max_toi);
+
+            if let Some(hit) =
+                object
+                    .shape
+                    .cast_ray_and_get_normal(&object.position, ray, max_toi, solid)
+            {
+                best = Some((handle, hit));
+            }
+        };
+
+        for (handle, object) in self.modified_objects(groups) {
+            cast_on_object(handle, object);
+        }
+
+        let mut callback = |handle: &u32| {
+            if let Some(object) = self.objects[*handle as usize].as_ref() {
+                if !object.modified && groups.test(object.groups) {
+                    cast_on_object(CollisionObjectHandle(*handle), object);
+                }
+            }
+
+            true
+        };
+
+        let mut visitor = RayIntersectionsVisitor::new(ray, max_toi, &mut callback);
+        let _ = self.broad_phase.qbvh().traverse_depth_first(&mut visitor);
+        best
+    }
+
+    /// Projects a point on the closest object compatible with `groups`.
+    pub fn project_point(
+        &self,
+        point: &Point<T>,
+        solid: bool,
+        groups: InteractionGroups,
+    ) -> Option<(CollisionObjectHandle, PointProjection<T>)> {
+        let mut best: Option<(CollisionObjectHandle, PointProjection<T>, T)> = None;
+
+        for (handle, object) in self.modified_objects(groups) {
+            let (proj, dist) = project_point_on_object(object, point, solid);
+
+            if best
+                .map(|(_, _, best_dist)| dist < best_dist)
+                .unwrap_or(true)
+            {
+                best = Some((handle, proj, dist));
+            }
+        }
+
+        let mut visitor = ObjectClosestPointVisitor {
+            objects: &self.objects,
+            point,
+            simd_point: Point::splat(*point),
+            solid,
+            groups,
+        };
+        let init_cost = best
+            .map(|(_, _, best_dist)| best_dist)
+            .unwrap_or(T::constant(f64::max_value()));
+
+        if let Some((_, (handle, proj))) =
+            self.broad_phase
+                .qbvh()
+                .traverse_best_first_node(&mut visitor, 0, init_cost)
+        {
+            return Some((CollisionObjectHandle(handle), proj));
+        }
+
+        best.map(|(handle, proj, _)| (handle, proj))
+    }
+
+    /// Finds all the objects, compatible with `groups`, intersecting the given shape.
+    pub fn intersections_with_shape(
+        &self,
+        position: &Isometry<T>,
+        shape: &dyn Shape<T>,
+        groups: InteractionGroups,
+    ) -> Vec<CollisionObjectHandle> {
+        let aabb = shape.compute_aabb(position);
+        self.objects_intersecting_aabb(&aabb, groups)
+            .filter(|(_, object)| {
+                let pos12 = position.inv_mul(&object.position);
+                self.dispatcher
+                    .intersection_test(&pos12, shape, &*object.shape)
+                    .unwrap_or(false)
+            })
+            .map(|(handle, _)| handle)
+            .collect()
+    }
+
+    /// Computes the first object, compatible with `groups`, hit by a shape moving along a
+    /// straight line.
+    ///
+    /// The shape starts at `position` and moves with the velocity `velocity` until `max_toi`,
+    /// which must be finite. See [`query::time_of_impact`](crate::query::time_of_impact) for
+    /// details about `stop_at_penetration`.
+    pub fn cast_shape(
+        &self,
+        position: &Isometry<T>,
+        velocity: &Vector<T>,
+        shape: &dyn Shape<T>,
+        max_toi: T,
+        stop_at_penetration: bool,
+        groups: InteractionGroups,
+    ) -> Option<(CollisionObjectHandle, TOI<T>)> {
+        let start_aabb = shape.compute_aabb(position);
+        let displacement = velocity * max_toi;
+        let end_aabb = Aabb::new(
+            start_aabb.mins + displacement,
+            start_aabb.maxs + displacement,
+        );
+        let swept_aabb = start_aabb.merged(&end_aabb);
+        let mut best: Option<(CollisionObjectHandle, TOI<T>)> = None;
+
+        for (handle, object) in self.objects_intersecting_aabb(&swept_aabb, groups) {
+            let pos12 = position.inv_mul(&object.position);
+            let vel12 = position.inverse_transform_vector(&-velocity);
+            let max_toi = best.map(|(_, toi)| toi.toi).unwrap_or(max_toi);
+
+            if let Ok(Some(toi)) = self.dispatcher.time_of_impact(
+                &pos12,
+                &vel12,
+                shape,
+                &*object.shape,
+                max_toi,
+                stop_at_penetration,
+            ) {
+                if best.map(|(_, best)| toi.toi < best.toi).unwrap_or(true) {
+                    best = Some((handle, toi));
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// The projection of `point` on an object, and its distance to `point`.
+fn project_point_on_object<T: AD, U>(
+    object: &CollisionObject<T, U>,
+    point: &Point<T>,
+    solid: bool,
+) -> (PointProjection<T>, T) {
+    let proj = object.shape.project_point(&object.position, point, solid);
+    let dist = if proj.is_inside {
+        T::zero()
+    } else {
+        na::distance(&proj.point, point)
+    };
+    (proj, dist)
+}
+
+/// Best-first traversal visitor for projecting a point on the objects left untouched since the
+/// last update of a [`CollisionWorld`].
+struct ObjectClosestPointVisitor<'a, T: AD, U> {
+    objects: &'a [Option<CollisionObject<T, U>>],
+    point: &'a Point<T>,
+    simd_point: Point<T>,
+    solid: bool,
+    groups: InteractionGroups,
+}
+
+impl<'a, T: AD, U> SimdBestFirstVisitor<u32, SimdAabb<T>, T>
+    for ObjectClosestPointVisitor<'a, T, U>
+{
+    type Result = (u32, PointProjection<T>);
+
+    fn visit(
+        &mut self,
+        best: T,
+        aabb: &SimdAabb<T>,
+        data: Option<[Option<&u32>; SIMD_WIDTH]>,
+    ) -> SimdBestFirstVisitStatus<Self::Result, T> {
+        let dist = aabb.distance_to_local_point(&self.simd_point);
+        let mask = dist.simd_lt(best);
+
+        if let Some(data) = data {
+            let bitmask = mask.bitmask();
+            let mut weights = [T::zero(); SIMD_WIDTH];
+            let mut mask = [false; SIMD_WIDTH];
+            let mut results = [None; SIMD_WIDTH];
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) == 0 {
+                    continue;
+                }
+
+                let handle = match data[ii] {
+                    Some(handle) => *handle,
+                    None => continue,
+                };
+
+                if let Some(object) = &self.objects[handle as usize] {
+                    // The modified objects are tested separately.
+                    if !object.modified && self.groups.test(object.groups) {
+                        let (proj, dist) = project_point_on_object(object, self.point, self.solid);
+                        weights[ii] = dist;
+                        mask[ii] = true;
+                        results[ii] = Some((handle, proj));
+                    }
+                }
+            }
+
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: weights[0],
+                mask: mask[0],
+                results,
+            }
+        } else {
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: dist,
+                mask,
+                results: [None; SIMD_WIDTH],
+            }
+        }
+    }
+}
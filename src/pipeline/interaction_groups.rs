@@ -0,0 +1,56 @@
+/// Pairwise filtering of collision objects based on bit masks.
+///
+/// Each object is a member of the groups whose bits are set in `memberships`, and can only
+/// interact with the objects that are members of at least one of the groups set in `filter`.
+/// Two objects interact only if each of them passes the filter of the other.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct InteractionGroups {
+    /// The groups this object is a member of.
+    pub memberships: u32,
+    /// The groups this object can interact with.
+    pub filter: u32,
+}
+
+impl InteractionGroups {
+    /// Creates new interaction groups from the given memberships and filter.
+    pub const fn new(memberships: u32, filter: u32) -> Self {
+        Self {
+            memberships,
+            filter,
+        }
+    }
+
+    /// Interaction groups that are members of all the groups and interact with all the groups.
+    pub const fn all() -> Self {
+        Self::new(u32::MAX, u32::MAX)
+    }
+
+    /// Interaction groups that are members of no group and interact with no group.
+    pub const fn none() -> Self {
+        Self::new(0, 0)
+    }
+
+    /// Sets the groups this object is a member of.
+    pub const fn with_memberships(mut self, memberships: u32) -> Self {
+        self.memberships = memberships;
+        self
+    }
+
+    /// Sets the groups this object can interact with.
+    pub const fn with_filter(mut self, filter: u32) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Checks whether objects with the groups `self` and `other` can interact.
+    pub const fn test(self, other: Self) -> bool {
+        (self.memberships & other.filter) != 0 && (other.memberships & self.filter) != 0
+    }
+}
+
+impl Default for InteractionGroups {
+    fn default() -> Self {
+        Self::all()
+    }
+}
@@ -0,0 +1,295 @@
+use crate::math::Isometry;
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::SharedShape;
+use ad_trait::AD;
+
+/// A path in the joint-space of a kinematic chain, parametrized on `[0, 1]`.
+pub trait JointPath<T: AD> {
+    /// The joint values at the parameter `s`.
+    fn configuration_at(&self, s: T) -> Vec<T>;
+
+    /// An upper bound of the speed of every joint, i.e., of the absolute value of the
+    /// derivative of each joint value with respect to `s`, on the interval `[s0, s1]`.
+    fn max_joint_speed(&self, s0: T, s1: T) -> T;
+}
+
+/// A straight-line segment in joint-space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearJointPath<T: AD> {
+    /// The joint values at the start of the segment.
+    pub start: Vec<T>,
+    /// The joint values at the end of the segment.
+    pub end: Vec<T>,
+}
+
+impl<T: AD> LinearJointPath<T> {
+    /// Creates the segment between two joint configurations.
+    pub fn new(start: Vec<T>, end: Vec<T>) -> Self {
+        assert_eq!(
+            start.len(),
+            end.len(),
+            "Both configurations must have the same number of joints."
+        );
+        Self { start, end }
+    }
+}
+
+impl<T: AD> JointPath<T> for LinearJointPath<T> {
+    fn configuration_at(&self, s: T) -> Vec<T> {
+        self.start
+            .iter()
+            .zip(self.end.iter())
+            .map(|(a, b)| *a + (*b - *a) * s)
+            .collect()
+    }
+
+    fn max_joint_speed(&self, _: T, _: T) -> T {
+        self.start
+            .iter()
+            .zip(self.end.iter())
+            .fold(T::zero(), |speed, (a, b)| speed.max((*b - *a).abs()))
+    }
+}
+
+/// A cubic Hermite spline in joint-space, through uniformly spaced waypoints.
+///
+/// With `n + 1` waypoints, the `i`-th waypoint is reached at the parameter `i / n`, where the
+/// derivative of the joint values with respect to the parameter is the `i`-th tangent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubicJointPath<T: AD> {
+    /// The joint values at the waypoints.
+    pub waypoints: Vec<Vec<T>>,
+    /// The derivatives of the joint values with respect to the path parameter at the waypoints.
+    pub tangents: Vec<Vec<T>>,
+}
+
+impl<T: AD> CubicJointPath<T> {
+    /// Creates the spline through the given waypoints, with the given tangents.
+    ///
+    /// # Panics
+    /// Panics if there are less than two waypoints, if there isn’t exactly one tangent per
+    /// waypoint, or if the waypoints and tangents don’t all have the same number of joints.
+    pub fn new(waypoints: Vec<Vec<T>>, tangents: Vec<Vec<T>>) -> Self {
+        assert!(
+            waypoints.len() >= 2,
+            "A cubic joint path needs at least two waypoints."
+        );
+        assert_eq!(
+            waypoints.len(),
+            tangents.len(),
+            "There must be exactly one tangent per waypoint."
+        );
+        let num_joints = waypoints[0].len();
+        assert!(
+            waypoints
+                .iter()
+                .chain(tangents.iter())
+                .all(|q| q.len() == num_joints),
+            "All the waypoints and tangents must have the same number of joints."
+        );
+        Self {
+            waypoints,
+            tangents,
+        }
+    }
+
+    fn num_segments(&self) -> usize {
+        self.waypoints.len() - 1
+    }
+
+    /// The index of the segment containing the parameter `s`, clamped to `[0, 1]`, and the
+    /// local parameter in `[0, 1]` of `s` on it.
+    fn segment_at(&self, s: T) -> (usize, T) {
+        let n = self.num_segments();
+        let scaled = s.max(T::zero()).min(T::one()) * T::constant(n as f64);
+        let i = (scaled.to_constant() as usize).min(n - 1);
+        (i, scaled - T::constant(i as f64))
+    }
+}
+
+impl<T: AD> JointPath<T> for CubicJointPath<T> {
+    fn configuration_at(&self, s: T) -> Vec<T> {
+        let (i, u) = self.segment_at(s);
+        let h = T::one() / T::constant(self.num_segments() as f64);
+        let _2 = T::constant(2.0);
+        let _3 = T::constant(3.0);
+        let u2 = u * u;
+        let u3 = u2 * u;
+        let weights = [
+            _2 * u3 - _3 * u2 + T::one(),
+            (u3 - _2 * u2 + u) * h,
+            -_2 * u3 + _3 * u2,
+            (u3 - u2) * h,
+        ];
+        let (p0, v0) = (&self.waypoints[i], &self.tangents[i]);
+        let (p1, v1) = (&self.waypoints[i + 1], &self.tangents[i + 1]);
+
+        (0..p0.len())
+            .map(|j| {
+                p0[j] * weights[0] + v0[j] * weights[1] + p1[j] * weights[2] + v1[j] * weights[3]
+            })
+            .collect()
+    }
+
+    fn max_joint_speed(&self, s0: T, s1: T) -> T {
+        // On each segment, the derivative of a joint value is a quadratic Bézier curve with the
+        // control points `v0`, `3 * (p1 - p0) / h - v0 - v1`, and `v1`, so it lies between the
+        // smallest and the largest of those.
+        let n = self.num_segments();
+        let _3_h = T::constant(3.0 * n as f64);
+        let mut speed = T::zero();
+
+        for i in 0..n {
+            let sa = T::constant(i as f64 / n as f64);
+            let sb = T::constant((i + 1) as f64 / n as f64);
+
+            // Skip the segments that don’t overlap `[s0, s1]`.
+            if sb < s0 || sa > s1 {
+                continue;
+            }
+
+            let (p0, v0) = (&self.waypoints[i], &self.tangents[i]);
+            let (p1, v1) = (&self.waypoints[i + 1], &self.tangents[i + 1]);
+
+            for j in 0..p0.len() {
+                let mid = (p1[j] - p0[j]) * _3_h - v0[j] - v1[j];
+                speed = speed.max(v0[j].abs()).max(mid.abs()).max(v1[j].abs());
+            }
+        }
+
+        speed
+    }
+}
+
+/// The first contact found along a joint-space path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JointPathContact<T: AD> {
+    /// The path parameter of the contact.
+    pub time: T,
+    /// The joint values at the contact.
+    pub configuration: Vec<T>,
+    /// The index of the link involved in the contact.
+    pub link: usize,
+    /// The index of the obstacle involved in the contact.
+    pub obstacle: usize,
+    /// The distance between the link and the obstacle, smaller than the tolerance.
+    pub distance: T,
+}
+
+/// The result of the certification of a joint-space path.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JointPathCertificate<T: AD> {
+    /// The links stay farther than half the tolerance from all the obstacles along the whole
+    /// path.
+    Free,
+    /// A link gets closer than the tolerance to an obstacle.
+    Contact(JointPathContact<T>),
+}
+
+impl<T: AD> JointPathCertificate<T> {
+    /// Is the path free of any contact?
+    pub fn is_free(&self) -> bool {
+        matches!(self, JointPathCertificate::Free)
+    }
+}
+
+/// The joint values at the parameter `s` of the path, and the corresponding poses of the links.
+fn link_poses<T: AD>(
+    path: &dyn JointPath<T>,
+    forward_kinematics: &dyn Fn(&[T]) -> Vec<Isometry<T>>,
+    num_links: usize,
+    s: T,
+) -> (Vec<T>, Vec<Isometry<T>>) {
+    let configuration = path.configuration_at(s);
+    let poses = forward_kinematics(&configuration);
+    assert_eq!(
+        poses.len(),
+        num_links,
+        "The forward kinematics must return exactly one pose per link."
+    );
+    (configuration, poses)
+}
+
+/// Certifies that the links of a kinematic chain moving along a joint-space path don’t hit any
+/// obstacle.
+///
+/// This runs conservative advancement on the path parameter. At each step, the distance `d`
+/// between each link and each obstacle is computed, and a contact is reported if it is smaller
+/// than `tolerance`. Otherwise, the parameter advances by the smallest
+/// `(d - tolerance / 2) / (displacement_bound * max_joint_speed)`, with the joint speed bounded
+/// until the end of the path, so no link can get closer than half the tolerance to an obstacle
+/// during the step. Since every step is then at least `tolerance / 2` divided by the largest
+/// link speed, the advancement terminates.
+///
+/// If the result is [`JointPathCertificate::Free`], this is a proof that the links never get
+/// closer than half the tolerance to the obstacles, provided the displacement bounds hold.
+///
+/// # Parameters
+/// * `path` - The path in joint-space.
+/// * `forward_kinematics` - Computes the world-space poses of all the links from the joint
+///   values.
+/// * `links` - The shapes of the links, in their local-space.
+/// * `displacement_bounds` - For each link, an upper bound of the distance any point of the
+///   link can travel when each joint value changes by at most one unit.
+/// * `obstacles` - The world-space poses and shapes of the static obstacles.
+/// * `tolerance` - A contact is reported as soon as a link is closer than this distance to
+///   an obstacle.
+///
+/// # Panics
+/// Panics if there isn’t exactly one displacement bound per link, if `forward_kinematics`
+/// doesn’t return exactly one pose per link, or if `tolerance` isn’t positive.
+pub fn certify_joint_path<T: AD>(
+    path: &dyn JointPath<T>,
+    forward_kinematics: &dyn Fn(&[T]) -> Vec<Isometry<T>>,
+    links: &[SharedShape<T>],
+    displacement_bounds: &[T],
+    obstacles: &[(Isometry<T>, SharedShape<T>)],
+    tolerance: T,
+) -> Result<JointPathCertificate<T>, Unsupported> {
+    assert_eq!(
+        links.len(),
+        displacement_bounds.len(),
+        "There must be exactly one displacement bound per link."
+    );
+    assert!(tolerance > T::zero(), "The tolerance must be positive.");
+
+    let end_time = T::one();
+    let margin = tolerance * T::constant(0.5);
+    let mut curr_time = T::zero();
+
+    loop {
+        let (configuration, poses) = link_poses(path, forward_kinematics, links.len(), curr_time);
+        let joint_speed = path.max_joint_speed(curr_time, end_time);
+        let mut step = end_time - curr_time;
+
+        for (link, (shape, pose)) in links.iter().zip(poses.iter()).enumerate() {
+            let speed = displacement_bounds[link] * joint_speed;
+
+            for (obstacle, (obstacle_pose, obstacle_shape)) in obstacles.iter().enumerate() {
+                let pos12 = pose.inv_mul(obstacle_pose);
+                let distance =
+                    DefaultQueryDispatcher.distance(&pos12, &**shape, &**obstacle_shape)?;
+
+                if distance < tolerance {
+                    return Ok(JointPathCertificate::Contact(JointPathContact {
+                        time: curr_time,
+                        configuration,
+                        link,
+                        obstacle,
+                        distance,
+                    }));
+                }
+
+                if speed > T::zero() {
+                    step = step.min((distance - margin) / speed);
+                }
+            }
+        }
+
+        if curr_time >= end_time {
+            return Ok(JointPathCertificate::Free);
+        }
+
+        curr_time = (curr_time + step).min(end_time);
+    }
+}
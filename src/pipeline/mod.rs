@@ -0,0 +1,16 @@
+//! High-level collision detection pipeline, maintaining the contacts between a set of objects.
+
+pub use self::collision_object::{CollisionObject, CollisionObjectHandle};
+pub use self::collision_world::{CollisionWorld, ContactEvent, ContactPair, PairFilter};
+pub use self::interaction_groups::InteractionGroups;
+pub use self::joint_path_certification::{
+    certify_joint_path, CubicJointPath, JointPath, JointPathCertificate, JointPathContact,
+    LinearJointPath,
+};
+pub use self::self_collision_model::{LinkPairProximity, PoseGradient, SelfCollisionModel};
+
+mod collision_object;
+mod collision_world;
+mod interaction_groups;
+mod joint_path_certification;
+mod self_collision_model;
@@ -0,0 +1,319 @@
+use na::Unit;
+
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{AngVector, Isometry, Point, Vector};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::shape::SharedShape;
+use crate::utils::hashmap::HashMap;
+use crate::utils::{SortedPair, WCross};
+use ad_trait::AD;
+
+/// The derivative of a distance with respect to the pose of a link.
+///
+/// If the link moves with the linear velocity `v` and the angular velocity `w` (both expressed
+/// in world-space, the rotation being centered at the link origin), the distance changes at the
+/// rate `linear.dot(v) + angular.dot(w)`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PoseGradient<T: AD> {
+    /// The derivative of the distance with respect to the translation of the link.
+    pub linear: Vector<T>,
+    /// The derivative of the distance with respect to the rotation of the link.
+    pub angular: AngVector<T>,
+}
+
+/// The proximity between two links of a [`SelfCollisionModel`].
+#[derive(Copy, Clone, Debug)]
+pub struct LinkPairProximity<T: AD> {
+    /// The index of the first link.
+    pub link1: usize,
+    /// The index of the second link.
+    pub link2: usize,
+    /// The signed distance between both links, negative if they penetrate.
+    pub distance: T,
+    /// Is the distance smaller than the margin of this pair of links?
+    pub colliding: bool,
+    /// The world-space closest point on the first link.
+    pub point1: Point<T>,
+    /// The world-space closest point on the second link.
+    pub point2: Point<T>,
+    /// The world-space normal on the first link, pointing toward the second link.
+    pub normal1: Unit<Vector<T>>,
+    /// The gradient of `distance` with respect to the pose of the first link.
+    pub gradient1: PoseGradient<T>,
+    /// The gradient of `distance` with respect to the pose of the second link.
+    pub gradient2: PoseGradient<T>,
+}
+
+/// A model detecting the collisions between the links of a robot.
+///
+/// Each link has a single shape, usually a `Compound` of all its collision geometries. Some
+/// pairs of links can be excluded from the checks, typically the adjacent links (which touch at
+/// their joint) and the links that collide in every configuration. Each pair of links has a
+/// margin: the links are considered colliding if their distance is smaller than this margin.
+///
+/// If `T` is a dual number, the distances reported carry their derivatives with respect to the
+/// parameters of the link poses. The explicit [`PoseGradient`]s of the distances are given too.
+///
+/// Each query checks the Aabbs of all the pairs of links that aren’t excluded before computing
+/// their distances, which is `O(n²)` on the number of links, instead of traversing a bounding
+/// volume hierarchy. This is cheap enough for the few dozens of links of a typical robot.
+#[derive(Clone)]
+pub struct SelfCollisionModel<T: AD> {
+    links: Vec<SharedShape<T>>,
+    excluded: Vec<bool>,
+    margins: HashMap<SortedPair<u32>, T>,
+    default_margin: T,
+}
+
+impl<T: AD> SelfCollisionModel<T> {
+    /// Creates a model with the given link shapes, no excluded pair, and a zero margin.
+    pub fn new(links: Vec<SharedShape<T>>) -> Self {
+        let n = links.len();
+        Self {
+            links,
+            excluded: vec![false; n * n],
+            margins: HashMap::default(),
+            default_margin: T::zero(),
+        }
+    }
+
+    /// The number of links of this model.
+    pub fn num_links(&self) -> usize {
+        self.links.len()
+    }
+
+    /// The shapes of the links of this model.
+    pub fn links(&self) -> &[SharedShape<T>] {
+        &self.links
+    }
+
+    /// Excludes the pair of links `i` and `j` from the collision checks.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` isn’t the index of a link.
+    pub fn exclude_pair(&mut self, i: usize, j: usize) {
+        self.set_excluded(i, j, true);
+    }
+
+    /// Includes the pair of links `i` and `j` in the collision checks.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` isn’t the index of a link.
+    pub fn include_pair(&mut self, i: usize, j: usize) {
+        self.set_excluded(i, j, false);
+    }
+
+    fn set_excluded(&mut self, i: usize, j: usize, excluded: bool) {
+        self.check_pair(i, j);
+        let n = self.links.len();
+        self.excluded[i * n + j] = excluded;
+        self.excluded[j * n + i] = excluded;
+    }
+
+    fn check_pair(&self, i: usize, j: usize) {
+        assert!(
+            i < self.links.len() && j < self.links.len(),
+            "Link index out of bounds: ({}, {}) with {} links.",
+            i,
+            j,
+            self.links.len()
+        );
+    }
+
+    /// Is the pair of links `i` and `j` excluded from the collision checks?
+    ///
+    /// A link is never checked against itself.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` isn’t the index of a link.
+    pub fn is_pair_excluded(&self, i: usize, j: usize) -> bool {
+        self.check_pair(i, j);
+        i == j || self.excluded[i * self.links.len() + j]
+    }
+
+    /// Excludes every link from the collision checks with its parent link.
+    ///
+    /// The `parents[i]` is the index of the parent of the link `i`, or `None` for a root link.
+    ///
+    /// # Panics
+    /// Panics if `parents` refers to a link that doesn’t exist.
+    pub fn exclude_adjacent_links(&mut self, parents: &[Option<usize>]) {
+        for (i, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                self.exclude_pair(i, *parent);
+            }
+        }
+    }
+
+    /// Excludes the pairs of links that collide at all the given sample configurations.
+    ///
+    /// Each sample contains the world-space poses of all the links. Such pairs usually overlap
+    /// by design (e.g. because of a conservative geometry near a joint) and would hide any
+    /// actual collision. Returns the number of pairs newly excluded.
+    ///
+    /// # Panics
+    /// Panics if a sample doesn’t contain exactly one pose per link.
+    pub fn exclude_always_colliding_pairs(
+        &mut self,
+        samples: &[Vec<Isometry<T>>],
+    ) -> Result<usize, Unsupported> {
+        if samples.is_empty() {
+            return Ok(0);
+        }
+
+        let n = self.links.len();
+        let mut num_collisions = vec![0; n * n];
+
+        for poses in samples {
+            for proximity in self.colliding_pairs(poses)? {
+                num_collisions[proximity.link1 * n + proximity.link2] += 1;
+            }
+        }
+
+        let mut num_excluded = 0;
+
+        for i in 0..n {
+            for j in i + 1..n {
+                if num_collisions[i * n + j] == samples.len() {
+                    self.exclude_pair(i, j);
+                    num_excluded += 1;
+                }
+            }
+        }
+
+        Ok(num_excluded)
+    }
+
+    /// Sets the margin used by the pairs of links without a specific margin.
+    pub fn set_default_margin(&mut self, margin: T) {
+        self.default_margin = margin;
+    }
+
+    /// Sets the margin of the pair of links `i` and `j`.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` isn’t the index of a link.
+    pub fn set_pair_margin(&mut self, i: usize, j: usize, margin: T) {
+        self.check_pair(i, j);
+        let _ = self
+            .margins
+            .insert(SortedPair::new(i as u32, j as u32), margin);
+    }
+
+    /// The margin of the pair of links `i` and `j`.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` isn’t the index of a link.
+    pub fn pair_margin(&self, i: usize, j: usize) -> T {
+        self.check_pair(i, j);
+        self.margins
+            .get(&SortedPair::new(i as u32, j as u32))
+            .copied()
+            .unwrap_or(self.default_margin)
+    }
+
+    /// Computes all the pairs of links closer than their margin.
+    ///
+    /// The `poses[i]` is the world-space pose of the link `i`.
+    ///
+    /// # Panics
+    /// Panics if there isn’t exactly one pose per link.
+    pub fn colliding_pairs(
+        &self,
+        poses: &[Isometry<T>],
+    ) -> Result<Vec<LinkPairProximity<T>>, Unsupported> {
+        let mut result = self.proximities(poses, |i, j| self.pair_margin(i, j))?;
+        result.retain(|proximity| proximity.colliding);
+        Ok(result)
+    }
+
+    /// Computes the distance between all the pairs of links that are not excluded, and closer
+    /// than `max_dist` or than their margin.
+    ///
+    /// The `poses[i]` is the world-space pose of the link `i`.
+    ///
+    /// # Panics
+    /// Panics if there isn’t exactly one pose per link.
+    pub fn pair_distances(
+        &self,
+        poses: &[Isometry<T>],
+        max_dist: T,
+    ) -> Result<Vec<LinkPairProximity<T>>, Unsupported> {
+        self.proximities(poses, |i, j| self.pair_margin(i, j).max(max_dist))
+    }
+
+    fn proximities(
+        &self,
+        poses: &[Isometry<T>],
+        max_dist: impl Fn(usize, usize) -> T,
+    ) -> Result<Vec<LinkPairProximity<T>>, Unsupported> {
+        assert_eq!(
+            poses.len(),
+            self.links.len(),
+            "There must be exactly one pose per link."
+        );
+
+        let aabbs: Vec<_> = self
+            .links
+            .iter()
+            .zip(poses.iter())
+            .map(|(link, pose)| link.compute_aabb(pose))
+            .collect();
+        let mut result = vec![];
+
+        for i in 0..self.links.len() {
+            for j in i + 1..self.links.len() {
+                if self.is_pair_excluded(i, j) {
+                    continue;
+                }
+
+                let max_dist = max_dist(i, j);
+
+                if !aabbs[i].loosened(max_dist).intersects(&aabbs[j]) {
+                    continue;
+                }
+
+                let pos12 = poses[i].inv_mul(&poses[j]);
+                let contact = DefaultQueryDispatcher.contact(
+                    &pos12,
+                    &*self.links[i],
+                    &*self.links[j],
+                    max_dist,
+                )?;
+
+                if let Some(contact) = contact {
+                    let point1 = poses[i] * contact.point1;
+                    let point2 = poses[j] * contact.point2;
+                    let normal1 = poses[i] * contact.normal1;
+
+                    // Moving the first (resp. second) link along the normal decreases
+                    // (resp. increases) the distance.
+                    let r1 = point1 - poses[i].translation.vector;
+                    let r2 = point2 - poses[j].translation.vector;
+                    let gradient1 = PoseGradient {
+                        linear: -normal1.into_inner(),
+                        angular: -r1.coords.gcross(normal1.into_inner()),
+                    };
+                    let gradient2 = PoseGradient {
+                        linear: normal1.into_inner(),
+                        angular: r2.coords.gcross(normal1.into_inner()),
+                    };
+
+                    result.push(LinkPairProximity {
+                        link1: i,
+                        link2: j,
+                        distance: contact.dist,
+                        colliding: contact.dist < self.pair_margin(i, j),
+                        point1,
+                        point2,
+                        normal1,
+                        gradient1,
+                        gradient2,
+                    });
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
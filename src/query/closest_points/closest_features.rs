@@ -0,0 +1,80 @@
+use crate::math::Point;
+use crate::shape::{FeatureId, Shape};
+use ad_trait::AD;
+use std::mem;
+
+#[cfg(feature = "std")]
+use crate::query::point::PointCompositeShapeProjWithFeatureBestFirstVisitor;
+
+/// The features of two shapes on which a pair of closest points (or contact points) lie.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+pub struct ClosestFeatures {
+    /// The feature of the first shape (or of its sub-shape `subshape1`) the first point lies on.
+    pub fid1: FeatureId,
+    /// The feature of the second shape (or of its sub-shape `subshape2`) the second point lies on.
+    pub fid2: FeatureId,
+    /// The index of the sub-shape of the first shape containing `fid1`, if it is a composite shape.
+    pub subshape1: Option<u32>,
+    /// The index of the sub-shape of the second shape containing `fid2`, if it is a composite shape.
+    pub subshape2: Option<u32>,
+}
+
+impl ClosestFeatures {
+    /// Identifies the features the witness points `local_p1` and `local_p2` lie on.
+    ///
+    /// The point `local_p1` (resp. `local_p2`) is expressed in the local-space of `g1` (resp. `g2`).
+    /// If a shape is composite, its feature is identified on the closest of its sub-shapes, and
+    /// the index of that sub-shape is recorded as well.
+    pub fn from_local_witnesses<T: AD>(
+        g1: &dyn Shape<T>,
+        local_p1: &Point<T>,
+        g2: &dyn Shape<T>,
+        local_p2: &Point<T>,
+    ) -> Self {
+        let (subshape1, fid1) = witness_feature(g1, local_p1);
+        let (subshape2, fid2) = witness_feature(g2, local_p2);
+
+        ClosestFeatures {
+            fid1,
+            fid2,
+            subshape1,
+            subshape2,
+        }
+    }
+
+    /// Swaps the features of the first and second shapes.
+    #[inline]
+    pub fn flip(&mut self) {
+        mem::swap(&mut self.fid1, &mut self.fid2);
+        mem::swap(&mut self.subshape1, &mut self.subshape2);
+    }
+
+    /// Returns the result of swapping the features of the first and second shapes.
+    #[inline]
+    #[must_use]
+    pub fn flipped(mut self) -> Self {
+        self.flip();
+        self
+    }
+}
+
+/// The sub-shape index and feature of `shape` the local point `pt` lies on.
+fn witness_feature<T: AD>(shape: &dyn Shape<T>, pt: &Point<T>) -> (Option<u32>, FeatureId) {
+    #[cfg(feature = "std")]
+    if let Some(composite) = shape.as_composite_shape() {
+        let mut visitor =
+            PointCompositeShapeProjWithFeatureBestFirstVisitor::new(composite, pt, false);
+        return match composite.qbvh().traverse_best_first(&mut visitor) {
+            Some((_, (_, (part_id, fid)))) => (Some(part_id), fid),
+            None => (None, FeatureId::Unknown),
+        };
+    }
+
+    (None, shape.project_local_point_and_get_feature(pt).1)
+}
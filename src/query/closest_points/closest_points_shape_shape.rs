@@ -1,5 +1,7 @@
 use crate::math::{Isometry};
-use crate::query::{ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::query::{
+    ClosestFeatures, ClosestPoints, DefaultQueryDispatcher, QueryDispatcher, Unsupported,
+};
 use crate::shape::Shape;
 use ad_trait::AD;
 
@@ -19,3 +21,26 @@ pub fn closest_points<T: AD>(
         .closest_points(&pos12, g1, g2, max_dist)
         .map(|res| res.transform_by(pos1, pos2))
 }
+
+/// Computes the pair of closest points between two shapes, and the features they lie on.
+///
+/// This is the same as [`closest_points`], except that it also identifies, for each shape, the
+/// feature (and sub-shape, for composite shapes) the closest point lies on. The features are
+/// only returned if the result is `ClosestPoints::WithinMargin`.
+pub fn closest_points_with_features<T: AD>(
+    pos1: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    pos2: &Isometry<T>,
+    g2: &dyn Shape<T>,
+    max_dist: T,
+) -> Result<(ClosestPoints<T>, Option<ClosestFeatures>), Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let res = DefaultQueryDispatcher.closest_points(&pos12, g1, g2, max_dist)?;
+    let features = if let ClosestPoints::WithinMargin(local_p1, local_p2) = res {
+        Some(ClosestFeatures::from_local_witnesses(g1, &local_p1, g2, &local_p2))
+    } else {
+        None
+    };
+
+    Ok((res.transform_by(pos1, pos2), features))
+}
@@ -1,5 +1,5 @@
 use crate::math::{Isometry, Vector};
-use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+use crate::query::gjk::{self, CSOPoint, DistanceCache, GJKResult, VoronoiSimplex};
 use crate::query::ClosestPoints;
 use crate::shape::SupportMap;
 use ad_trait::AD;
@@ -68,3 +68,27 @@ where
 
     gjk::closest_points(pos12, g1, g2, prediction, true, simplex)
 }
+
+/// Closest points between support-mapped shapes, warm-started with the result of a previous query.
+///
+/// The `cache` must have been used only by previous queries on the same pair of shapes.
+pub fn closest_points_support_map_support_map_with_cache<T: AD, G1: ?Sized, G2: ?Sized>(
+    pos12: &Isometry<T>,
+    g1: &G1,
+    g2: &G2,
+    prediction: T,
+    cache: &mut DistanceCache<T>,
+) -> ClosestPoints<T>
+where
+    G1: SupportMap<T>,
+    G2: SupportMap<T>,
+{
+    match cache.closest_points(pos12, g1, g2, prediction, true) {
+        GJKResult::ClosestPoints(pt1, pt2, _) => {
+            ClosestPoints::WithinMargin(pt1, pos12.inverse_transform_point(&pt2))
+        }
+        GJKResult::NoIntersection(_) => ClosestPoints::Disjoint,
+        GJKResult::Intersection => ClosestPoints::Intersecting,
+        GJKResult::Proximity(_) => unreachable!(),
+    }
+}
@@ -1,5 +1,6 @@
 //! Implementation details of the `closest_points` function.
 
+pub use self::closest_features::ClosestFeatures;
 pub use self::closest_points::ClosestPoints;
 pub use self::closest_points_ball_ball::closest_points_ball_ball;
 pub use self::closest_points_ball_convex_polyhedron::{
@@ -25,10 +26,12 @@ pub use self::closest_points_segment_segment::{
     closest_points_segment_segment, closest_points_segment_segment_with_locations,
     closest_points_segment_segment_with_locations_nD,
 };
-pub use self::closest_points_shape_shape::closest_points;
+pub use self::closest_points_shape_shape::{closest_points, closest_points_with_features};
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map;
+pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map_with_cache;
 pub use self::closest_points_support_map_support_map::closest_points_support_map_support_map_with_params;
 
+mod closest_features;
 mod closest_points;
 mod closest_points_ball_ball;
 mod closest_points_ball_convex_polyhedron;
@@ -1,5 +1,7 @@
 use crate::math::{Isometry};
-use crate::query::{Contact, DefaultQueryDispatcher, QueryDispatcher, Unsupported};
+use crate::query::{
+    ClosestFeatures, Contact, DefaultQueryDispatcher, QueryDispatcher, Unsupported,
+};
 use crate::shape::Shape;
 use ad_trait::AD;
 
@@ -23,3 +25,25 @@ pub fn contact<T: AD>(
 
     result
 }
+
+/// Computes one pair of contact points between two shapes, and the features they lie on.
+///
+/// This is the same as [`contact`], except that it also identifies, for each shape, the
+/// feature (and sub-shape, for composite shapes) the contact point lies on.
+pub fn contact_with_features<T: AD>(
+    pos1: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    pos2: &Isometry<T>,
+    g2: &dyn Shape<T>,
+    prediction: T,
+) -> Result<Option<(Contact<T>, ClosestFeatures)>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let result = DefaultQueryDispatcher.contact(&pos12, g1, g2, prediction)?;
+
+    Ok(result.map(|mut contact| {
+        let features =
+            ClosestFeatures::from_local_witnesses(g1, &contact.point1, g2, &contact.point2);
+        contact.transform_by_mut(pos1, pos2);
+        (contact, features)
+    }))
+}
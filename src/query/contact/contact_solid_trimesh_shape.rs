@@ -0,0 +1,93 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::query::details::contact_composite_shape_shape;
+use crate::query::{Contact, PointQueryWithLocation, QueryDispatcher};
+use crate::shape::{Shape, TriMesh};
+use ad_trait::AD;
+use na::Unit;
+
+/// Best contact between a triangle mesh treated as a solid, and any other shape.
+///
+/// Unlike [`contact_composite_shape_shape`], which only considers the surface of the mesh,
+/// this reports a true penetration depth if `g2` is a convex shape overlapping the interior
+/// of the mesh, including if it is completely enclosed by it. The mesh must be closed and
+/// support the inside test of [`TriMesh::signed_distance`], and `g2` must implement
+/// `SupportMap`. Otherwise, this falls back to the surface contact.
+///
+/// The penetration depth is the signed distance to the mesh of the deepest point of `g2`.
+/// Candidates for this deepest point are the support points of `g2` along the opposite of the
+/// normals of the triangles overlapping it, and of the triangle closest to its center.
+pub fn contact_solid_trimesh_shape<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &TriMesh<T>,
+    g2: &dyn Shape<T>,
+    prediction: T,
+) -> Option<Contact<T>>
+where
+    D: QueryDispatcher<T>,
+{
+    let surface_contact = contact_composite_shape_shape(dispatcher, pos12, g1, g2, prediction);
+
+    let sm2 = match g2.as_support_map() {
+        Some(sm2) if g1.has_inside_test() => sm2,
+        _ => return surface_contact,
+    };
+
+    let aabb2 = g2.compute_aabb(pos12);
+    let center2 = aabb2.center();
+
+    if surface_contact.map(|c| c.dist > T::zero()).unwrap_or(true)
+        && g1.signed_projection(&center2).0 >= T::zero()
+    {
+        // The surfaces don’t overlap and `g2` isn’t enclosed by the mesh.
+        return surface_contact;
+    }
+
+    let mut candidates = vec![];
+    g1.qbvh().intersect_aabb(&aabb2, &mut candidates);
+    let (_, (closest_tri, _)) = g1.project_local_point_and_get_location(&center2, false);
+    candidates.push(closest_tri);
+
+    let mut deepest: Option<(T, Point<T>, Point<T>, Unit<Vector<T>>)> = None;
+
+    for tri_id in candidates {
+        if let Some(normal) = g1.triangle(tri_id).normal() {
+            let pt = sm2.support_point(pos12, &-*normal);
+            let (dist, proj) = g1.signed_projection(&pt);
+
+            if deepest.map(|best| dist < best.0).unwrap_or(true) {
+                deepest = Some((dist, pt, proj, normal));
+            }
+        }
+    }
+
+    match deepest {
+        Some((dist, pt, proj, tri_normal)) if dist < T::zero() => {
+            // For very shallow penetrations, the projection is too close to the deepest point
+            // to give a direction, so the normal of the triangle is used instead.
+            let normal1 = Unit::try_new(proj - pt, T::constant(f64::EPSILON)).unwrap_or(tri_normal);
+            let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+            let point2 = pos12.inverse_transform_point(&pt);
+            Some(Contact::new(proj, point2, normal1, normal2, dist))
+        }
+        // The mesh may be enclosed by `g2` instead.
+        _ => surface_contact,
+    }
+}
+
+/// Best contact between any shape and a triangle mesh treated as a solid.
+///
+/// See [`contact_solid_trimesh_shape`] for details.
+pub fn contact_shape_solid_trimesh<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    g2: &TriMesh<T>,
+    prediction: T,
+) -> Option<Contact<T>>
+where
+    D: QueryDispatcher<T>,
+{
+    contact_solid_trimesh_shape(dispatcher, &pos12.inverse(), g2, g1, prediction)
+        .map(|c| c.flipped())
+}
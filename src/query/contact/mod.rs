@@ -13,7 +13,11 @@ pub use self::contact_cuboid_cuboid::contact_cuboid_cuboid;
 pub use self::contact_halfspace_support_map::{
     contact_halfspace_support_map, contact_support_map_halfspace,
 };
-pub use self::contact_shape_shape::contact;
+pub use self::contact_shape_shape::{contact, contact_with_features};
+#[cfg(all(feature = "std", feature = "dim3"))]
+pub use self::contact_solid_trimesh_shape::{
+    contact_shape_solid_trimesh, contact_solid_trimesh_shape,
+};
 #[cfg(feature = "std")] // TODO: doesn’t work without std because of EPA
 pub use self::contact_support_map_support_map::{
     contact_support_map_support_map, contact_support_map_support_map_with_params,
@@ -27,5 +31,7 @@ mod contact_composite_shape_shape;
 mod contact_cuboid_cuboid;
 mod contact_halfspace_support_map;
 mod contact_shape_shape;
+#[cfg(all(feature = "std", feature = "dim3"))]
+mod contact_solid_trimesh_shape;
 #[cfg(feature = "std")] // TODO: doesn’t work without std because of EPA
 mod contact_support_map_support_map;
@@ -9,6 +9,8 @@ use crate::query::ContactManifold;
 #[cfg(feature = "dim2")]
 use crate::shape::Capsule;
 use crate::shape::{HeightField, Shape};
+#[cfg(feature = "dim3")]
+use crate::shape::{RoundHeightField, RoundShape};
 use crate::utils::hashmap::{Entry, HashMap};
 use ad_trait::AD;
 
@@ -82,6 +84,31 @@ pub fn contact_manifolds_heightfield_shape_shapes<ManifoldData, ContactData, T:
             workspace,
             true,
         )
+    } else {
+        #[cfg(feature = "dim3")]
+        if let Some(round_heightfield1) = shape1.as_round_heightfield() {
+            contact_manifolds_round_heightfield_shape(
+                dispatcher,
+                pos12,
+                round_heightfield1,
+                shape2,
+                prediction,
+                manifolds,
+                workspace,
+                false,
+            )
+        } else if let Some(round_heightfield2) = shape2.as_round_heightfield() {
+            contact_manifolds_round_heightfield_shape(
+                dispatcher,
+                &pos12.inverse(),
+                round_heightfield2,
+                shape1,
+                prediction,
+                manifolds,
+                workspace,
+                true,
+            )
+        }
     }
 }
 
@@ -114,6 +141,63 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData, T: AD>(
 ) where
     ManifoldData: Default + Clone,
     ContactData: Default + Copy,
+{
+    contact_manifolds_dilated_heightfield_shape(
+        dispatcher,
+        pos12,
+        heightfield1,
+        T::zero(),
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+/// Computes the contact manifold between an heightfield with rounded corners and an abstract shape.
+///
+/// Each triangle close to `shape2` is handled as a round triangle sub-shape.
+#[cfg(feature = "dim3")]
+pub fn contact_manifolds_round_heightfield_shape<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    round_heightfield1: &RoundHeightField<T>,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
+{
+    contact_manifolds_dilated_heightfield_shape(
+        dispatcher,
+        pos12,
+        &round_heightfield1.inner_shape,
+        round_heightfield1.border_radius,
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+fn contact_manifolds_dilated_heightfield_shape<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    heightfield1: &HeightField<T>,
+    border_radius1: T,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
 {
     ensure_workspace_exists(workspace);
     let workspace: &mut HeightFieldShapeContactManifoldsWorkspace =
@@ -125,14 +209,26 @@ pub fn contact_manifolds_heightfield_shape<ManifoldData, ContactData, T: AD>(
      * Compute interferences.
      */
     // TODO: somehow precompute the Aabb and reuse it?
-    let ls_aabb2 = shape2.compute_aabb(&pos12).loosened(prediction);
+    let ls_aabb2 = shape2
+        .compute_aabb(&pos12)
+        .loosened(prediction + border_radius1);
     let mut old_manifolds = std::mem::replace(manifolds, Vec::new());
 
     heightfield1.map_elements_in_local_aabb(&ls_aabb2, &mut |i, part1| {
         #[cfg(feature = "dim2")]
-        let sub_shape1 = Capsule::new(part1.a, part1.b, 0.0); // TODO: use a segment instead.
+        let sub_shape1 = Capsule::new(part1.a, part1.b, border_radius1); // TODO: use a segment instead.
+        #[cfg(feature = "dim3")]
+        let round_sub_shape1;
         #[cfg(feature = "dim3")]
-        let sub_shape1 = *part1;
+        let sub_shape1: &dyn Shape<T> = if border_radius1.is_zero() {
+            part1
+        } else {
+            round_sub_shape1 = RoundShape {
+                inner_shape: *part1,
+                border_radius: border_radius1,
+            };
+            &round_sub_shape1
+        };
 
         let sub_detector = match workspace.sub_detectors.entry(i) {
             Entry::Occupied(entry) => {
@@ -0,0 +1,39 @@
+use crate::math::Isometry;
+use crate::query::{ContactManifold, DefaultQueryDispatcher, PersistentQueryDispatcher, Unsupported};
+use crate::shape::Shape;
+use ad_trait::AD;
+
+/// Computes all the contact manifolds between two shapes.
+///
+/// Unlike [`PersistentQueryDispatcher::contact_manifolds`], this doesn’t rely on any
+/// workspace or manifolds persisting across calls: each call computes the full set of
+/// manifolds from scratch. This supports any pair of shapes, including composite shapes,
+/// with one manifold per pair of sub-shapes in contact.
+///
+/// Manifolds without any contact closer than `prediction` are not returned. The contact
+/// points and normals of each manifold are expressed in the local-space of the first and
+/// second shapes or, if `subshape_pos1` (resp. `subshape_pos2`) is set, in the local-space
+/// of the involved sub-shape.
+pub fn contact_manifolds<T: AD>(
+    pos1: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    pos2: &Isometry<T>,
+    g2: &dyn Shape<T>,
+    prediction: T,
+) -> Result<Vec<ContactManifold<(), (), T>>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+    let mut manifolds = vec![];
+    let mut workspace = None;
+
+    DefaultQueryDispatcher.contact_manifolds(
+        &pos12,
+        g1,
+        g2,
+        prediction,
+        &mut manifolds,
+        &mut workspace,
+    )?;
+
+    manifolds.retain(|manifold| !manifold.points.is_empty());
+    Ok(manifolds)
+}
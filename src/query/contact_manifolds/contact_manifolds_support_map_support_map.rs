@@ -0,0 +1,60 @@
+use crate::math::Isometry;
+use crate::query::{self, ContactManifold, TrackedContact};
+use crate::shape::{PackedFeatureId, Shape, SupportMap};
+use ad_trait::AD;
+
+/// Computes the contact manifold between two support-mapped shapes, both represented as `Shape` trait-objects.
+pub fn contact_manifold_support_map_support_map_shapes<ManifoldData, ContactData, T: AD>(
+    pos12: &Isometry<T>,
+    shape1: &dyn Shape<T>,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifold: &mut ContactManifold<ManifoldData, ContactData, T>,
+) where
+    ContactData: Default + Copy,
+{
+    if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
+        contact_manifold_support_map_support_map(pos12, s1, s2, prediction, manifold);
+    }
+}
+
+/// Computes the contact manifold between two support-mapped shapes.
+///
+/// The manifold contains at most one contact point. Shapes implementing `PolygonalFeatureMap`
+/// should use `contact_manifold_pfm_pfm` instead to get a full contact manifold.
+pub fn contact_manifold_support_map_support_map<'a, ManifoldData, ContactData, S1, S2, T: AD>(
+    pos12: &Isometry<T>,
+    shape1: &'a S1,
+    shape2: &'a S2,
+    prediction: T,
+    manifold: &mut ContactManifold<ManifoldData, ContactData, T>,
+) where
+    S1: ?Sized + SupportMap<T>,
+    S2: ?Sized + SupportMap<T>,
+    ContactData: Default + Copy,
+{
+    if let Some(contact) =
+        query::details::contact_support_map_support_map(pos12, shape1, shape2, prediction)
+    {
+        let contact_point = TrackedContact::new(
+            contact.point1,
+            contact.point2,
+            PackedFeatureId::UNKNOWN,
+            PackedFeatureId::UNKNOWN,
+            contact.dist,
+        );
+
+        if manifold.points.len() != 1 {
+            manifold.clear();
+            manifold.points.push(contact_point);
+        } else {
+            // Copy only the geometry so we keep the warmstart impulses.
+            manifold.points[0].copy_geometry_from(contact_point);
+        }
+
+        manifold.local_n1 = *contact.normal1;
+        manifold.local_n2 = *contact.normal2;
+    } else {
+        manifold.clear();
+    }
+}
@@ -6,7 +6,7 @@ use crate::query::contact_manifolds::contact_manifolds_workspace::{
 use crate::query::contact_manifolds::{ContactManifoldsWorkspace, InternalEdgesFixer};
 use crate::query::query_dispatcher::PersistentQueryDispatcher;
 use crate::query::ContactManifold;
-use crate::shape::{Shape, TriMesh};
+use crate::shape::{RoundShape, RoundTriMesh, Shape, TriMesh};
 use ad_trait::AD;
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -62,6 +62,28 @@ pub fn contact_manifolds_trimesh_shape_shapes<ManifoldData, ContactData, T: AD>(
             workspace,
             true,
         )
+    } else if let Some(round_trimesh1) = shape1.as_round_trimesh() {
+        contact_manifolds_round_trimesh_shape(
+            dispatcher,
+            pos12,
+            round_trimesh1,
+            shape2,
+            prediction,
+            manifolds,
+            workspace,
+            false,
+        )
+    } else if let Some(round_trimesh2) = shape2.as_round_trimesh() {
+        contact_manifolds_round_trimesh_shape(
+            dispatcher,
+            &pos12.inverse(),
+            round_trimesh2,
+            shape1,
+            prediction,
+            manifolds,
+            workspace,
+            true,
+        )
     }
 }
 
@@ -92,6 +114,62 @@ pub fn contact_manifolds_trimesh_shape<ManifoldData, ContactData, T: AD>(
 ) where
     ManifoldData: Default,
     ContactData: Default + Copy,
+{
+    contact_manifolds_dilated_trimesh_shape(
+        dispatcher,
+        pos12,
+        trimesh1,
+        T::zero(),
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+/// Computes the contact manifold between a triangle-mesh with rounded corners and a shape.
+///
+/// Each triangle close to `shape2` is handled as a round triangle sub-shape.
+pub fn contact_manifolds_round_trimesh_shape<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    round_trimesh1: &RoundTriMesh<T>,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+    flipped: bool,
+) where
+    ManifoldData: Default,
+    ContactData: Default + Copy,
+{
+    contact_manifolds_dilated_trimesh_shape(
+        dispatcher,
+        pos12,
+        &round_trimesh1.inner_shape,
+        round_trimesh1.border_radius,
+        shape2,
+        prediction,
+        manifolds,
+        workspace,
+        flipped,
+    )
+}
+
+fn contact_manifolds_dilated_trimesh_shape<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    trimesh1: &TriMesh<T>,
+    border_radius1: T,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+    flipped: bool,
+) where
+    ManifoldData: Default,
+    ContactData: Default + Copy,
 {
     ensure_workspace_exists(workspace);
     let workspace: &mut TriMeshShapeContactManifoldsWorkspace<T> =
@@ -101,7 +179,9 @@ pub fn contact_manifolds_trimesh_shape<ManifoldData, ContactData, T: AD>(
      * Compute interferences.
      */
     // TODO: somehow precompute the Aabb and reuse it?
-    let mut new_local_aabb2 = shape2.compute_aabb(&pos12).loosened(prediction);
+    let mut new_local_aabb2 = shape2
+        .compute_aabb(&pos12)
+        .loosened(prediction + border_radius1);
     let same_local_aabb2 = workspace.local_aabb2.contains(&new_local_aabb2);
     let mut old_manifolds = Vec::new();
 
@@ -182,18 +262,28 @@ pub fn contact_manifolds_trimesh_shape<ManifoldData, ContactData, T: AD>(
 
         let manifold = &mut manifolds[i];
         let triangle1 = trimesh1.triangle(*triangle_id);
+        let round_triangle1;
+        let sub_shape1: &dyn Shape<T> = if border_radius1.is_zero() {
+            &triangle1
+        } else {
+            round_triangle1 = RoundShape {
+                inner_shape: triangle1,
+                border_radius: border_radius1,
+            };
+            &round_triangle1
+        };
 
         if flipped {
             let _ = dispatcher.contact_manifold_convex_convex(
                 &pos12.inverse(),
                 shape2,
-                &triangle1,
+                sub_shape1,
                 prediction,
                 manifold,
             );
         } else {
             let _ = dispatcher
-                .contact_manifold_convex_convex(pos12, &triangle1, shape2, prediction, manifold);
+                .contact_manifold_convex_convex(pos12, sub_shape1, shape2, prediction, manifold);
         }
     }
 
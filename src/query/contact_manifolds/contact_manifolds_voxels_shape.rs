@@ -0,0 +1,185 @@
+use crate::bounding_volume::BoundingVolume;
+use crate::math::{Isometry, Point};
+use crate::query::contact_manifolds::contact_manifolds_workspace::{
+    TypedWorkspaceData, WorkspaceData,
+};
+use crate::query::contact_manifolds::ContactManifoldsWorkspace;
+use crate::query::query_dispatcher::PersistentQueryDispatcher;
+use crate::query::ContactManifold;
+use crate::shape::{Shape, Voxels};
+use crate::utils::hashmap::{Entry, HashMap};
+use ad_trait::AD;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[derive(Clone)]
+struct SubDetector {
+    manifold_id: usize,
+    timestamp: bool,
+}
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone)]
+pub struct VoxelsShapeContactManifoldsWorkspace {
+    timestamp: bool,
+    // NOTE: the sub-detectors are keyed by voxel keys rather than voxel identifiers because
+    //       the identifiers change when the voxels domain grows.
+    sub_detectors: HashMap<Point<i32>, SubDetector>,
+}
+
+impl VoxelsShapeContactManifoldsWorkspace {
+    pub fn new() -> Self {
+        Self {
+            timestamp: false,
+            sub_detectors: HashMap::default(),
+        }
+    }
+}
+
+/// Computes the contact manifold between a set of voxels and a shape, both represented as `Shape` trait-objects.
+pub fn contact_manifolds_voxels_shape_shapes<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    shape1: &dyn Shape<T>,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
+{
+    if let Some(voxels1) = shape1.as_voxels() {
+        contact_manifolds_voxels_shape(
+            dispatcher, pos12, voxels1, shape2, prediction, manifolds, workspace, false,
+        )
+    } else if let Some(voxels2) = shape2.as_voxels() {
+        contact_manifolds_voxels_shape(
+            dispatcher,
+            &pos12.inverse(),
+            voxels2,
+            shape1,
+            prediction,
+            manifolds,
+            workspace,
+            true,
+        )
+    }
+}
+
+fn ensure_workspace_exists<T: AD>(workspace: &mut Option<ContactManifoldsWorkspace<T>>) {
+    if workspace
+        .as_ref()
+        .and_then(|w| w.0.downcast_ref::<VoxelsShapeContactManifoldsWorkspace>())
+        .is_some()
+    {
+        return;
+    }
+
+    *workspace = Some(ContactManifoldsWorkspace(Box::new(
+        VoxelsShapeContactManifoldsWorkspace::new(),
+    )));
+}
+
+/// Computes the contact manifold between a set of voxels and an abstract shape.
+///
+/// Each filled voxel close to `shape2` is handled as a cuboid sub-shape, so `shape2`
+/// must be supported by `dispatcher.contact_manifold_convex_convex`.
+pub fn contact_manifolds_voxels_shape<ManifoldData, ContactData, T: AD>(
+    dispatcher: &dyn PersistentQueryDispatcher<T, ManifoldData, ContactData>,
+    pos12: &Isometry<T>,
+    voxels1: &Voxels<T>,
+    shape2: &dyn Shape<T>,
+    prediction: T,
+    manifolds: &mut Vec<ContactManifold<ManifoldData, ContactData, T>>,
+    workspace: &mut Option<ContactManifoldsWorkspace<T>>,
+    flipped: bool,
+) where
+    ManifoldData: Default + Clone,
+    ContactData: Default + Copy,
+{
+    ensure_workspace_exists(workspace);
+    let workspace: &mut VoxelsShapeContactManifoldsWorkspace =
+        workspace.as_mut().unwrap().0.downcast_mut().unwrap();
+    let new_timestamp = !workspace.timestamp;
+    workspace.timestamp = new_timestamp;
+
+    /*
+     * Compute interferences.
+     */
+    let pos21 = pos12.inverse();
+    let ls_aabb2 = shape2.compute_aabb(pos12).loosened(prediction);
+    let sub_shape1 = voxels1.voxel_cuboid();
+    let mut old_manifolds = std::mem::replace(manifolds, Vec::new());
+
+    voxels1.map_elements_in_local_aabb(&ls_aabb2, &mut |i, center1| {
+        let part_pos1 = Isometry::from(center1.coords);
+        let key1 = voxels1.voxel_key(i);
+
+        let sub_detector = match workspace.sub_detectors.entry(key1) {
+            Entry::Occupied(entry) => {
+                let sub_detector = entry.into_mut();
+                let manifold = old_manifolds[sub_detector.manifold_id].take();
+                sub_detector.manifold_id = manifolds.len();
+                sub_detector.timestamp = new_timestamp;
+                manifolds.push(manifold);
+                sub_detector
+            }
+            Entry::Vacant(entry) => {
+                let sub_detector = SubDetector {
+                    manifold_id: manifolds.len(),
+                    timestamp: new_timestamp,
+                };
+
+                manifolds.push(ContactManifold::new());
+                entry.insert(sub_detector)
+            }
+        };
+
+        let manifold = &mut manifolds[sub_detector.manifold_id];
+
+        // NOTE: the voxel identifiers change if the voxels domain grows, so the
+        //       sub-shape identifiers and positions are always updated.
+        if flipped {
+            manifold.subshape1 = 0;
+            manifold.subshape2 = i;
+            manifold.subshape_pos2 = Some(part_pos1);
+            let _ = dispatcher.contact_manifold_convex_convex(
+                &(pos21 * part_pos1),
+                shape2,
+                &sub_shape1,
+                prediction,
+                manifold,
+            );
+        } else {
+            manifold.subshape1 = i;
+            manifold.subshape2 = 0;
+            manifold.subshape_pos1 = Some(part_pos1);
+            let _ = dispatcher.contact_manifold_convex_convex(
+                &part_pos1.inv_mul(pos12),
+                &sub_shape1,
+                shape2,
+                prediction,
+                manifold,
+            );
+        }
+    });
+
+    workspace
+        .sub_detectors
+        .retain(|_, detector| detector.timestamp == new_timestamp)
+}
+
+impl<T: AD> WorkspaceData<T> for VoxelsShapeContactManifoldsWorkspace {
+    fn as_typed_workspace_data(&self) -> TypedWorkspaceData<T> {
+        TypedWorkspaceData::VoxelsShapeContactManifoldsWorkspace(self)
+    }
+
+    fn clone_dyn(&self) -> Box<dyn WorkspaceData<T>> {
+        Box::new(self.clone())
+    }
+}
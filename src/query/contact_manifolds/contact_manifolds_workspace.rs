@@ -6,6 +6,8 @@ use crate::query::contact_manifolds::{
     HeightFieldCompositeShapeContactManifoldsWorkspace, HeightFieldShapeContactManifoldsWorkspace,
     TriMeshShapeContactManifoldsWorkspace,
 };
+#[cfg(feature = "dim3")]
+use crate::query::contact_manifolds::VoxelsShapeContactManifoldsWorkspace;
 
 use ad_trait::AD;
 
@@ -27,6 +29,9 @@ pub enum TypedWorkspaceData<'a, T: AD> {
     ),
     /// A composite shape vs. shape workspace.
     CompositeShapeShapeContactManifoldsWorkspace(&'a CompositeShapeShapeContactManifoldsWorkspace),
+    /// A voxels vs. shape workspace.
+    #[cfg(feature = "dim3")]
+    VoxelsShapeContactManifoldsWorkspace(&'a VoxelsShapeContactManifoldsWorkspace),
     /// A custom workspace.
     Custom(u32),
 }
@@ -44,6 +49,8 @@ enum DeserializableWorkspaceData<T: AD> {
         CompositeShapeCompositeShapeContactManifoldsWorkspace,
     ),
     CompositeShapeShapeContactManifoldsWorkspace(CompositeShapeShapeContactManifoldsWorkspace),
+    #[cfg(feature = "dim3")]
+    VoxelsShapeContactManifoldsWorkspace(VoxelsShapeContactManifoldsWorkspace),
     Custom(u32),
 }
 
@@ -66,6 +73,10 @@ impl<T: AD> DeserializableWorkspaceData<T> {
             DeserializableWorkspaceData::CompositeShapeShapeContactManifoldsWorkspace(w) => {
                 Some(ContactManifoldsWorkspace(Box::new(w)))
             }
+            #[cfg(feature = "dim3")]
+            DeserializableWorkspaceData::VoxelsShapeContactManifoldsWorkspace(w) => {
+                Some(ContactManifoldsWorkspace(Box::new(w)))
+            }
             DeserializableWorkspaceData::Custom(_) => None,
         }
     }
@@ -26,11 +26,22 @@ pub use self::contact_manifolds_heightfield_composite_shape::contact_manifolds_h
 pub use self::contact_manifolds_heightfield_shape::{
     contact_manifolds_heightfield_shape, contact_manifolds_heightfield_shape_shapes,
 };
+#[cfg(feature = "dim3")]
+pub use self::contact_manifolds_heightfield_shape::contact_manifolds_round_heightfield_shape;
 pub use self::contact_manifolds_pfm_pfm::{
     contact_manifold_pfm_pfm, contact_manifold_pfm_pfm_shapes,
 };
+pub use self::contact_manifolds_shape_shape::contact_manifolds;
+pub use self::contact_manifolds_support_map_support_map::{
+    contact_manifold_support_map_support_map, contact_manifold_support_map_support_map_shapes,
+};
 pub use self::contact_manifolds_trimesh_shape::{
-    contact_manifolds_trimesh_shape, contact_manifolds_trimesh_shape_shapes,
+    contact_manifolds_round_trimesh_shape, contact_manifolds_trimesh_shape,
+    contact_manifolds_trimesh_shape_shapes,
+};
+#[cfg(feature = "dim3")]
+pub use self::contact_manifolds_voxels_shape::{
+    contact_manifolds_voxels_shape, contact_manifolds_voxels_shape_shapes,
 };
 pub use self::contact_manifolds_workspace::{
     ContactManifoldsWorkspace, TypedWorkspaceData, WorkspaceData,
@@ -44,6 +55,8 @@ pub(self) use {
     self::contact_manifolds_trimesh_shape::TriMeshShapeContactManifoldsWorkspace,
     self::internal_edges_fixer::InternalEdgesFixer,
 };
+#[cfg(feature = "dim3")]
+pub(self) use self::contact_manifolds_voxels_shape::VoxelsShapeContactManifoldsWorkspace;
 
 mod contact_manifold;
 mod contact_manifolds_ball_ball;
@@ -58,6 +71,10 @@ mod contact_manifolds_halfspace_pfm;
 mod contact_manifolds_heightfield_composite_shape;
 mod contact_manifolds_heightfield_shape;
 mod contact_manifolds_pfm_pfm;
+mod contact_manifolds_shape_shape;
+mod contact_manifolds_support_map_support_map;
 mod contact_manifolds_trimesh_shape;
+#[cfg(feature = "dim3")]
+mod contact_manifolds_voxels_shape;
 mod contact_manifolds_workspace;
 mod internal_edges_fixer;
@@ -1,14 +1,16 @@
 use crate::math::{Isometry, Point, Vector};
 use crate::query::{
-    self, details::NonlinearTOIMode, ClosestPoints, Contact, NonlinearRigidMotion, QueryDispatcher,
+    self, details::NonlinearTOIMode, ClosestPoints, Contact, QueryDispatcher, RigidMotion,
     Unsupported, TOI,
 };
 #[cfg(feature = "std")]
 use crate::query::{
-    contact_manifolds::ContactManifoldsWorkspace, query_dispatcher::PersistentQueryDispatcher,
-    ContactManifold,
+    contact_manifolds::ContactManifoldsWorkspace, gjk::DistanceCache,
+    query_dispatcher::PersistentQueryDispatcher, ContactManifold,
 };
 use crate::shape::{HalfSpace, Segment, Shape, ShapeType};
+#[cfg(all(feature = "std", feature = "dim3"))]
+use crate::shape::{TriMesh, TriMeshFlags};
 use ad_trait::{AD};
 
 /// A dispatcher that exposes built-in queries
@@ -62,6 +64,25 @@ impl<T: AD> QueryDispatcher<T> for DefaultQueryDispatcher {
                 pos12, s1, s2,
             ))
         } else {
+            #[cfg(feature = "std")]
+            if let Some((inner1, border_radius1)) = as_round_composite_shape(shape1) {
+                return Ok(self.distance(pos12, inner1, shape2)? <= border_radius1);
+            } else if let Some((inner2, border_radius2)) = as_round_composite_shape(shape2) {
+                return Ok(self.distance(pos12, shape1, inner2)? <= border_radius2);
+            }
+
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "std")]
+            if let Some(v1) = shape1.as_voxels() {
+                return Ok(query::details::intersection_test_voxels_shape(
+                    self, pos12, v1, shape2,
+                ));
+            } else if let Some(v2) = shape2.as_voxels() {
+                return Ok(query::details::intersection_test_shape_voxels(
+                    self, pos12, shape1, v2,
+                ));
+            }
+
             #[cfg(feature = "std")]
             if let Some(c1) = shape1.as_composite_shape() {
                 return Ok(query::details::intersection_test_composite_shape_shape(
@@ -121,6 +142,30 @@ impl<T: AD> QueryDispatcher<T> for DefaultQueryDispatcher {
                 pos12, s1, s2,
             ))
         } else {
+            // The penetration depth is ignored: the distance is zero as soon as a shape
+            // overlaps the interior of a solid mesh.
+            #[cfg(all(feature = "std", feature = "dim3"))]
+            if let Some(mesh1) = as_solid_trimesh(shape1) {
+                let dist = query::details::distance_solid_trimesh_shape(
+                    self, pos12, mesh1, shape2,
+                );
+                return Ok(dist.max(T::zero()));
+            } else if let Some(mesh2) = as_solid_trimesh(shape2) {
+                let dist = query::details::distance_shape_solid_trimesh(
+                    self, pos12, shape1, mesh2,
+                );
+                return Ok(dist.max(T::zero()));
+            }
+
+            #[cfg(feature = "std")]
+            if let Some((inner1, border_radius1)) = as_round_composite_shape(shape1) {
+                let dist = self.distance(pos12, inner1, shape2)?;
+                return Ok((dist - border_radius1).max(T::zero()));
+            } else if let Some((inner2, border_radius2)) = as_round_composite_shape(shape2) {
+                let dist = self.distance(pos12, shape1, inner2)?;
+                return Ok((dist - border_radius2).max(T::zero()));
+            }
+
             #[cfg(feature = "std")]
             if let Some(c1) = shape1.as_composite_shape() {
                 return Ok(query::details::distance_composite_shape_shape(
@@ -136,6 +181,59 @@ impl<T: AD> QueryDispatcher<T> for DefaultQueryDispatcher {
         }
     }
 
+    fn distance_with_max(
+        &self,
+        pos12: &Isometry<T>,
+        shape1: &dyn Shape<T>,
+        shape2: &dyn Shape<T>,
+        max_dist: T,
+    ) -> Result<Option<T>, Unsupported> {
+        // Pairs with a closed-form distance are handled by `self.distance`.
+        let closed_form = shape1.as_ball().is_some()
+            || shape2.as_ball().is_some()
+            || (shape1.as_cuboid().is_some() && shape2.as_cuboid().is_some())
+            || (shape1.as_segment().is_some() && shape2.as_segment().is_some());
+
+        if let (false, Some(s1), Some(s2)) = (
+            closed_form,
+            shape1.as_support_map(),
+            shape2.as_support_map(),
+        ) {
+            return Ok(query::details::distance_support_map_support_map_with_max(
+                pos12, s1, s2, max_dist,
+            ));
+        }
+
+        #[cfg(all(feature = "std", feature = "dim3"))]
+        if as_solid_trimesh(shape1).is_some() || as_solid_trimesh(shape2).is_some() {
+            let dist = self.distance(pos12, shape1, shape2)?;
+            return Ok(if dist <= max_dist { Some(dist) } else { None });
+        }
+
+        #[cfg(feature = "std")]
+        if let Some((inner1, border_radius1)) = as_round_composite_shape(shape1) {
+            let dist = self.distance_with_max(pos12, inner1, shape2, max_dist + border_radius1)?;
+            return Ok(dist.map(|dist| (dist - border_radius1).max(T::zero())));
+        } else if let Some((inner2, border_radius2)) = as_round_composite_shape(shape2) {
+            let dist = self.distance_with_max(pos12, shape1, inner2, max_dist + border_radius2)?;
+            return Ok(dist.map(|dist| (dist - border_radius2).max(T::zero())));
+        }
+
+        #[cfg(feature = "std")]
+        if let Some(c1) = shape1.as_composite_shape() {
+            return Ok(query::details::distance_composite_shape_shape_with_max(
+                self, pos12, c1, shape2, max_dist,
+            ));
+        } else if let Some(c2) = shape2.as_composite_shape() {
+            return Ok(query::details::distance_shape_composite_shape_with_max(
+                self, pos12, shape1, c2, max_dist,
+            ));
+        }
+
+        let dist = self.distance(pos12, shape1, shape2)?;
+        Ok(if dist <= max_dist { Some(dist) } else { None })
+    }
+
     fn contact(
         &self,
         pos12: &Isometry<T>,
@@ -173,6 +271,42 @@ impl<T: AD> QueryDispatcher<T> for DefaultQueryDispatcher {
                 pos12, shape1, b2, prediction,
             ))
         } else {
+            #[cfg(all(feature = "std", feature = "dim3"))]
+            if let Some(mesh1) = as_solid_trimesh(shape1) {
+                return Ok(query::details::contact_solid_trimesh_shape(
+                    self, pos12, mesh1, shape2, prediction,
+                ));
+            } else if let Some(mesh2) = as_solid_trimesh(shape2) {
+                return Ok(query::details::contact_shape_solid_trimesh(
+                    self, pos12, shape1, mesh2, prediction,
+                ));
+            }
+
+            #[cfg(feature = "std")]
+            if let Some((inner1, border_radius1)) = as_round_composite_shape(shape1) {
+                let contact = self.contact(pos12, inner1, shape2, prediction + border_radius1)?;
+                return Ok(contact.and_then(|mut c| {
+                    c.point1 += *c.normal1 * border_radius1;
+                    c.dist -= border_radius1;
+                    if c.dist <= prediction {
+                        Some(c)
+                    } else {
+                        None
+                    }
+                }));
+            } else if let Some((inner2, border_radius2)) = as_round_composite_shape(shape2) {
+                let contact = self.contact(pos12, shape1, inner2, prediction + border_radius2)?;
+                return Ok(contact.and_then(|mut c| {
+                    c.point2 += *c.normal2 * border_radius2;
+                    c.dist -= border_radius2;
+                    if c.dist <= prediction {
+                        Some(c)
+                    } else {
+                        None
+                    }
+                }));
+            }
+
             #[cfg(feature = "std")]
             if let (Some(s1), Some(s2)) = (shape1.as_support_map(), shape2.as_support_map()) {
                 return Ok(query::details::contact_support_map_support_map(
@@ -367,9 +501,9 @@ impl<T: AD> QueryDispatcher<T> for DefaultQueryDispatcher {
 
     fn nonlinear_time_of_impact(
         &self,
-        motion1: &NonlinearRigidMotion<T>,
+        motion1: &dyn RigidMotion<T>,
         shape1: &dyn Shape<T>,
-        motion2: &NonlinearRigidMotion<T>,
+        motion2: &dyn RigidMotion<T>,
         shape2: &dyn Shape<T>,
         start_time: T,
         end_time: T,
@@ -501,6 +635,23 @@ where
                     );
                 }
             }
+            (ShapeType::RoundTriMesh, _) | (_, ShapeType::RoundTriMesh) => {
+                contact_manifolds_trimesh_shape_shapes(
+                    self, pos12, shape1, shape2, prediction, manifolds, workspace,
+                );
+            }
+            #[cfg(feature = "dim3")]
+            (ShapeType::RoundHeightField, _) | (_, ShapeType::RoundHeightField) => {
+                contact_manifolds_heightfield_shape_shapes(
+                    self, pos12, shape1, shape2, prediction, manifolds, workspace,
+                );
+            }
+            #[cfg(feature = "dim3")]
+            (ShapeType::Voxels, _) | (_, ShapeType::Voxels) => {
+                contact_manifolds_voxels_shape_shapes(
+                    self, pos12, shape1, shape2, prediction, manifolds, workspace,
+                );
+            }
             _ => {
                 if let Some(composite1) = composite1 {
                     contact_manifolds_composite_shape_shape(
@@ -609,6 +760,10 @@ where
                     contact_manifold_pfm_pfm(
                         pos12, pfm1.0, pfm1.1, pfm2.0, pfm2.1, prediction, manifold,
                     )
+                } else if let (Some(s1), Some(s2)) =
+                    (shape1.as_support_map(), shape2.as_support_map())
+                {
+                    contact_manifold_support_map_support_map(pos12, s1, s2, prediction, manifold)
                 } else {
                     return Err(Unsupported);
                 }
@@ -617,4 +772,68 @@ where
 
         Ok(())
     }
+
+    fn distance_with_cache(
+        &self,
+        pos12: &Isometry<T>,
+        shape1: &dyn Shape<T>,
+        shape2: &dyn Shape<T>,
+        cache: &mut DistanceCache<T>,
+    ) -> Result<T, Unsupported> {
+        if let (false, Some(s1), Some(s2)) = (
+            shape1.as_ball().is_some() && shape2.as_ball().is_some(),
+            shape1.as_support_map(),
+            shape2.as_support_map(),
+        ) {
+            Ok(query::details::distance_support_map_support_map_with_cache(
+                pos12, s1, s2, cache,
+            ))
+        } else {
+            self.distance(pos12, shape1, shape2)
+        }
+    }
+
+    fn closest_points_with_cache(
+        &self,
+        pos12: &Isometry<T>,
+        shape1: &dyn Shape<T>,
+        shape2: &dyn Shape<T>,
+        max_dist: T,
+        cache: &mut DistanceCache<T>,
+    ) -> Result<ClosestPoints<T>, Unsupported> {
+        if let (false, Some(s1), Some(s2)) = (
+            shape1.as_ball().is_some() && shape2.as_ball().is_some(),
+            shape1.as_support_map(),
+            shape2.as_support_map(),
+        ) {
+            Ok(query::details::closest_points_support_map_support_map_with_cache(
+                pos12, s1, s2, max_dist, cache,
+            ))
+        } else {
+            self.closest_points(pos12, shape1, shape2, max_dist)
+        }
+    }
+}
+
+/// Splits a composite shape with rounded corners into its inner shape and its border radius.
+#[cfg(feature = "std")]
+fn as_round_composite_shape<T: AD>(shape: &dyn Shape<T>) -> Option<(&dyn Shape<T>, T)> {
+    if let Some(s) = shape.as_round_trimesh() {
+        return Some((&s.inner_shape, s.border_radius));
+    }
+
+    #[cfg(feature = "dim3")]
+    if let Some(s) = shape.as_round_heightfield() {
+        return Some((&s.inner_shape, s.border_radius));
+    }
+
+    None
+}
+
+/// The triangle mesh `shape` is, if it has the [`TriMeshFlags::SOLID`] flag.
+#[cfg(all(feature = "std", feature = "dim3"))]
+fn as_solid_trimesh<T: AD>(shape: &dyn Shape<T>) -> Option<&TriMesh<T>> {
+    shape
+        .as_trimesh()
+        .filter(|mesh| mesh.flags().contains(TriMeshFlags::SOLID))
 }
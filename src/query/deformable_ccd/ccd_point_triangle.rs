@@ -0,0 +1,39 @@
+use super::coplanarity::{
+    earliest_close_root, lerp, max_relative_displacement, triple_product_cubic,
+};
+use crate::math::Point;
+use crate::query::PointQuery;
+use crate::shape::Triangle;
+use ad_trait::AD;
+
+/// The earliest time in `[0, 1]` when a point and a triangle, both with vertices moving
+/// linearly in time, come into contact.
+///
+/// Each argument gives the position of a vertex at the beginning (`t = 0`) and at the end
+/// (`t = 1`) of the motion. A contact is detected when the point is coplanar with the
+/// triangle, at a distance smaller than `thickness` from it.
+///
+/// # Panics
+///
+/// Panics if `thickness` isn’t positive. Exactly touching primitives are only detected up to
+/// a positive `thickness` since the time of impact is computed with a limited precision.
+pub fn point_triangle_time_of_impact<T: AD>(
+    pt: &[Point<T>; 2],
+    a: &[Point<T>; 2],
+    b: &[Point<T>; 2],
+    c: &[Point<T>; 2],
+    thickness: T,
+) -> Option<T> {
+    let cubic = triple_product_cubic(
+        [b[0] - a[0], b[1] - a[1]],
+        [c[0] - a[0], c[1] - a[1]],
+        [pt[0] - a[0], pt[1] - a[1]],
+    );
+
+    let max_speed = max_relative_displacement(&[pt], &[a, b, c]);
+
+    earliest_close_root(&cubic, max_speed, thickness, |t| {
+        let tri = Triangle::new(lerp(a, t), lerp(b, t), lerp(c, t));
+        tri.distance_to_local_point(&lerp(pt, t), true)
+    })
+}
@@ -0,0 +1,44 @@
+use super::coplanarity::{
+    earliest_close_root, lerp, max_relative_displacement, triple_product_cubic,
+};
+use crate::math::Point;
+use crate::query::details::closest_points_segment_segment_with_locations_nD;
+use crate::shape::Segment;
+use ad_trait::AD;
+
+/// The earliest time in `[0, 1]` when two segments, both with vertices moving linearly in
+/// time, come into contact.
+///
+/// Each argument gives the position of a vertex at the beginning (`t = 0`) and at the end
+/// (`t = 1`) of the motion. A contact is detected when the two segments are coplanar, at a
+/// distance smaller than `thickness` from each other.
+///
+/// # Panics
+///
+/// Panics if `thickness` isn’t positive. Exactly touching primitives are only detected up to
+/// a positive `thickness` since the time of impact is computed with a limited precision.
+pub fn segment_segment_time_of_impact<T: AD>(
+    a1: &[Point<T>; 2],
+    b1: &[Point<T>; 2],
+    a2: &[Point<T>; 2],
+    b2: &[Point<T>; 2],
+    thickness: T,
+) -> Option<T> {
+    let cubic = triple_product_cubic(
+        [b1[0] - a1[0], b1[1] - a1[1]],
+        [b2[0] - a2[0], b2[1] - a2[1]],
+        [a2[0] - a1[0], a2[1] - a1[1]],
+    );
+
+    let max_speed = max_relative_displacement(&[a1, b1], &[a2, b2]);
+
+    earliest_close_root(&cubic, max_speed, thickness, |t| {
+        let seg1 = Segment::new(lerp(a1, t), lerp(b1, t));
+        let seg2 = Segment::new(lerp(a2, t), lerp(b2, t));
+        let (loc1, loc2) = closest_points_segment_segment_with_locations_nD(
+            (&seg1.a, &seg1.b),
+            (&seg2.a, &seg2.b),
+        );
+        na::distance(&seg1.point_at(&loc1), &seg2.point_at(&loc2))
+    })
+}
@@ -0,0 +1,228 @@
+use super::{point_triangle_time_of_impact, segment_segment_time_of_impact};
+use crate::bounding_volume::{Aabb, BoundingVolume};
+use crate::math::Point;
+use crate::partitioning::{Qbvh, QbvhUpdateWorkspace};
+use crate::query::visitors::BoundingVolumeIntersectionsSimultaneousVisitor;
+use crate::shape::FeatureId;
+use ad_trait::AD;
+
+/// A triangle mesh with vertices moving linearly in time, e.g., the surface of a cloth or a
+/// soft body during one time step.
+///
+/// Its `Qbvh` bounds the volume swept by each triangle during the motion. It is refitted,
+/// without any topology change, whenever the motion of the vertices is updated.
+pub struct DeformingTriMesh<T: AD> {
+    indices: Vec<[u32; 3]>,
+    start_vertices: Vec<Point<T>>,
+    end_vertices: Vec<Point<T>>,
+    margin: T,
+    qbvh: Qbvh<u32, T>,
+    workspace: QbvhUpdateWorkspace<T>,
+}
+
+impl<T: AD> DeformingTriMesh<T> {
+    /// Creates a deforming mesh with vertices moving from `start_vertices` to `end_vertices`.
+    ///
+    /// The swept Aabb of each triangle is enlarged by `margin`, which must be at least
+    /// half of the `thickness` given to [`time_of_impacts_deforming_trimeshes`].
+    ///
+    /// # Panics
+    /// Panics if `start_vertices` and `end_vertices` don’t have the same length.
+    pub fn new(
+        indices: Vec<[u32; 3]>,
+        start_vertices: Vec<Point<T>>,
+        end_vertices: Vec<Point<T>>,
+        margin: T,
+    ) -> Self {
+        assert_eq!(start_vertices.len(), end_vertices.len());
+
+        let mut result = Self {
+            indices,
+            start_vertices,
+            end_vertices,
+            margin,
+            qbvh: Qbvh::new(),
+            workspace: QbvhUpdateWorkspace::default(),
+        };
+
+        let aabbs: Vec<_> = (0..result.indices.len() as u32)
+            .map(|i| (i, result.swept_triangle_aabb(i)))
+            .collect();
+        result.qbvh.clear_and_rebuild(aabbs.into_iter(), T::zero());
+        result
+    }
+
+    /// The indices of the vertices of each triangle of this mesh.
+    pub fn indices(&self) -> &[[u32; 3]] {
+        &self.indices
+    }
+
+    /// The positions of the vertices at the beginning of the motion.
+    pub fn start_vertices(&self) -> &[Point<T>] {
+        &self.start_vertices
+    }
+
+    /// The positions of the vertices at the end of the motion.
+    pub fn end_vertices(&self) -> &[Point<T>] {
+        &self.end_vertices
+    }
+
+    /// The `Qbvh` bounding the volume swept by each triangle.
+    pub fn qbvh(&self) -> &Qbvh<u32, T> {
+        &self.qbvh
+    }
+
+    /// Replaces the motion of the vertices of this mesh, e.g., for the next time step.
+    ///
+    /// The topology of the mesh is kept unchanged and its `Qbvh` is refitted.
+    ///
+    /// # Panics
+    /// Panics if the vertex buffers don’t have the same length as the current ones.
+    pub fn set_vertices(&mut self, start_vertices: Vec<Point<T>>, end_vertices: Vec<Point<T>>) {
+        assert_eq!(
+            start_vertices.len(),
+            self.start_vertices.len(),
+            "The number of vertices of a deforming mesh cannot be modified."
+        );
+        assert_eq!(start_vertices.len(), end_vertices.len());
+        self.start_vertices = start_vertices;
+        self.end_vertices = end_vertices;
+
+        for i in 0..self.indices.len() as u32 {
+            self.qbvh.pre_update_or_insert(i);
+        }
+
+        let indices = &self.indices;
+        let start_vertices = &self.start_vertices;
+        let end_vertices = &self.end_vertices;
+        let margin = self.margin;
+        let _ = self.qbvh.refit(T::zero(), &mut self.workspace, |i| {
+            let motion = triangle_motion(indices, start_vertices, end_vertices, *i);
+            swept_aabb(&motion, margin)
+        });
+    }
+
+    /// The motion of the three vertices of the `i`-th triangle.
+    pub fn triangle_motion(&self, i: u32) -> [[Point<T>; 2]; 3] {
+        triangle_motion(&self.indices, &self.start_vertices, &self.end_vertices, i)
+    }
+
+    /// The Aabb, enlarged by the margin of this mesh, of the volume swept by the `i`-th triangle.
+    pub fn swept_triangle_aabb(&self, i: u32) -> Aabb<T> {
+        swept_aabb(&self.triangle_motion(i), self.margin)
+    }
+}
+
+fn triangle_motion<T: AD>(
+    indices: &[[u32; 3]],
+    start_vertices: &[Point<T>],
+    end_vertices: &[Point<T>],
+    i: u32,
+) -> [[Point<T>; 2]; 3] {
+    indices[i as usize].map(|k| [start_vertices[k as usize], end_vertices[k as usize]])
+}
+
+fn swept_aabb<T: AD>(motion: &[[Point<T>; 2]; 3], margin: T) -> Aabb<T> {
+    Aabb::from_points(motion.iter().flatten()).loosened(margin)
+}
+
+/// The earliest contact between two triangles of two deforming meshes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeformingTriMeshTOI<T: AD> {
+    /// The time in `[0, 1]` of the contact.
+    pub toi: T,
+    /// The index of the triangle of the first mesh.
+    pub triangle1: u32,
+    /// The index of the triangle of the second mesh.
+    pub triangle2: u32,
+    /// The feature of the first triangle involved in the contact.
+    ///
+    /// This is either a vertex hitting the second triangle, an edge hitting an edge of
+    /// the second triangle, or the face hit by a vertex of the second triangle.
+    pub feature1: FeatureId,
+    /// The feature of the second triangle involved in the contact.
+    pub feature2: FeatureId,
+}
+
+/// The earliest contact of each pair of triangles of two deforming meshes that come into contact.
+///
+/// For each pair of triangles with overlapping swept Aabbs, the six vertex-face and nine
+/// edge-edge combinations are tested with [`point_triangle_time_of_impact`] and
+/// [`segment_segment_time_of_impact`], and only the earliest contact is reported.
+///
+/// # Panics
+///
+/// Panics if `thickness` isn’t positive.
+pub fn time_of_impacts_deforming_trimeshes<T: AD>(
+    mesh1: &DeformingTriMesh<T>,
+    mesh2: &DeformingTriMesh<T>,
+    thickness: T,
+) -> Vec<DeformingTriMeshTOI<T>> {
+    assert!(
+        thickness > T::zero(),
+        "The thickness must be positive to detect contacts."
+    );
+
+    let mut pairs = vec![];
+    let mut visitor = BoundingVolumeIntersectionsSimultaneousVisitor::new(|i: &u32, j: &u32| {
+        pairs.push((*i, *j));
+        true
+    });
+    mesh1.qbvh.traverse_bvtt(&mesh2.qbvh, &mut visitor);
+
+    pairs
+        .into_iter()
+        .filter_map(|(i, j)| {
+            let tri1 = mesh1.triangle_motion(i);
+            let tri2 = mesh2.triangle_motion(j);
+            triangles_time_of_impact(&tri1, &tri2, thickness).map(|(toi, feature1, feature2)| {
+                DeformingTriMeshTOI {
+                    toi,
+                    triangle1: i,
+                    triangle2: j,
+                    feature1,
+                    feature2,
+                }
+            })
+        })
+        .collect()
+}
+
+/// The earliest contact between two moving triangles, with the features involved.
+fn triangles_time_of_impact<T: AD>(
+    tri1: &[[Point<T>; 2]; 3],
+    tri2: &[[Point<T>; 2]; 3],
+    thickness: T,
+) -> Option<(T, FeatureId, FeatureId)> {
+    let mut best: Option<(T, FeatureId, FeatureId)> = None;
+    let mut keep_earliest = |toi: Option<T>, fid1, fid2| {
+        if let Some(toi) = toi {
+            if best.map(|best| toi < best.0).unwrap_or(true) {
+                best = Some((toi, fid1, fid2));
+            }
+        }
+    };
+
+    for k in 0..3 {
+        let toi = point_triangle_time_of_impact(&tri1[k], &tri2[0], &tri2[1], &tri2[2], thickness);
+        keep_earliest(toi, FeatureId::Vertex(k as u32), FeatureId::Face(0));
+
+        let toi = point_triangle_time_of_impact(&tri2[k], &tri1[0], &tri1[1], &tri1[2], thickness);
+        keep_earliest(toi, FeatureId::Face(0), FeatureId::Vertex(k as u32));
+    }
+
+    for e1 in 0..3 {
+        for e2 in 0..3 {
+            let toi = segment_segment_time_of_impact(
+                &tri1[e1],
+                &tri1[(e1 + 1) % 3],
+                &tri2[e2],
+                &tri2[(e2 + 1) % 3],
+                thickness,
+            );
+            keep_earliest(toi, FeatureId::Edge(e1 as u32), FeatureId::Edge(e2 as u32));
+        }
+    }
+
+    best
+}
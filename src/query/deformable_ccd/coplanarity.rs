@@ -0,0 +1,144 @@
+use crate::math::{Point, Vector};
+use crate::utils::{self, Interval, IntervalFunction};
+use ad_trait::AD;
+
+/// A cubic polynomial `c[0] + c[1] * t + c[2] * t² + c[3] * t³`.
+pub(super) struct Cubic<T: AD> {
+    coeffs: [T; 4],
+}
+
+impl<T: AD> IntervalFunction<T> for Cubic<T> {
+    fn eval(&self, t: T) -> T {
+        let c = &self.coeffs;
+        ((c[3] * t + c[2]) * t + c[1]) * t + c[0]
+    }
+
+    fn eval_interval(&self, t: Interval<T>) -> Interval<T> {
+        let c = &self.coeffs;
+        ((t * c[3] + c[2]) * t + c[1]) * t + c[0]
+    }
+
+    fn eval_interval_gradient(&self, t: Interval<T>) -> Interval<T> {
+        let c = &self.coeffs;
+        (t * (c[3] * T::constant(3.0)) + c[2] * T::constant(2.0)) * t + c[1]
+    }
+}
+
+/// The cubic `(u(t) × v(t)) · w(t)`, where each vector moves linearly from its first to its
+/// second value as `t` goes from 0 to 1.
+///
+/// It vanishes whenever the three vectors are coplanar.
+pub(super) fn triple_product_cubic<T: AD>(
+    u: [Vector<T>; 2],
+    v: [Vector<T>; 2],
+    w: [Vector<T>; 2],
+) -> Cubic<T> {
+    let (u0, du) = (u[0], u[1] - u[0]);
+    let (v0, dv) = (v[0], v[1] - v[0]);
+    let (w0, dw) = (w[0], w[1] - w[0]);
+
+    let n0 = u0.cross(&v0);
+    let n1 = u0.cross(&dv) + du.cross(&v0);
+    let n2 = du.cross(&dv);
+
+    Cubic {
+        coeffs: [
+            n0.dot(&w0),
+            n0.dot(&dw) + n1.dot(&w0),
+            n1.dot(&dw) + n2.dot(&w0),
+            n2.dot(&dw),
+        ],
+    }
+}
+
+/// The point moving linearly from `pts[0]` to `pts[1]`, at time `t`.
+#[inline]
+pub(super) fn lerp<T: AD>(pts: &[Point<T>; 2], t: T) -> Point<T> {
+    pts[0] + (pts[1] - pts[0]) * t
+}
+
+/// An upper bound of the speed at which the distance between two primitives changes, if
+/// each of their vertices moves linearly from its first to its second position.
+///
+/// Any point of a primitive is a convex combination of its vertices, so the relative
+/// displacement of two such points is bounded by the largest relative displacement of two
+/// vertices.
+pub(super) fn max_relative_displacement<T: AD>(
+    vertices1: &[&[Point<T>; 2]],
+    vertices2: &[&[Point<T>; 2]],
+) -> T {
+    let mut result = T::zero();
+
+    for v1 in vertices1 {
+        for v2 in vertices2 {
+            let displacement = (v1[1] - v1[0]) - (v2[1] - v2[0]);
+            result = result.max(displacement.norm());
+        }
+    }
+
+    result
+}
+
+/// The earliest time in `[0, 1]` when `cubic` vanishes and `distance(t) <= thickness`.
+///
+/// The roots of the cubic are isolated with the interval Newton method. Each isolated
+/// interval is then bisected on the proximity test, the sub-intervals being discarded when
+/// `distance` can’t reach `thickness` given that it changes no faster than `max_speed`.
+/// Bisecting is necessary when the primitives stay coplanar during a whole sub-interval of
+/// time (e.g. a point sliding in the plane of a triangle), since the cubic is then zero on
+/// a wide interval while the primitives only touch at some of its times.
+///
+/// The roots are only known up to the bisection precision, so `thickness` must be positive:
+/// an exact contact (at a distance of exactly zero) would almost never be found.
+pub(super) fn earliest_close_root<T: AD>(
+    cubic: &Cubic<T>,
+    max_speed: T,
+    thickness: T,
+    mut distance: impl FnMut(T) -> T,
+) -> Option<T> {
+    assert!(
+        thickness > T::zero(),
+        "The thickness must be positive to detect contacts."
+    );
+
+    let min_width = T::constant(1.0e-6);
+    let mut roots = utils::find_root_intervals(
+        cubic,
+        Interval(T::zero(), T::one()),
+        min_width,
+        T::constant(1.0e-8),
+        64,
+    );
+    roots.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut stack = vec![];
+
+    for root in roots {
+        stack.clear();
+        stack.push(root);
+
+        // Depth-first search, earliest sub-interval first.
+        while let Some(Interval(start, end)) = stack.pop() {
+            if distance(start) <= thickness {
+                return Some(start);
+            }
+
+            let mid = (start + end) * T::constant(0.5);
+            if distance(mid) - thickness > max_speed * (mid - start) {
+                continue;
+            }
+
+            if end - start < min_width {
+                if distance(end) <= thickness {
+                    return Some(end);
+                }
+                continue;
+            }
+
+            stack.push(Interval(mid, end));
+            stack.push(Interval(start, mid));
+        }
+    }
+
+    None
+}
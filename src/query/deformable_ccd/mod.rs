@@ -0,0 +1,16 @@
+//! Continuous collision detection between primitives with vertices moving linearly in time.
+//!
+//! Unlike [`crate::query::time_of_impact`], which handles rigid motions, these queries
+//! are meant for deformable bodies (cloth, soft bodies) where each vertex follows its own
+//! straight line between the beginning (`t = 0`) and the end (`t = 1`) of a time step.
+
+pub use self::ccd_point_triangle::point_triangle_time_of_impact;
+pub use self::ccd_segment_segment::segment_segment_time_of_impact;
+pub use self::ccd_trimesh_trimesh::{
+    time_of_impacts_deforming_trimeshes, DeformingTriMesh, DeformingTriMeshTOI,
+};
+
+mod ccd_point_triangle;
+mod ccd_segment_segment;
+mod ccd_trimesh_trimesh;
+mod coplanarity;
@@ -17,3 +17,19 @@ pub fn distance<T: AD>(
     let pos12 = pos1.inv_mul(&pos2);
     DefaultQueryDispatcher.distance(&pos12, g1, g2)
 }
+
+/// Computes the minimum distance separating two shapes, if it is smaller than `max_dist`.
+///
+/// Returns `None` if the objects are separated by a distance greater than `max_dist`. This
+/// is cheaper than [`distance`] when only nearby objects matter since the computation stops
+/// as soon as the distance is known to exceed `max_dist`.
+pub fn distance_with_max<T: AD>(
+    pos1: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    pos2: &Isometry<T>,
+    g2: &dyn Shape<T>,
+    max_dist: T,
+) -> Result<Option<T>, Unsupported> {
+    let pos12 = pos1.inv_mul(&pos2);
+    DefaultQueryDispatcher.distance_with_max(&pos12, g1, g2, max_dist)
+}
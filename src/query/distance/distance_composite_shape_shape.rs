@@ -40,6 +40,42 @@ where
     distance_composite_shape_shape(dispatcher, &pos12.inverse(), g2, g1)
 }
 
+/// Smallest distance between a composite shape and any other shape, if it is smaller than `max_dist`.
+///
+/// The parts of the composite shape farther than `max_dist` are never tested, and `None`
+/// is returned if all of them are.
+pub fn distance_composite_shape_shape_with_max<D: ?Sized, G1: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &G1,
+    g2: &dyn Shape<T>,
+    max_dist: T,
+) -> Option<T>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    let mut visitor = CompositeShapeAgainstAnyDistanceVisitor::new(dispatcher, pos12, g1, g2);
+    g1.typed_qbvh()
+        .traverse_best_first_node(&mut visitor, 0, max_dist)
+        .map(|res| res.1 .1)
+}
+
+/// Smallest distance between a shape and a composite shape, if it is smaller than `max_dist`.
+pub fn distance_shape_composite_shape_with_max<D: ?Sized, G2: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    g2: &G2,
+    max_dist: T,
+) -> Option<T>
+where
+    D: QueryDispatcher<T>,
+    G2: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    distance_composite_shape_shape_with_max(dispatcher, &pos12.inverse(), g2, g1, max_dist)
+}
+
 /// A visitor for computing the distance between a composite shape and a shape.
 pub struct CompositeShapeAgainstAnyDistanceVisitor<'a, D: ?Sized, G1: ?Sized + 'a, T: AD> {
     msum_shift: Vector<T>,
@@ -103,15 +139,19 @@ where
             for ii in 0..SIMD_WIDTH {
                 if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
                     let part_id = *data[ii].unwrap();
-                    let mut dist = Ok(T::zero());
+                    let mut dist = Ok(None);
                     self.g1.map_untyped_part_at(part_id, |part_pos1, g1| {
-                        dist =
-                            self.dispatcher
-                                .distance(&part_pos1.inv_mul(self.pos12), g1, self.g2);
+                        // Parts farther than the best distance so far cannot improve it.
+                        dist = self.dispatcher.distance_with_max(
+                            &part_pos1.inv_mul(self.pos12),
+                            g1,
+                            self.g2,
+                            best,
+                        );
                     });
 
                     match dist {
-                        Ok(dist) => {
+                        Ok(Some(dist)) => {
                             if dist == T::zero() {
                                 return SimdBestFirstVisitStatus::ExitEarly(Some((part_id, T::zero())));
                             } else {
@@ -120,7 +160,7 @@ where
                                 results[ii] = Some((part_id, dist));
                             }
                         }
-                        Err(_) => {}
+                        Ok(None) | Err(_) => {}
                     }
                 }
             }
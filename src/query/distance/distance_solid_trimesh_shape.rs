@@ -0,0 +1,54 @@
+use crate::math::Isometry;
+use crate::query::details::{contact_solid_trimesh_shape, distance_composite_shape_shape};
+use crate::query::QueryDispatcher;
+use crate::shape::{Shape, TriMesh};
+use ad_trait::AD;
+
+/// Signed distance between a triangle mesh treated as a solid, and any other shape.
+///
+/// This is negative, and equal to the opposite of the penetration depth, if `g2` overlaps the
+/// interior of the mesh. See [`contact_solid_trimesh_shape`] for the conditions under which
+/// the penetration depth can be computed.
+pub fn distance_solid_trimesh_shape<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &TriMesh<T>,
+    g2: &dyn Shape<T>,
+) -> T
+where
+    D: QueryDispatcher<T>,
+{
+    let dist = distance_composite_shape_shape(dispatcher, pos12, g1, g2);
+
+    let center2 = g2.compute_aabb(pos12).center();
+
+    if dist > T::zero()
+        && g1
+            .signed_distance(&center2)
+            .map(|center_dist| center_dist >= T::zero())
+            .unwrap_or(true)
+    {
+        // The surfaces don’t overlap and `g2` isn’t enclosed by the mesh.
+        return dist;
+    }
+
+    match contact_solid_trimesh_shape(dispatcher, pos12, g1, g2, T::zero()) {
+        Some(c) if c.dist < T::zero() => c.dist,
+        _ => dist,
+    }
+}
+
+/// Signed distance between any shape and a triangle mesh treated as a solid.
+///
+/// See [`distance_solid_trimesh_shape`] for details.
+pub fn distance_shape_solid_trimesh<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    g2: &TriMesh<T>,
+) -> T
+where
+    D: QueryDispatcher<T>,
+{
+    distance_solid_trimesh_shape(dispatcher, &pos12.inverse(), g2, g1)
+}
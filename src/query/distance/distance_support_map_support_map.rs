@@ -1,5 +1,5 @@
 use crate::math::{Isometry, Vector};
-use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+use crate::query::gjk::{self, CSOPoint, DistanceCache, GJKResult, VoronoiSimplex};
 use crate::shape::SupportMap;
 use ad_trait::AD;
 
@@ -54,3 +54,84 @@ where
         GJKResult::NoIntersection(_) => T::zero(), // FIXME: GJK did not converge.
     }
 }
+
+/// Distance between support-mapped shapes, if it is smaller than `max_dist`.
+///
+/// GJK stops as soon as it finds a lower bound of the distance greater than `max_dist`, in
+/// which case `None` is returned. If GJK fails to converge, the distance is recomputed without
+/// early-out instead.
+pub fn distance_support_map_support_map_with_max<G1: ?Sized, G2: ?Sized, T: AD>(
+    pos12: &Isometry<T>,
+    g1: &G1,
+    g2: &G2,
+    max_dist: T,
+) -> Option<T>
+where
+    G1: SupportMap<T>,
+    G2: SupportMap<T>,
+{
+    let simplex = &mut VoronoiSimplex::new();
+    let dir = Unit::try_new(
+        -pos12.translation.vector,
+        T::constant(crate::math::DEFAULT_EPSILON),
+    )
+    .unwrap_or_else(Vector::x_axis);
+    simplex.reset(CSOPoint::from_shapes_toward(pos12, g1, g2, &dir));
+
+    match gjk::closest_points(pos12, g1, g2, max_dist, true, simplex) {
+        GJKResult::Intersection => Some(T::zero()),
+        GJKResult::ClosestPoints(p1, p2, _) => {
+            let dist = na::distance(&p1, &p2);
+            if dist <= max_dist {
+                Some(dist)
+            } else {
+                None
+            }
+        }
+        GJKResult::Proximity(_) => unreachable!(),
+        GJKResult::NoIntersection(dir) => {
+            // NOTE: GJK returns `NoIntersection` both when it found a separating lower bound
+            // greater than `max_dist`, and when it did not converge. Only trust the former.
+            let cso_point = CSOPoint::from_shapes(pos12, g1, g2, &dir);
+
+            if -dir.dot(&cso_point.point.coords) > max_dist {
+                None
+            } else {
+                let dist = distance_support_map_support_map_with_params(
+                    pos12,
+                    g1,
+                    g2,
+                    &mut VoronoiSimplex::new(),
+                    None,
+                );
+
+                if dist <= max_dist {
+                    Some(dist)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Distance between support-mapped shapes, warm-started with the result of a previous query.
+///
+/// The `cache` must have been used only by previous queries on the same pair of shapes.
+pub fn distance_support_map_support_map_with_cache<G1: ?Sized, G2: ?Sized, T: AD>(
+    pos12: &Isometry<T>,
+    g1: &G1,
+    g2: &G2,
+    cache: &mut DistanceCache<T>,
+) -> T
+where
+    G1: SupportMap<T>,
+    G2: SupportMap<T>,
+{
+    match cache.closest_points(pos12, g1, g2, T::constant(f64::max_value()), true) {
+        GJKResult::Intersection => T::zero(),
+        GJKResult::ClosestPoints(p1, p2, _) => na::distance(&p1, &p2),
+        GJKResult::Proximity(_) => unreachable!(),
+        GJKResult::NoIntersection(_) => T::zero(), // FIXME: GJK did not converge.
+    }
+}
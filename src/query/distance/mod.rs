@@ -1,13 +1,14 @@
 //! Implementation details of the `distance` function.
 
-pub use self::distance::distance;
+pub use self::distance::{distance, distance_with_max};
 pub use self::distance_ball_ball::distance_ball_ball;
 pub use self::distance_ball_convex_polyhedron::{
     distance_ball_convex_polyhedron, distance_convex_polyhedron_ball,
 };
 #[cfg(feature = "std")]
 pub use self::distance_composite_shape_shape::{
-    distance_composite_shape_shape, distance_shape_composite_shape,
+    distance_composite_shape_shape, distance_composite_shape_shape_with_max,
+    distance_shape_composite_shape, distance_shape_composite_shape_with_max,
     CompositeShapeAgainstAnyDistanceVisitor,
 };
 pub use self::distance_cuboid_cuboid::distance_cuboid_cuboid;
@@ -15,8 +16,13 @@ pub use self::distance_halfspace_support_map::{
     distance_halfspace_support_map, distance_support_map_halfspace,
 };
 pub use self::distance_segment_segment::distance_segment_segment;
+#[cfg(all(feature = "std", feature = "dim3"))]
+pub use self::distance_solid_trimesh_shape::{
+    distance_shape_solid_trimesh, distance_solid_trimesh_shape,
+};
 pub use self::distance_support_map_support_map::{
-    distance_support_map_support_map, distance_support_map_support_map_with_params,
+    distance_support_map_support_map, distance_support_map_support_map_with_cache,
+    distance_support_map_support_map_with_max, distance_support_map_support_map_with_params,
 };
 
 mod distance;
@@ -27,4 +33,6 @@ mod distance_composite_shape_shape;
 mod distance_cuboid_cuboid;
 mod distance_halfspace_support_map;
 mod distance_segment_segment;
+#[cfg(all(feature = "std", feature = "dim3"))]
+mod distance_solid_trimesh_shape;
 mod distance_support_map_support_map;
@@ -0,0 +1,111 @@
+use crate::math::{Isometry, Vector};
+use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+use crate::shape::SupportMap;
+use ad_trait::AD;
+
+use na::Unit;
+
+/// Data persisting between successive GJK executions on the same pair of shapes.
+///
+/// The simplex found by the last execution is kept, together with the relative position of
+/// the shapes it was computed for. On the next execution, each vertex of this simplex is moved
+/// to the new relative position before GJK starts. If the relative position changed only
+/// slightly, GJK usually converges after one or two iterations.
+#[derive(Clone, Debug)]
+pub struct DistanceCache<T: AD> {
+    simplex: VoronoiSimplex<T>,
+    pos12: Isometry<T>,
+    separating_axis: Option<Unit<Vector<T>>>,
+    valid: bool,
+}
+
+impl<T: AD> Default for DistanceCache<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AD> DistanceCache<T> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            simplex: VoronoiSimplex::new(),
+            pos12: Isometry::identity(),
+            separating_axis: None,
+            valid: false,
+        }
+    }
+
+    /// Empties this cache so that the next GJK execution starts from scratch.
+    ///
+    /// This must be called before re-using this cache for a different pair of shapes.
+    pub fn reset(&mut self) {
+        self.separating_axis = None;
+        self.valid = false;
+    }
+
+    /// The separating axis found by the last GJK execution, if the shapes were disjoint.
+    ///
+    /// It is expressed in the local-space of the first shape.
+    pub fn separating_axis(&self) -> Option<Unit<Vector<T>>> {
+        self.separating_axis
+    }
+
+    /// The simplex found by the last GJK execution.
+    pub fn simplex(&self) -> &VoronoiSimplex<T> {
+        &self.simplex
+    }
+
+    /// Runs GJK on two support-mapped shapes, starting from the simplex of the last execution.
+    ///
+    /// See [`gjk::closest_points`] for details about the parameters and the result.
+    pub fn closest_points<G1: ?Sized, G2: ?Sized>(
+        &mut self,
+        pos12: &Isometry<T>,
+        g1: &G1,
+        g2: &G2,
+        max_dist: T,
+        exact_dist: bool,
+    ) -> GJKResult<T>
+    where
+        G1: SupportMap<T>,
+        G2: SupportMap<T>,
+    {
+        self.warm_start(pos12, g1, g2);
+        let result = gjk::closest_points(pos12, g1, g2, max_dist, exact_dist, &mut self.simplex);
+
+        self.separating_axis = match &result {
+            GJKResult::ClosestPoints(_, _, dir)
+            | GJKResult::Proximity(dir)
+            | GJKResult::NoIntersection(dir) => Some(*dir),
+            GJKResult::Intersection => None,
+        };
+
+        result
+    }
+
+    fn warm_start<G1: ?Sized, G2: ?Sized>(&mut self, pos12: &Isometry<T>, g1: &G1, g2: &G2)
+    where
+        G1: SupportMap<T>,
+        G2: SupportMap<T>,
+    {
+        if self.valid {
+            // The points of the second shape are expressed in the local-space of the
+            // first shape, so they have to follow the change of relative position.
+            let delta = pos12 * self.pos12.inverse();
+            self.simplex
+                .modify_pnts(&|pt| *pt = CSOPoint::new(pt.orig1, delta * pt.orig2));
+        } else {
+            let dir = Unit::try_new(
+                -pos12.translation.vector,
+                T::constant(crate::math::DEFAULT_EPSILON),
+            )
+            .unwrap_or_else(Vector::x_axis);
+            self.simplex
+                .reset(CSOPoint::from_shapes_toward(pos12, g1, g2, &dir));
+            self.valid = true;
+        }
+
+        self.pos12 = *pos12;
+    }
+}
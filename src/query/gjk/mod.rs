@@ -1,6 +1,7 @@
 //! The GJK algorithm for distance computation.
 
 pub use self::cso_point::CSOPoint;
+pub use self::distance_cache::DistanceCache;
 #[cfg(feature = "dim2")]
 pub use self::voronoi_simplex2::VoronoiSimplex;
 #[cfg(feature = "dim3")]
@@ -9,6 +10,7 @@ pub use gjk::*;
 pub use special_support_maps::*;
 
 mod cso_point;
+mod distance_cache;
 mod gjk;
 mod special_support_maps;
 #[cfg(feature = "dim2")]
@@ -1,7 +1,11 @@
 use na::{self, Unit};
 
 use crate::math::{Isometry, Vector};
+#[cfg(feature = "std")]
+use crate::query::epa::EPA;
 use crate::query::gjk::{self, CSOPoint, GJKResult, VoronoiSimplex};
+#[cfg(feature = "std")]
+use crate::query::{sat, ClosestFeatures, Contact, IntersectionWitness};
 use crate::shape::SupportMap;
 use ad_trait::AD;
 
@@ -58,3 +62,58 @@ where
         GJKResult::ClosestPoints(..) => unreachable!(),
     }
 }
+
+/// Intersection test between support-mapped shapes, returning a witness of the result.
+///
+/// If `hint` is the witness returned by a previous test on the same pair of shapes, its
+/// axis is tested first, and used to initialize GJK otherwise. If the shapes are still
+/// separated along that axis, no GJK iteration is performed at all.
+#[cfg(feature = "std")] // TODO: doesn’t work without std because of EPA
+pub fn intersection_test_support_map_support_map_with_witness<G1: ?Sized, G2: ?Sized, T: AD>(
+    pos12: &Isometry<T>,
+    g1: &G1,
+    g2: &G2,
+    hint: Option<&IntersectionWitness<T>>,
+) -> IntersectionWitness<T>
+where
+    G1: SupportMap<T>,
+    G2: SupportMap<T>,
+{
+    if let Some(IntersectionWitness::Separated { axis }) = hint {
+        if sat::support_map_support_map_compute_separation(g1, g2, pos12, axis) > T::zero() {
+            return IntersectionWitness::Separated { axis: *axis };
+        }
+    }
+
+    let dir = hint
+        .and_then(|hint| hint.axis())
+        .or_else(|| {
+            Unit::try_new(
+                pos12.translation.vector,
+                T::constant(crate::math::DEFAULT_EPSILON),
+            )
+        })
+        .unwrap_or_else(Vector::x_axis);
+    let simplex = &mut VoronoiSimplex::new();
+    simplex.reset(CSOPoint::from_shapes(pos12, g1, g2, &dir));
+
+    match gjk::closest_points(pos12, g1, g2, T::zero(), false, simplex) {
+        GJKResult::Proximity(axis) | GJKResult::NoIntersection(axis) => {
+            IntersectionWitness::Separated { axis }
+        }
+        GJKResult::Intersection => match EPA::new().closest_points(pos12, g1, g2, simplex) {
+            Some((point1, point2_1, normal1)) => {
+                let dist = (point2_1 - point1).dot(&normal1);
+                let point2 = pos12.inverse_transform_point(&point2_1);
+                let normal2 = pos12.inverse_transform_unit_vector(&-normal1);
+                IntersectionWitness::Penetrating {
+                    contact: Contact::new(point1, point2, normal1, normal2, dist),
+                    features: ClosestFeatures::default(),
+                }
+            }
+            // GJK proved the intersection, but EPA failed to compute the penetration.
+            None => IntersectionWitness::Intersecting,
+        },
+        GJKResult::ClosestPoints(..) => unreachable!(),
+    }
+}
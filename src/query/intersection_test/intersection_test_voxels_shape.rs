@@ -0,0 +1,60 @@
+use crate::math::{Isometry, Point};
+use crate::query::QueryDispatcher;
+use crate::shape::{Shape, Voxels};
+use ad_trait::AD;
+
+/// Intersection test between a set of voxels and any other shape.
+///
+/// Each filled voxel intersecting the Aabb of `shape2` is tested as a cuboid.
+pub fn intersection_test_voxels_shape<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    voxels1: &Voxels<T>,
+    shape2: &dyn Shape<T>,
+) -> bool
+where
+    D: QueryDispatcher<T>,
+{
+    let (filled_mins, filled_maxs) = match voxels1.filled_keys_bounds() {
+        Some(bounds) => bounds,
+        None => return false,
+    };
+    let ls_aabb2 = shape2.compute_aabb(pos12);
+    let (mins, maxs) = voxels1.voxel_keys_range_in_local_aabb(&ls_aabb2);
+    let (mins, maxs) = (mins.sup(&filled_mins), maxs.inf(&filled_maxs));
+    let voxel1 = voxels1.voxel_cuboid();
+
+    for k in mins.z..=maxs.z {
+        for j in mins.y..=maxs.y {
+            for i in mins.x..=maxs.x {
+                let key = Point::new(i, j, k);
+
+                if voxels1.is_voxel_filled(&key) {
+                    let part_pos1 = Isometry::from(voxels1.voxel_center(&key).coords);
+
+                    if dispatcher
+                        .intersection_test(&part_pos1.inv_mul(pos12), &voxel1, shape2)
+                        .unwrap_or(false)
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Intersection test between any shape and a set of voxels.
+pub fn intersection_test_shape_voxels<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    shape1: &dyn Shape<T>,
+    voxels2: &Voxels<T>,
+) -> bool
+where
+    D: QueryDispatcher<T>,
+{
+    intersection_test_voxels_shape(dispatcher, &pos12.inverse(), voxels2, shape1)
+}
@@ -0,0 +1,139 @@
+use na::Unit;
+
+use crate::math::{Isometry, Translation, Vector};
+use crate::query::{
+    self, sat, ClosestFeatures, ClosestPoints, Contact, DefaultQueryDispatcher, QueryDispatcher,
+    Unsupported,
+};
+use crate::shape::{Cuboid, Shape};
+use ad_trait::AD;
+
+/// The result of an intersection test, together with a proof of that result.
+///
+/// Everything is expressed in the local-space of the shapes, so a witness stays meaningful
+/// when both shapes move, and can be given back as a hint to the next test on the same pair.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum IntersectionWitness<T: AD> {
+    /// The shapes are disjoint.
+    Separated {
+        /// An axis, expressed in the local-space of the first shape and pointing toward the
+        /// second shape, such that the planes orthogonal to it separate both shapes.
+        ///
+        /// If one of the shapes isn’t convex, this is only the direction between their
+        /// closest points.
+        axis: Unit<Vector<T>>,
+    },
+    /// The shapes are intersecting.
+    Penetrating {
+        /// The deepest penetration between the shapes, expressed in their local-spaces.
+        contact: Contact<T>,
+        /// The features of both shapes the penetration points lie on.
+        ///
+        /// These are only identified by [`intersection_test_with_witness`].
+        features: ClosestFeatures,
+    },
+    /// The shapes are intersecting, but their penetration couldn’t be computed.
+    ///
+    /// This happens if the penetration algorithm fails to converge, or if no penetration
+    /// is defined between the shapes (e.g. if one is a composite shape lying on the other).
+    Intersecting,
+}
+
+impl<T: AD> IntersectionWitness<T> {
+    /// Is this the witness of an intersection?
+    pub fn intersects(&self) -> bool {
+        matches!(
+            self,
+            IntersectionWitness::Penetrating { .. } | IntersectionWitness::Intersecting
+        )
+    }
+
+    /// The separating axis, or the penetration normal, in the local-space of the first shape.
+    ///
+    /// Returns `None` if the shapes are intersecting without a known penetration.
+    pub fn axis(&self) -> Option<Unit<Vector<T>>> {
+        match self {
+            IntersectionWitness::Separated { axis } => Some(*axis),
+            IntersectionWitness::Penetrating { contact, .. } => Some(contact.normal1),
+            IntersectionWitness::Intersecting => None,
+        }
+    }
+}
+
+/// Tests whether two shapes are intersecting, and returns a witness of the result.
+///
+/// The witness is either a separating axis or the deepest penetration between the shapes.
+/// If `hint` is the witness returned by the previous test on the same pair of shapes, it is
+/// used to accelerate this test: for support-mapped shapes that stay separated along the same
+/// axis, this is only two support point computations. For other shapes, the previous axis is
+/// only tested on their bounding boxes.
+///
+/// Pairs of support-mapped shapes are tested with GJK, and their penetration is computed with
+/// EPA. Other pairs are tested with the [`DefaultQueryDispatcher`], which relies on the
+/// separating axis theorem for the polyhedral pairs (cuboids and triangles), including the
+/// sub-shapes of composite shapes.
+pub fn intersection_test_with_witness<T: AD>(
+    pos1: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    pos2: &Isometry<T>,
+    g2: &dyn Shape<T>,
+    hint: Option<&IntersectionWitness<T>>,
+) -> Result<IntersectionWitness<T>, Unsupported> {
+    let pos12 = pos1.inv_mul(pos2);
+
+    let mut witness = if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        query::details::intersection_test_support_map_support_map_with_witness(&pos12, s1, s2, hint)
+    } else {
+        if let Some(IntersectionWitness::Separated { axis }) = hint {
+            if aabb_separation(&pos12, g1, g2, axis) > T::zero() {
+                return Ok(IntersectionWitness::Separated { axis: *axis });
+            }
+        }
+
+        if DefaultQueryDispatcher.intersection_test(&pos12, g1, g2)? {
+            match DefaultQueryDispatcher.contact(&pos12, g1, g2, T::zero())? {
+                Some(contact) => IntersectionWitness::Penetrating {
+                    contact,
+                    features: ClosestFeatures::default(),
+                },
+                None => IntersectionWitness::Intersecting,
+            }
+        } else {
+            let closest_points =
+                DefaultQueryDispatcher.closest_points(&pos12, g1, g2, T::constant(f64::MAX))?;
+            let eps = T::constant(crate::math::DEFAULT_EPSILON);
+            let axis = match closest_points {
+                ClosestPoints::WithinMargin(pt1, pt2) => Unit::try_new(pos12 * pt2 - pt1, eps),
+                ClosestPoints::Intersecting | ClosestPoints::Disjoint => None,
+            }
+            .or_else(|| Unit::try_new(pos12.translation.vector, eps))
+            .unwrap_or_else(Vector::x_axis);
+            IntersectionWitness::Separated { axis }
+        }
+    };
+
+    if let IntersectionWitness::Penetrating { contact, features } = &mut witness {
+        *features = ClosestFeatures::from_local_witnesses(g1, &contact.point1, g2, &contact.point2);
+    }
+
+    Ok(witness)
+}
+
+/// The separation along `axis` between the local bounding boxes of both shapes.
+///
+/// The shapes are disjoint if this is positive.
+fn aabb_separation<T: AD>(
+    pos12: &Isometry<T>,
+    g1: &dyn Shape<T>,
+    g2: &dyn Shape<T>,
+    axis: &Unit<Vector<T>>,
+) -> T {
+    let aabb1 = g1.compute_local_aabb();
+    let aabb2 = g2.compute_local_aabb();
+    let box1 = Cuboid::new(aabb1.half_extents());
+    let box2 = Cuboid::new(aabb2.half_extents());
+    let box_pos12 = Translation::from(-aabb1.center().coords)
+        * pos12
+        * Translation::from(aabb2.center().coords);
+    sat::support_map_support_map_compute_separation(&box1, &box2, &box_pos12, axis)
+}
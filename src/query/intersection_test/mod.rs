@@ -26,6 +26,17 @@ pub use self::intersection_test_halfspace_support_map::{
 };
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map;
 pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map_with_params;
+#[cfg(feature = "std")]
+pub use self::intersection_test_support_map_support_map::intersection_test_support_map_support_map_with_witness;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+pub use self::intersection_test_voxels_shape::{
+    intersection_test_shape_voxels, intersection_test_voxels_shape,
+};
+#[cfg(feature = "std")]
+pub use self::intersection_test_with_witness::{
+    intersection_test_with_witness, IntersectionWitness,
+};
 
 mod intersection_test;
 mod intersection_test_ball_ball;
@@ -37,3 +48,8 @@ mod intersection_test_cuboid_segment;
 mod intersection_test_cuboid_triangle;
 mod intersection_test_halfspace_support_map;
 mod intersection_test_support_map_support_map;
+#[cfg(feature = "std")]
+mod intersection_test_with_witness;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod intersection_test_voxels_shape;
@@ -0,0 +1,192 @@
+use na::Unit;
+use std::collections::BinaryHeap;
+
+use crate::math::{Point, Vector};
+use crate::query::nonlinear_time_of_impact::{local_radius, max_relative_speed};
+use crate::query::{DefaultQueryDispatcher, QueryDispatcher, RigidMotion, Unsupported};
+use crate::shape::Shape;
+use crate::utils::{Interval, WeightedValue};
+use ad_trait::AD;
+
+/// The smallest distance between two shapes moving along rigid motions during a time interval.
+#[derive(Copy, Clone, Debug)]
+pub struct MinDistanceOverMotion<T: AD> {
+    /// The time at which the distance is the smallest.
+    pub time: T,
+    /// The signed distance between the two shapes at `time`, as given by
+    /// [`QueryDispatcher::contact`].
+    ///
+    /// This is negative if the shapes are penetrating at `time`.
+    pub distance: T,
+    /// The local-space closest point on the first shape at `time`.
+    pub witness1: Point<T>,
+    /// The local-space closest point on the second shape at `time`.
+    pub witness2: Point<T>,
+    /// The local-space outward normal on the first shape at `time`.
+    pub normal1: Unit<Vector<T>>,
+    /// The local-space outward normal on the second shape at `time`.
+    pub normal2: Unit<Vector<T>>,
+}
+
+/// Computes the smallest distance between two shapes moving along rigid motions during the
+/// time interval `[start_time, end_time]`.
+///
+/// The result is within `tolerance` of the actual smallest distance. The search only relies on
+/// the (unsigned) distance between the shapes: if they penetrate at some time, the returned
+/// time is one where they penetrate, not necessarily the time of deepest penetration. The
+/// reported distance is then the opposite of the penetration depth at that time.
+///
+/// Returns `Ok(None)` if no closest points exist between the shapes, e.g. if one of them is
+/// an empty composite shape.
+///
+/// The distance and witness points of the result are evaluated at the returned time, so their
+/// derivatives (if `T` is a dual number) are the derivatives of the smallest distance with
+/// respect to the parameters of the motions and shapes.
+///
+/// # Parameters
+/// * `motion1` - The motion of the first shape.
+/// * `g1` - The first shape involved in the query.
+/// * `motion2` - The motion of the second shape.
+/// * `g2` - The second shape involved in the query.
+/// * `start_time` - The starting time of the interval where the motion takes place.
+/// * `end_time` - The end time of the interval where the motion takes place.
+/// * `tolerance` - The maximum error allowed on the computed smallest distance. It must be
+///   positive.
+///
+/// # Panics
+/// Panics if `tolerance` isn’t positive.
+pub fn min_distance_over_motion<T: AD>(
+    motion1: &dyn RigidMotion<T>,
+    g1: &dyn Shape<T>,
+    motion2: &dyn RigidMotion<T>,
+    g2: &dyn Shape<T>,
+    start_time: T,
+    end_time: T,
+    tolerance: T,
+) -> Result<Option<MinDistanceOverMotion<T>>, Unsupported> {
+    min_distance_over_motion_with_bounds(
+        &DefaultQueryDispatcher,
+        motion1,
+        g1,
+        motion2,
+        g2,
+        start_time,
+        end_time,
+        tolerance,
+        None,
+    )
+}
+
+/// Computes the smallest distance between two shapes moving along rigid motions, using
+/// user-provided bounds on the distance.
+///
+/// The time interval is bisected in a best-first order. The distance on each sub-interval
+/// `[a, b]` is bounded from below using the speed bounds of the motions, like with
+/// conservative advancement. If `distance_bounds` is provided, it must return an enclosure
+/// (e.g. computed with interval arithmetic) of the distance between the shapes during a
+/// given time interval; its lower bound is used to discard sub-intervals earlier.
+///
+/// See [`min_distance_over_motion`] for details about the other parameters.
+///
+/// # Panics
+/// Panics if `tolerance` isn’t positive.
+pub fn min_distance_over_motion_with_bounds<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    motion1: &dyn RigidMotion<T>,
+    g1: &dyn Shape<T>,
+    motion2: &dyn RigidMotion<T>,
+    g2: &dyn Shape<T>,
+    start_time: T,
+    end_time: T,
+    tolerance: T,
+    distance_bounds: Option<&dyn Fn(Interval<T>) -> Interval<T>>,
+) -> Result<Option<MinDistanceOverMotion<T>>, Unsupported>
+where
+    D: QueryDispatcher<T>,
+{
+    assert!(
+        tolerance > T::zero(),
+        "The tolerance must be positive for the bisection to terminate."
+    );
+
+    let rad1 = local_radius(g1);
+    let rad2 = local_radius(g2);
+
+    let distance_at = |t: T| {
+        let pos12 = motion1
+            .position_at_time(t)
+            .inv_mul(&motion2.position_at_time(t));
+        dispatcher.distance(&pos12, g1, g2)
+    };
+
+    // A lower bound of the distance on `[a, b]` given the distances `da` and `db` at its ends.
+    let lower_bound = |a: T, da: T, b: T, db: T| {
+        let max_speed = max_relative_speed(motion1, rad1, motion2, rad2, a, b);
+        let lb = (da + db - max_speed * (b - a)) / T::constant(2.0);
+
+        if let Some(distance_bounds) = distance_bounds {
+            lb.max(distance_bounds(Interval(a, b)).0)
+        } else {
+            lb
+        }
+    };
+
+    let d_start = distance_at(start_time)?;
+    let d_end = distance_at(end_time)?;
+    let (mut best_time, mut best_dist) = if d_end < d_start {
+        (end_time, d_end)
+    } else {
+        (start_time, d_start)
+    };
+
+    let mut queue = BinaryHeap::new();
+    let lb = lower_bound(start_time, d_start, end_time, d_end);
+    queue.push(WeightedValue::new(
+        (start_time, d_start, end_time, d_end),
+        -lb,
+    ));
+
+    while let Some(candidate) = queue.pop() {
+        if -candidate.cost >= best_dist - tolerance || best_dist <= T::zero() {
+            // No remaining sub-interval can improve the result significantly.
+            break;
+        }
+
+        let (a, da, b, db) = candidate.value;
+        let mid = (a + b) / T::constant(2.0);
+
+        if mid <= a || mid >= b {
+            // We reached the numerical resolution of the time.
+            continue;
+        }
+
+        let dmid = distance_at(mid)?;
+
+        if dmid < best_dist {
+            best_time = mid;
+            best_dist = dmid;
+        }
+
+        for (a, da, b, db) in [(a, da, mid, dmid), (mid, dmid, b, db)] {
+            let lb = lower_bound(a, da, b, db);
+
+            if lb < best_dist - tolerance {
+                queue.push(WeightedValue::new((a, da, b, db), -lb));
+            }
+        }
+    }
+
+    let pos12 = motion1
+        .position_at_time(best_time)
+        .inv_mul(&motion2.position_at_time(best_time));
+    let contact = dispatcher.contact(&pos12, g1, g2, T::constant(f64::MAX))?;
+
+    Ok(contact.map(|contact| MinDistanceOverMotion {
+        time: best_time,
+        distance: contact.dist,
+        witness1: contact.point1,
+        witness2: contact.point2,
+        normal1: contact.normal1,
+        normal2: contact.normal2,
+    }))
+}
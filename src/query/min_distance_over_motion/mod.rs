@@ -0,0 +1,7 @@
+//! Implementation details of the `min_distance_over_motion` function.
+
+pub use self::min_distance_over_motion::{
+    min_distance_over_motion, min_distance_over_motion_with_bounds, MinDistanceOverMotion,
+};
+
+mod min_distance_over_motion;
@@ -4,11 +4,19 @@
 //! The most general methods provided by this module are:
 //!
 //! * [`query::closest_points()`] to compute the closest points between two shapes.
+//! * [`query::closest_points_with_features()`] to compute the closest points between two shapes, and the features they lie on.
 //! * [`query::distance()`] to compute the distance between two shapes.
+//! * [`query::distance_with_max()`] to compute the distance between two shapes, unless they are farther apart than a given bound.
 //! * [`query::contact()`] to compute one pair of contact points between two shapes, including penetrating contact.
+//! * [`query::contact_with_features()`] to compute one pair of contact points between two shapes, and the features they lie on.
+//! * [`query::contact_manifolds()`] to compute all the contact manifolds between two shapes, including composite shapes.
 //! * [`query::intersection_test()`] to determine if two shapes are intersecting or not.
+//! * [`query::intersection_test_with_witness()`] to determine if two shapes are intersecting or not, with a separating axis or penetration witness.
 //! * [`query::time_of_impact()`] to determine when two shapes undergoing translational motions hit for the first time.
 //! * [`query::nonlinear_time_of_impact()`] to determine when two shapes undergoing continuous rigid motions hit for the first time.
+//! * [`query::nonlinear_collision_intervals()`] to determine all the time intervals during which two shapes undergoing continuous rigid motions overlap.
+//! * [`query::min_distance_over_motion()`] to determine how close two shapes undergoing continuous rigid motions get to each other.
+//! * [`query::deformable_ccd`] to determine when primitives with vertices moving linearly (e.g. cloth triangles) hit for the first time.
 //!
 //! Ray-casting and point-projection can be achieved by importing traits:
 //!
@@ -25,22 +33,44 @@
 //! * `[shape1]` is the type of the first shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 //! * `[shape2]` is the type of the second shape passed to the function, e.g., `ball`, or `halfspace`. Can also identify a trait implemented by supported shapes, e.g., `support_map`.
 
-pub use self::closest_points::{closest_points, ClosestPoints};
-pub use self::contact::{contact, Contact};
+pub use self::closest_points::{
+    closest_points, closest_points_with_features, ClosestFeatures, ClosestPoints,
+};
+pub use self::contact::{contact, contact_with_features, Contact};
 #[cfg(feature = "std")]
 pub use self::contact_manifolds::{
-    ContactManifold, ContactManifoldsWorkspace, TrackedContact, TypedWorkspaceData, WorkspaceData,
+    contact_manifolds, ContactManifold, ContactManifoldsWorkspace, TrackedContact,
+    TypedWorkspaceData, WorkspaceData,
 };
 pub use self::default_query_dispatcher::DefaultQueryDispatcher;
-pub use self::distance::distance;
+pub use self::distance::{distance, distance_with_max};
+pub use self::gjk::DistanceCache;
 pub use self::error::Unsupported;
 pub use self::intersection_test::intersection_test;
-pub use self::nonlinear_time_of_impact::{nonlinear_time_of_impact, NonlinearRigidMotion};
+#[cfg(feature = "std")]
+pub use self::intersection_test::{intersection_test_with_witness, IntersectionWitness};
+#[cfg(feature = "std")]
+pub use self::min_distance_over_motion::{min_distance_over_motion, MinDistanceOverMotion};
+pub use self::nonlinear_time_of_impact::{
+    nonlinear_time_of_impact, FnRigidMotion, NonlinearRigidMotion, PrependedRigidMotion,
+    RigidMotion,
+};
+#[cfg(feature = "std")]
+pub use self::nonlinear_time_of_impact::{
+    nonlinear_collision_intervals, BSplineMotion, CollisionInterval, HermiteKeyframe,
+    HermiteMotion, KeyframeMotion,
+};
 pub use self::point::{PointProjection, PointQuery, PointQueryWithLocation};
 #[cfg(feature = "std")]
 pub use self::query_dispatcher::PersistentQueryDispatcher;
 pub use self::query_dispatcher::{QueryDispatcher, QueryDispatcherChain};
-pub use self::ray::{Ray, RayCast, RayIntersection, SimdRay};
+pub use self::ray::{Ray, RayCast, RayHit, RayIntersection, SimdRay};
+#[cfg(feature = "std")]
+pub use self::ray::{cast_ray_batch, cast_ray_batch_with_qbvh};
+#[cfg(all(feature = "std", feature = "parallel"))]
+pub use self::ray::{par_cast_ray_batch, par_cast_ray_batch_with_qbvh};
+#[cfg(all(feature = "std", feature = "dim3"))]
+pub use self::ray::RaySensor;
 pub use self::split::{IntersectResult, SplitResult};
 pub use self::time_of_impact::{time_of_impact, TOIStatus, TOI};
 
@@ -50,12 +80,16 @@ pub mod contact;
 #[cfg(feature = "std")]
 mod contact_manifolds;
 mod default_query_dispatcher;
+#[cfg(all(feature = "std", feature = "dim3"))]
+pub mod deformable_ccd;
 mod distance;
 #[cfg(feature = "std")]
 pub mod epa;
 mod error;
 pub mod gjk;
 mod intersection_test;
+#[cfg(feature = "std")]
+mod min_distance_over_motion;
 mod nonlinear_time_of_impact;
 pub mod point;
 mod query_dispatcher;
@@ -74,6 +108,8 @@ pub mod details {
     pub use super::contact_manifolds::*;
     pub use super::distance::*;
     pub use super::intersection_test::*;
+    #[cfg(feature = "std")]
+    pub use super::min_distance_over_motion::*;
     pub use super::nonlinear_time_of_impact::*;
     pub use super::point::*;
     pub use super::ray::*;
@@ -0,0 +1,156 @@
+use super::pose_coordinates::{ang_norm, local_point_velocity, rotation_vector};
+use crate::math::{AngVector, Isometry, Point, Vector};
+use crate::query::RigidMotion;
+use ad_trait::AD;
+
+/// A rigid motion following a uniform cubic B-spline of poses.
+///
+/// Each control pose is converted to its translation and its rotation vector (the scaled
+/// axis of the rotation in 3D, or its angle in 2D), and these coordinates are interpolated
+/// by a C² uniform cubic B-spline. Consecutive control poses must have close rotation
+/// vectors (i.e. no wrap-around at `±π`) for the rotation to be interpolated smoothly.
+///
+/// The motion starts at `start_time` and each of its segments lasts `knot_interval`, so it
+/// ends at `start_time + (n - 3) * knot_interval` where `n` is the number of control poses.
+#[derive(Clone, Debug)]
+pub struct BSplineMotion<T: AD> {
+    start_time: T,
+    knot_interval: T,
+    translations: Vec<Vector<T>>,
+    rotations: Vec<AngVector<T>>,
+}
+
+impl<T: AD> BSplineMotion<T> {
+    /// Creates a B-spline motion from its control poses.
+    ///
+    /// # Panics
+    /// Panics if there are less than four control poses, or if `knot_interval` isn’t positive.
+    pub fn new(start_time: T, knot_interval: T, control_poses: &[Isometry<T>]) -> Self {
+        assert!(
+            control_poses.len() >= 4,
+            "A cubic B-spline motion needs at least four control poses."
+        );
+        assert!(knot_interval > T::zero());
+
+        Self {
+            start_time,
+            knot_interval,
+            translations: control_poses.iter().map(|p| p.translation.vector).collect(),
+            rotations: control_poses.iter().map(rotation_vector).collect(),
+        }
+    }
+
+    /// The time at which this motion starts.
+    pub fn start_time(&self) -> T {
+        self.start_time
+    }
+
+    /// The time at which this motion ends.
+    pub fn end_time(&self) -> T {
+        self.start_time + self.knot_interval * T::constant(self.num_segments() as f64)
+    }
+
+    fn num_segments(&self) -> usize {
+        self.translations.len() - 3
+    }
+
+    /// The index of the segment containing the time `t`, and the local parameter in `[0, 1]` of `t` on it.
+    fn segment_at(&self, t: T) -> (usize, T) {
+        let s = ((t - self.start_time) / self.knot_interval)
+            .max(T::zero())
+            .min(T::constant(self.num_segments() as f64));
+        let i = (s.floor().to_constant() as usize).min(self.num_segments() - 1);
+        (i, s - T::constant(i as f64))
+    }
+
+    /// Combines the control poses of the `i`-th segment with the given weights.
+    fn interpolate(&self, i: usize, weights: [T; 4]) -> (Vector<T>, AngVector<T>) {
+        let mut translation = Vector::zeros();
+        let mut rotation = self.rotations[i] * T::zero();
+
+        for (k, w) in weights.iter().enumerate() {
+            translation += self.translations[i + k] * *w;
+            rotation += self.rotations[i + k] * *w;
+        }
+
+        (translation, rotation)
+    }
+}
+
+/// The uniform cubic B-spline basis functions at the local parameter `u`.
+fn basis<T: AD>(u: T) -> [T; 4] {
+    let _1 = T::one();
+    let _3 = T::constant(3.0);
+    let _6 = T::constant(6.0);
+    let u2 = u * u;
+    let u3 = u2 * u;
+    let v = _1 - u;
+
+    [
+        v * v * v / _6,
+        (_3 * u3 - _6 * u2 + T::constant(4.0)) / _6,
+        (-_3 * u3 + _3 * u2 + _3 * u + _1) / _6,
+        u3 / _6,
+    ]
+}
+
+/// The derivatives of the uniform cubic B-spline basis functions at the local parameter `u`.
+fn basis_derivatives<T: AD>(u: T) -> [T; 4] {
+    let _2 = T::constant(2.0);
+    let _3 = T::constant(3.0);
+    let u2 = u * u;
+    let v = T::one() - u;
+
+    [
+        -v * v / _2,
+        (_3 * u2 - T::constant(4.0) * u) / _2,
+        (-_3 * u2 + _2 * u + T::one()) / _2,
+        u2 / _2,
+    ]
+}
+
+impl<T: AD> RigidMotion<T> for BSplineMotion<T> {
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        let (i, u) = self.segment_at(t);
+        let (translation, rotation) = self.interpolate(i, basis(u));
+        Isometry::new(translation, rotation)
+    }
+
+    fn local_point_velocity(&self, t: T, local_pt: &Point<T>) -> Vector<T> {
+        if t < self.start_time || t > self.end_time() {
+            // The pose is constant outside of the spline.
+            return Vector::zeros();
+        }
+
+        let (i, u) = self.segment_at(t);
+        let (translation, rotation) = self.interpolate(i, basis(u));
+        let (dtra, drot) = self.interpolate(i, basis_derivatives(u));
+        let inv_knot_interval = T::one() / self.knot_interval;
+        local_point_velocity(
+            &Isometry::new(translation, rotation),
+            rotation,
+            dtra * inv_knot_interval,
+            drot * inv_knot_interval,
+            local_pt,
+        )
+    }
+
+    fn velocity_bounds(&self, t0: T, t1: T) -> (T, T) {
+        // The derivative of a cubic B-spline is a quadratic B-spline with the control points
+        // `(P[j + 1] - P[j]) / knot_interval`, so it lies in the convex hull of those.
+        // The angular velocity is bounded by the derivative of the rotation vector because
+        // the Jacobian of the exponential map has a norm smaller than 1.
+        let (first, _) = self.segment_at(t0);
+        let (last, _) = self.segment_at(t1);
+        let mut bounds = (T::zero(), T::zero());
+
+        for j in first..last + 3 {
+            let dtra = self.translations[j + 1] - self.translations[j];
+            let drot = self.rotations[j + 1] - self.rotations[j];
+            bounds.0 = bounds.0.max(dtra.norm() / self.knot_interval);
+            bounds.1 = bounds.1.max(ang_norm(drot) / self.knot_interval);
+        }
+
+        bounds
+    }
+}
@@ -0,0 +1,209 @@
+use super::pose_coordinates::{ang_norm, local_point_velocity, rotation_vector};
+use crate::math::{AngVector, Isometry, Point, Vector};
+use crate::query::RigidMotion;
+use ad_trait::AD;
+
+/// A keyframe of a [`HermiteMotion`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HermiteKeyframe<T: AD> {
+    /// The time of this keyframe.
+    pub time: T,
+    /// The pose at this keyframe.
+    pub pose: Isometry<T>,
+    /// The derivative of the translation at this keyframe.
+    pub linvel: Vector<T>,
+    /// The derivative of the rotation vector at this keyframe.
+    ///
+    /// This is the angular velocity if the rotation of `pose` is the identity, or if the
+    /// rotation axis doesn’t change.
+    pub rotvel: AngVector<T>,
+}
+
+impl<T: AD> HermiteKeyframe<T> {
+    /// Creates a keyframe with the given pose and derivatives.
+    pub fn new(time: T, pose: Isometry<T>, linvel: Vector<T>, rotvel: AngVector<T>) -> Self {
+        Self {
+            time,
+            pose,
+            linvel,
+            rotvel,
+        }
+    }
+}
+
+/// A rigid motion following a cubic Hermite spline of poses.
+///
+/// Each keyframe pose is converted to its translation and its rotation vector (the scaled
+/// axis of the rotation in 3D, or its angle in 2D), and these coordinates are interpolated
+/// by cubic polynomials matching the poses and their derivatives at the keyframes.
+/// Consecutive keyframes must have close rotation vectors (i.e. no wrap-around at `±π`) for
+/// the rotation to be interpolated smoothly. Before the first keyframe (resp. after the
+/// last), the pose stays at the first (resp. last) keyframe.
+#[derive(Clone, Debug)]
+pub struct HermiteMotion<T: AD> {
+    keyframes: Vec<HermiteKeyframe<T>>,
+    rotations: Vec<AngVector<T>>,
+}
+
+impl<T: AD> HermiteMotion<T> {
+    /// Creates a motion interpolating the given keyframes.
+    ///
+    /// # Panics
+    /// Panics if `keyframes` is empty or if its times aren’t sorted in strictly increasing order.
+    pub fn new(keyframes: Vec<HermiteKeyframe<T>>) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "A Hermite motion needs at least one keyframe."
+        );
+        assert!(
+            keyframes.windows(2).all(|w| w[0].time < w[1].time),
+            "The keyframe times must be strictly increasing."
+        );
+        let rotations = keyframes.iter().map(|k| rotation_vector(&k.pose)).collect();
+        Self {
+            keyframes,
+            rotations,
+        }
+    }
+
+    /// The keyframes of this motion.
+    pub fn keyframes(&self) -> &[HermiteKeyframe<T>] {
+        &self.keyframes
+    }
+
+    /// The index of the segment containing the time `t`, and the local parameter in `[0, 1]` of
+    /// `t` on it.
+    ///
+    /// Returns `None` if `t` is outside of the keyframe times, or if there is only one keyframe.
+    fn segment_at(&self, t: T) -> Option<(usize, T)> {
+        let first = self.keyframes[0].time;
+        let last = self.keyframes[self.keyframes.len() - 1].time;
+
+        if t < first || t > last || self.keyframes.len() == 1 {
+            return None;
+        }
+
+        // The index of the first keyframe strictly after `t`, and not after the last one.
+        let i = self
+            .keyframes
+            .partition_point(|k| k.time <= t)
+            .min(self.keyframes.len() - 1);
+        let (ta, tb) = (self.keyframes[i - 1].time, self.keyframes[i].time);
+        Some((i - 1, (t - ta) / (tb - ta)))
+    }
+
+    /// Combines the poses and derivatives of the `i`-th segment with the given weights.
+    ///
+    /// The weights apply, in order, to the first pose, the first derivative, the second pose,
+    /// and the second derivative.
+    fn interpolate(&self, i: usize, weights: [T; 4]) -> (Vector<T>, AngVector<T>) {
+        let (ka, kb) = (&self.keyframes[i], &self.keyframes[i + 1]);
+        let dt = kb.time - ka.time;
+        let translation = ka.pose.translation.vector * weights[0]
+            + ka.linvel * (weights[1] * dt)
+            + kb.pose.translation.vector * weights[2]
+            + kb.linvel * (weights[3] * dt);
+        let rotation = self.rotations[i] * weights[0]
+            + ka.rotvel * (weights[1] * dt)
+            + self.rotations[i + 1] * weights[2]
+            + kb.rotvel * (weights[3] * dt);
+        (translation, rotation)
+    }
+}
+
+/// The cubic Hermite basis functions at the local parameter `u`.
+fn basis<T: AD>(u: T) -> [T; 4] {
+    let _2 = T::constant(2.0);
+    let _3 = T::constant(3.0);
+    let u2 = u * u;
+    let u3 = u2 * u;
+
+    [
+        _2 * u3 - _3 * u2 + T::one(),
+        u3 - _2 * u2 + u,
+        -_2 * u3 + _3 * u2,
+        u3 - u2,
+    ]
+}
+
+/// The derivatives of the cubic Hermite basis functions at the local parameter `u`.
+fn basis_derivatives<T: AD>(u: T) -> [T; 4] {
+    let _2 = T::constant(2.0);
+    let _3 = T::constant(3.0);
+    let _6 = T::constant(6.0);
+    let u2 = u * u;
+
+    [
+        _6 * u2 - _6 * u,
+        _3 * u2 - T::constant(4.0) * u + T::one(),
+        -_6 * u2 + _6 * u,
+        _3 * u2 - _2 * u,
+    ]
+}
+
+impl<T: AD> RigidMotion<T> for HermiteMotion<T> {
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        match self.segment_at(t) {
+            Some((i, u)) => {
+                let (translation, rotation) = self.interpolate(i, basis(u));
+                Isometry::new(translation, rotation)
+            }
+            None if t < self.keyframes[0].time => self.keyframes[0].pose,
+            None => self.keyframes[self.keyframes.len() - 1].pose,
+        }
+    }
+
+    fn local_point_velocity(&self, t: T, local_pt: &Point<T>) -> Vector<T> {
+        let (i, u) = match self.segment_at(t) {
+            Some(segment) => segment,
+            // The pose is constant outside of the keyframe times.
+            None => return Vector::zeros(),
+        };
+
+        let (translation, rotation) = self.interpolate(i, basis(u));
+        let (dtra, drot) = self.interpolate(i, basis_derivatives(u));
+        let inv_dt = T::one() / (self.keyframes[i + 1].time - self.keyframes[i].time);
+        local_point_velocity(
+            &Isometry::new(translation, rotation),
+            rotation,
+            dtra * inv_dt,
+            drot * inv_dt,
+            local_pt,
+        )
+    }
+
+    fn velocity_bounds(&self, t0: T, t1: T) -> (T, T) {
+        // Each segment is a cubic Bézier curve with the control points `P0`,
+        // `P0 + V0 * dt / 3`, `P1 - V1 * dt / 3`, and `P1`. Its derivative is a quadratic
+        // Bézier curve with the control points `V0`, `3 * (P1 - P0) / dt - V0 - V1`, and `V1`,
+        // so it lies in the convex hull of those.
+        // The angular velocity is bounded by the derivative of the rotation vector because
+        // the Jacobian of the exponential map has a norm smaller than 1.
+        let mut bounds = (T::zero(), T::zero());
+
+        for i in 0..self.keyframes.len() - 1 {
+            let (ka, kb) = (&self.keyframes[i], &self.keyframes[i + 1]);
+
+            // Skip the segments that don’t overlap `[t0, t1]`.
+            if kb.time < t0 || ka.time > t1 {
+                continue;
+            }
+
+            let _3_dt = T::constant(3.0) / (kb.time - ka.time);
+            let mid_linvel = (kb.pose.translation.vector - ka.pose.translation.vector) * _3_dt
+                - ka.linvel
+                - kb.linvel;
+            let mid_rotvel =
+                (self.rotations[i + 1] - self.rotations[i]) * _3_dt - ka.rotvel - kb.rotvel;
+
+            for linvel in [ka.linvel, mid_linvel, kb.linvel] {
+                bounds.0 = bounds.0.max(linvel.norm());
+            }
+            for rotvel in [ka.rotvel, mid_rotvel, kb.rotvel] {
+                bounds.1 = bounds.1.max(ang_norm(rotvel));
+            }
+        }
+
+        bounds
+    }
+}
@@ -0,0 +1,84 @@
+use crate::math::Isometry;
+use crate::query::RigidMotion;
+use ad_trait::AD;
+
+/// A piecewise-linear rigid motion interpolating between poses at given times.
+///
+/// Between two consecutive keyframes, the translation is interpolated linearly and the
+/// rotation is interpolated spherically, at constant speed. Before the first keyframe
+/// (resp. after the last), the pose stays at the first (resp. last) keyframe.
+#[derive(Clone, Debug)]
+pub struct KeyframeMotion<T: AD> {
+    keyframes: Vec<(T, Isometry<T>)>,
+}
+
+impl<T: AD> KeyframeMotion<T> {
+    /// Creates a motion interpolating the given `(time, pose)` keyframes.
+    ///
+    /// # Panics
+    /// Panics if `keyframes` is empty or if its times aren’t sorted in strictly increasing order.
+    pub fn new(keyframes: Vec<(T, Isometry<T>)>) -> Self {
+        assert!(
+            !keyframes.is_empty(),
+            "A keyframe motion needs at least one keyframe."
+        );
+        assert!(
+            keyframes.windows(2).all(|w| w[0].0 < w[1].0),
+            "The keyframe times must be strictly increasing."
+        );
+        Self { keyframes }
+    }
+
+    /// The `(time, pose)` keyframes of this motion.
+    pub fn keyframes(&self) -> &[(T, Isometry<T>)] {
+        &self.keyframes
+    }
+
+    /// The linear and angular speeds of this motion between the `i`-th and `i + 1`-th keyframes.
+    fn segment_speeds(&self, i: usize) -> (T, T) {
+        let (ta, pa) = &self.keyframes[i];
+        let (tb, pb) = &self.keyframes[i + 1];
+        let dt = *tb - *ta;
+        let linvel = (pb.translation.vector - pa.translation.vector).norm() / dt;
+        // NOTE: in 2D, the angle between two rotations is signed.
+        let angvel = pa.rotation.angle_to(&pb.rotation).abs() / dt;
+        (linvel, angvel)
+    }
+}
+
+impl<T: AD> RigidMotion<T> for KeyframeMotion<T> {
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        let first = &self.keyframes[0];
+        let last = &self.keyframes[self.keyframes.len() - 1];
+
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        // The index of the first keyframe strictly after `t`.
+        let i = self.keyframes.partition_point(|(ti, _)| *ti <= t);
+        let (ta, pa) = &self.keyframes[i - 1];
+        let (tb, pb) = &self.keyframes[i];
+        pa.lerp_slerp(pb, (t - *ta) / (*tb - *ta))
+    }
+
+    fn velocity_bounds(&self, t0: T, t1: T) -> (T, T) {
+        let mut bounds = (T::zero(), T::zero());
+
+        for i in 0..self.keyframes.len().saturating_sub(1) {
+            // Skip the segments that don’t overlap `[t0, t1]`.
+            if self.keyframes[i + 1].0 < t0 || self.keyframes[i].0 > t1 {
+                continue;
+            }
+
+            let (linvel, angvel) = self.segment_speeds(i);
+            bounds.0 = bounds.0.max(linvel);
+            bounds.1 = bounds.1.max(angvel);
+        }
+
+        bounds
+    }
+}
@@ -6,15 +6,40 @@ pub use self::nonlinear_time_of_impact_composite_shape_shape::{
     NonlinearTOICompositeShapeShapeBestFirstVisitor,
 };
 //pub use self::nonlinear_time_of_impact_halfspace_support_map::{nonlinear_time_of_impact_halfspace_support_map, nonlinear_time_of_impact_support_map_halfspace};
+#[cfg(feature = "std")]
+pub use self::bspline_motion::BSplineMotion;
+#[cfg(feature = "std")]
+pub use self::hermite_motion::{HermiteKeyframe, HermiteMotion};
+#[cfg(feature = "std")]
+pub use self::keyframe_motion::KeyframeMotion;
+#[cfg(feature = "std")]
+pub use self::nonlinear_collision_intervals::{
+    nonlinear_collision_intervals, nonlinear_collision_intervals_with_dispatcher,
+    CollisionInterval,
+};
 pub use self::nonlinear_rigid_motion::NonlinearRigidMotion;
 pub use self::nonlinear_time_of_impact::nonlinear_time_of_impact;
 pub use self::nonlinear_time_of_impact_support_map_support_map::{
     nonlinear_time_of_impact_support_map_support_map, NonlinearTOIMode,
 };
+#[cfg(feature = "std")]
+pub(crate) use self::rigid_motion::{local_radius, max_relative_speed};
+pub use self::rigid_motion::{FnRigidMotion, PrependedRigidMotion, RigidMotion};
 
 #[cfg(feature = "std")]
 mod nonlinear_time_of_impact_composite_shape_shape;
 //mod nonlinear_time_of_impact_halfspace_support_map;
+#[cfg(feature = "std")]
+mod bspline_motion;
+#[cfg(feature = "std")]
+mod hermite_motion;
+#[cfg(feature = "std")]
+mod keyframe_motion;
+#[cfg(feature = "std")]
+mod nonlinear_collision_intervals;
 mod nonlinear_rigid_motion;
 mod nonlinear_time_of_impact;
 mod nonlinear_time_of_impact_support_map_support_map;
+#[cfg(feature = "std")]
+mod pose_coordinates;
+mod rigid_motion;
@@ -0,0 +1,199 @@
+use na::Unit;
+
+use super::{local_radius, max_relative_speed};
+use crate::math::Vector;
+use crate::query::{Contact, DefaultQueryDispatcher, QueryDispatcher, RigidMotion, Unsupported};
+use crate::shape::Shape;
+use ad_trait::AD;
+
+/// A time interval during which two moving shapes overlap.
+#[derive(Copy, Clone, Debug)]
+pub struct CollisionInterval<T: AD> {
+    /// The time at which the shapes start overlapping.
+    pub enter_time: T,
+    /// The time at which the shapes stop overlapping.
+    pub exit_time: T,
+    /// The local-space contact normal on the first shape at `enter_time`.
+    pub enter_normal1: Unit<Vector<T>>,
+    /// The local-space contact normal on the first shape at `exit_time`.
+    pub exit_normal1: Unit<Vector<T>>,
+}
+
+/// Computes all the time intervals during which two shapes undergoing rigid motions overlap.
+///
+/// The intervals are sorted by increasing times. If the shapes already overlap at `start_time`
+/// (resp. still overlap at `end_time`), the first (resp. last) interval starts at `start_time`
+/// (resp. ends at `end_time`).
+///
+/// The overlap status is given by the sign of the distance reported by
+/// [`QueryDispatcher::contact`]. For composite shapes, this is the signed distance between
+/// their closest (or deepest penetrating) sub-shapes, which doesn’t detect a shape lying
+/// entirely inside of a triangle mesh without volume. Set the `TriMeshFlags::SOLID` flag on
+/// such meshes to detect these overlaps.
+///
+/// # Parameters
+/// * `motion1` - The motion of the first shape.
+/// * `g1` - The first shape involved in the query.
+/// * `motion2` - The motion of the second shape.
+/// * `g2` - The second shape involved in the query.
+/// * `start_time` - The starting time of the interval where the motion takes place.
+/// * `end_time` - The end time of the interval where the motion takes place.
+/// * `tolerance` - The distance tolerance: overlaps (resp. separations) shallower than this
+///   distance may be missed, and the boundary times are accurate up to the time needed to
+///   travel this distance. It must be positive.
+///
+/// # Panics
+/// Panics if `tolerance` isn’t positive.
+pub fn nonlinear_collision_intervals<T: AD>(
+    motion1: &dyn RigidMotion<T>,
+    g1: &dyn Shape<T>,
+    motion2: &dyn RigidMotion<T>,
+    g2: &dyn Shape<T>,
+    start_time: T,
+    end_time: T,
+    tolerance: T,
+) -> Result<Vec<CollisionInterval<T>>, Unsupported> {
+    nonlinear_collision_intervals_with_dispatcher(
+        &DefaultQueryDispatcher,
+        motion1,
+        g1,
+        motion2,
+        g2,
+        start_time,
+        end_time,
+        tolerance,
+    )
+}
+
+/// Computes all the time intervals during which two shapes undergoing rigid motions overlap,
+/// using the given query dispatcher.
+///
+/// The motion is sampled by conservative advancement on the signed distance between the
+/// shapes, which cannot change faster than the speed bounds of the motions. Each change of
+/// sign of the distance is then located by bisection.
+///
+/// See [`nonlinear_collision_intervals`] for details about the parameters.
+pub fn nonlinear_collision_intervals_with_dispatcher<D: ?Sized, T: AD>(
+    dispatcher: &D,
+    motion1: &dyn RigidMotion<T>,
+    g1: &dyn Shape<T>,
+    motion2: &dyn RigidMotion<T>,
+    g2: &dyn Shape<T>,
+    start_time: T,
+    end_time: T,
+    tolerance: T,
+) -> Result<Vec<CollisionInterval<T>>, Unsupported>
+where
+    D: QueryDispatcher<T>,
+{
+    assert!(
+        tolerance > T::zero(),
+        "The tolerance must be positive for the advancement to terminate."
+    );
+
+    let rad1 = local_radius(g1);
+    let rad2 = local_radius(g2);
+
+    let contact_at = |t: T| -> Result<Contact<T>, Unsupported> {
+        let pos12 = motion1
+            .position_at_time(t)
+            .inv_mul(&motion2.position_at_time(t));
+        dispatcher
+            .contact(&pos12, g1, g2, T::constant(f64::MAX))?
+            .ok_or(Unsupported)
+    };
+
+    let max_speed = |t0: T, t1: T| max_relative_speed(motion1, rad1, motion2, rad2, t0, t1);
+
+    // Finds the time, up to `tolerance / speed`, where the overlap status changes in `[lo, hi]`.
+    // Returns the contact at the bound of the final range where the shapes don’t overlap.
+    let locate_boundary = |mut lo: T,
+                           mut contact_lo: Contact<T>,
+                           mut hi: T,
+                           mut contact_hi: Contact<T>|
+     -> Result<(T, Contact<T>), Unsupported> {
+        let entering = contact_lo.dist > T::zero();
+        let speed = max_speed(lo, hi);
+
+        while (hi - lo) * speed > tolerance {
+            let mid = (lo + hi) / T::constant(2.0);
+
+            if mid <= lo || mid >= hi {
+                break;
+            }
+
+            let contact_mid = contact_at(mid)?;
+
+            if (contact_mid.dist > T::zero()) == entering {
+                lo = mid;
+                contact_lo = contact_mid;
+            } else {
+                hi = mid;
+                contact_hi = contact_mid;
+            }
+        }
+
+        Ok(if entering {
+            (lo, contact_lo)
+        } else {
+            (hi, contact_hi)
+        })
+    };
+
+    let mut result = vec![];
+    let mut curr_time = start_time;
+    let mut curr_contact = contact_at(start_time)?;
+    let mut enter = if curr_contact.dist <= T::zero() {
+        Some((start_time, curr_contact.normal1))
+    } else {
+        None
+    };
+
+    while curr_time < end_time {
+        let speed = max_speed(curr_time, end_time);
+        let next_time = if speed > T::zero() {
+            // Always advance by a few ulps at least, so the time actually increases even if the
+            // step is rounded off.
+            let min_step = T::default_epsilon() * T::constant(4.0) * curr_time.abs().max(T::one());
+            let step = (curr_contact.dist.abs().max(tolerance) / speed).max(min_step);
+            (curr_time + step).min(end_time)
+        } else {
+            // Nothing moves so the overlap status cannot change.
+            end_time
+        };
+
+        let next_contact = contact_at(next_time)?;
+        let next_overlap = next_contact.dist <= T::zero();
+
+        if next_overlap != enter.is_some() {
+            let (boundary_time, boundary_contact) =
+                locate_boundary(curr_time, curr_contact, next_time, next_contact)?;
+
+            if let Some((enter_time, enter_normal1)) = enter {
+                result.push(CollisionInterval {
+                    enter_time,
+                    exit_time: boundary_time,
+                    enter_normal1,
+                    exit_normal1: boundary_contact.normal1,
+                });
+                enter = None;
+            } else {
+                enter = Some((boundary_time, boundary_contact.normal1));
+            }
+        }
+
+        curr_time = next_time;
+        curr_contact = next_contact;
+    }
+
+    if let Some((enter_time, enter_normal1)) = enter {
+        result.push(CollisionInterval {
+            enter_time,
+            exit_time: end_time,
+            enter_normal1,
+            exit_normal1: curr_contact.normal1,
+        });
+    }
+
+    Ok(result)
+}
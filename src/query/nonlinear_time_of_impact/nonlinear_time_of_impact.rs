@@ -1,5 +1,5 @@
 use crate::query::{
-    DefaultQueryDispatcher, NonlinearRigidMotion, QueryDispatcher, Unsupported, TOI,
+    DefaultQueryDispatcher, QueryDispatcher, RigidMotion, Unsupported, TOI,
 };
 use crate::shape::Shape;
 use ad_trait::AD;
@@ -21,9 +21,9 @@ use ad_trait::AD;
 ///    that normal) then the nonlinear shape-casting will attempt to find another impact,
 ///    at a time `> start_time` that could result in tunnelling.
 pub fn nonlinear_time_of_impact<T: AD>(
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     g1: &dyn Shape<T>,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     g2: &dyn Shape<T>,
     start_time: T,
     end_time: T,
@@ -1,8 +1,9 @@
 use ad_trait::AD;
 use crate::bounding_volume::{BoundingSphere, SimdAabb};
-use crate::math::{SIMD_WIDTH};
+use crate::math::{Isometry, Translation, SIMD_WIDTH};
 use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
-use crate::query::{self, details::NonlinearTOIMode, NonlinearRigidMotion, QueryDispatcher, TOI};
+use crate::query::{self, details::NonlinearTOIMode, QueryDispatcher, TOI};
+use crate::query::{PrependedRigidMotion, RigidMotion};
 use crate::shape::{Ball, Shape, TypedSimdCompositeShape};
 use crate::utils::DefaultStorage;
 use simba::simd::SimdValue;
@@ -10,9 +11,9 @@ use simba::simd::SimdValue;
 /// Time Of Impact of a composite shape with any other shape, under a rigid motion (translation + rotation).
 pub fn nonlinear_time_of_impact_composite_shape_shape<D: ?Sized, G1: ?Sized, T: AD>(
     dispatcher: &D,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     g1: &G1,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     g2: &dyn Shape<T>,
     start_time: T,
     end_time: T,
@@ -41,9 +42,9 @@ where
 /// Time Of Impact of any shape with a composite shape, under a rigid motion (translation + rotation).
 pub fn nonlinear_time_of_impact_shape_composite_shape<D: ?Sized, G2: ?Sized, T: AD>(
     dispatcher: &D,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     g1: &dyn Shape<T>,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     g2: &G2,
     start_time: T,
     end_time: T,
@@ -74,8 +75,8 @@ pub struct NonlinearTOICompositeShapeShapeBestFirstVisitor<'a, D: ?Sized, G1: ?S
     stop_at_penetration: bool,
 
     dispatcher: &'a D,
-    motion1: &'a NonlinearRigidMotion<T>,
-    motion2: &'a NonlinearRigidMotion<T>,
+    motion1: &'a dyn RigidMotion<T>,
+    motion2: &'a dyn RigidMotion<T>,
     g1: &'a G1,
     g2: &'a dyn Shape<T>,
 }
@@ -89,9 +90,9 @@ where
     /// a composite shape and another shape.
     pub fn new(
         dispatcher: &'a D,
-        motion1: &'a NonlinearRigidMotion<T>,
+        motion1: &'a dyn RigidMotion<T>,
         g1: &'a G1,
-        motion2: &'a NonlinearRigidMotion<T>,
+        motion2: &'a dyn RigidMotion<T>,
         g2: &'a dyn Shape<T>,
         start_time: T,
         end_time: T,
@@ -138,8 +139,10 @@ where
             let center1 = centers1.extract(ii);
             let ball1 = Ball::new(radius1[ii]);
             let ball2 = Ball::new(self.sphere2.radius());
-            let ball_motion1 = self.motion1.prepend_translation(center1.coords);
-            let ball_motion2 = self.motion2.prepend_translation(self.sphere2.center.coords);
+            let shift1 = Isometry::from(Translation::from(center1.coords));
+            let shift2 = Isometry::from(Translation::from(self.sphere2.center.coords));
+            let ball_motion1 = PrependedRigidMotion::new(self.motion1, shift1);
+            let ball_motion2 = PrependedRigidMotion::new(self.motion2, shift2);
 
             if let Some(toi) = query::details::nonlinear_time_of_impact_support_map_support_map(
                 self.dispatcher,
@@ -160,7 +163,7 @@ where
                             let toi = if let Some(part_pos1) = part_pos1 {
                                 self.dispatcher
                                     .nonlinear_time_of_impact(
-                                        &self.motion1.prepend(*part_pos1),
+                                        &PrependedRigidMotion::new(self.motion1, *part_pos1),
                                         g1,
                                         self.motion2,
                                         self.g2,
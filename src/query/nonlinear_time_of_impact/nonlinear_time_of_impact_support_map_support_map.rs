@@ -3,9 +3,8 @@ use na::ComplexField; // for .abs()
 use na::{RealField, Unit};
 
 use crate::math::{Point, Vector};
-use crate::query::{self, ClosestPoints, NonlinearRigidMotion, QueryDispatcher, TOIStatus, TOI};
+use crate::query::{self, ClosestPoints, QueryDispatcher, RigidMotion, TOIStatus, TOI};
 use crate::shape::{Shape, SupportMap};
-use crate::utils::WCross;
 
 use crate::query::gjk::ConstantPoint;
 
@@ -57,10 +56,10 @@ impl<T: AD> NonlinearTOIMode<T> {
 /// a nonlinear (with translations and rotations) motion.
 pub fn nonlinear_time_of_impact_support_map_support_map<D, SM1, SM2, T: AD>(
     dispatcher: &D,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     sm1: &SM1,
     g1: &dyn Shape<T>,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     sm2: &SM2,
     g2: &dyn Shape<T>,
     start_time: T,
@@ -94,10 +93,10 @@ where
 /// Time of impacts between two support-mapped shapes under a rigid motion.
 pub fn compute_toi<D, SM1, SM2, T: AD>(
     dispatcher: &D,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     sm1: &SM1,
     g1: &dyn Shape<T>,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     sm2: &SM2,
     g2: &dyn Shape<T>,
     start_time: T,
@@ -240,10 +239,10 @@ where
 
 fn handle_penetration_at_start_time<D, SM1, SM2, T: AD>(
     dispatcher: &D,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     sm1: &SM1,
     g1: &dyn Shape<T>,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     sm2: &SM2,
     g2: &dyn Shape<T>,
     start_time: T,
@@ -275,16 +274,14 @@ where
     // The iterative method here will iteratively check multiple rotation angles to
     // find new future contact points after some rotation; and check the relative
     // velocity at these future contact points.
-    #[cfg(feature = "dim2")]
-    let dangvel = (motion2.angvel - motion1.angvel).abs();
-    #[cfg(feature = "dim3")]
-    let dangvel = (motion2.angvel - motion1.angvel).norm();
+    let (linvel1, angvel1) = motion1.velocity_bounds(start_time, end_time);
+    let (linvel2, angvel2) = motion2.velocity_bounds(start_time, end_time);
+    let dangvel = angvel1 + angvel2;
     let inv_dangvel = crate::utils::inv(dangvel);
     let linear_increment = sum_linear_thickness;
     let angular_increment = T::constant(f64::pi()) - max_angular_thickness;
 
-    let linear_time_increment =
-        linear_increment * crate::utils::inv((motion2.linvel - motion1.linvel).norm());
+    let linear_time_increment = linear_increment * crate::utils::inv(linvel1 + linvel2);
     let angular_time_increment = angular_increment * inv_dangvel;
     let mut time_increment = angular_time_increment
         .min(linear_time_increment)
@@ -320,10 +317,8 @@ where
             // 2. Check if this results in a potential tunnelling.
             // 3. Use bisection to adjust the TOI to the time where a pair
             //    of contact points potentially causing tunneling hit for the first time.
-            let r1 = contact.point1 - motion1.local_center;
-            let r2 = contact.point2 - motion2.local_center;
-            let vel1 = motion1.linvel + motion1.angvel.gcross(pos1_at_next_time * r1);
-            let vel2 = motion2.linvel + motion2.angvel.gcross(pos2_at_next_time * r2);
+            let vel1 = motion1.local_point_velocity(next_time, &contact.point1);
+            let vel2 = motion2.local_point_velocity(next_time, &contact.point2);
             let vel12 = vel2 - vel1;
             let normal_vel = -vel12.dot(&(pos1_at_next_time * contact.normal1));
             let ccd_threshold = if contact.dist <= T::zero() {
@@ -332,15 +327,6 @@ where
                 contact.dist + sum_linear_thickness
             };
 
-            // println!(
-            //     "linvel: {:?}, angvel: {:?}, r2: {:?}, angpart: {:?}, vel2: {:?}",
-            //     motion2.linvel,
-            //     motion2.angvel,
-            //     r2,
-            //     motion2.angvel.gcross(pos2_at_next_time * r2),
-            //     vel2,
-            // );
-            //
             // println!(
             //     "Found normal vel: {}, dist: {}, threshold: {}, if_value: {}, time: {}",
             //     normal_vel,
@@ -440,9 +426,9 @@ struct BisectionRange<T: AD> {
 
 fn bisect<SM1, SM2, T: AD>(
     mut dist: T,
-    motion1: &NonlinearRigidMotion<T>,
+    motion1: &dyn RigidMotion<T>,
     sm1: &SM1,
-    motion2: &NonlinearRigidMotion<T>,
+    motion2: &dyn RigidMotion<T>,
     sm2: &SM2,
     normal1: &Unit<Vector<T>>,
     mut range: BisectionRange<T>,
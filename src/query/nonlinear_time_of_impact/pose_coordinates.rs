@@ -0,0 +1,66 @@
+//! Coordinates of poses interpolated by the spline motions.
+//!
+//! A pose is represented by its translation and its rotation vector (the scaled axis of the
+//! rotation in 3D, or its angle in 2D).
+
+use crate::math::{AngVector, Isometry, Point, Vector};
+use crate::utils::WCross;
+use ad_trait::AD;
+
+#[cfg(feature = "dim2")]
+pub(super) fn rotation_vector<T: AD>(pose: &Isometry<T>) -> AngVector<T> {
+    pose.rotation.angle()
+}
+
+#[cfg(feature = "dim3")]
+pub(super) fn rotation_vector<T: AD>(pose: &Isometry<T>) -> AngVector<T> {
+    pose.rotation.scaled_axis()
+}
+
+#[cfg(feature = "dim2")]
+pub(super) fn ang_norm<T: AD>(v: AngVector<T>) -> T {
+    v.abs()
+}
+
+#[cfg(feature = "dim3")]
+pub(super) fn ang_norm<T: AD>(v: AngVector<T>) -> T {
+    v.norm()
+}
+
+/// The world-space angular velocity of a rotation vector `rot` changing at the rate `drot`.
+#[cfg(feature = "dim2")]
+pub(super) fn angular_velocity<T: AD>(_rot: AngVector<T>, drot: AngVector<T>) -> AngVector<T> {
+    drot
+}
+
+/// The world-space angular velocity of a rotation vector `rot` changing at the rate `drot`.
+///
+/// This applies the left Jacobian of the exponential map of SO(3) to `drot`.
+#[cfg(feature = "dim3")]
+pub(super) fn angular_velocity<T: AD>(rot: AngVector<T>, drot: AngVector<T>) -> AngVector<T> {
+    let angle = rot.norm();
+    let rot_x_drot = rot.cross(&drot);
+
+    if angle < T::constant(1.0e-4) {
+        // First-order expansion of the Jacobian, avoiding the division by `angle`.
+        return drot + rot_x_drot * T::constant(0.5);
+    }
+
+    let angle2 = angle * angle;
+    let a = (T::one() - angle.cos()) / angle2;
+    let b = (angle - angle.sin()) / (angle2 * angle);
+    drot + rot_x_drot * a + rot.cross(&rot_x_drot) * b
+}
+
+/// The velocity of the point attached at `local_pt` to a body at the pose `pose`, with the
+/// rotation vector `rot`, when its translation changes at the rate `dtra` and its rotation
+/// vector at the rate `drot`.
+pub(super) fn local_point_velocity<T: AD>(
+    pose: &Isometry<T>,
+    rot: AngVector<T>,
+    dtra: Vector<T>,
+    drot: AngVector<T>,
+    local_pt: &Point<T>,
+) -> Vector<T> {
+    dtra + angular_velocity(rot, drot).gcross(pose.rotation * local_pt.coords)
+}
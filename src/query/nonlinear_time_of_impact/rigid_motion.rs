@@ -0,0 +1,162 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::query::NonlinearRigidMotion;
+#[cfg(feature = "std")]
+use crate::shape::Shape;
+use crate::utils::WCross;
+use ad_trait::AD;
+
+/// A continuous rigid motion, i.e., a trajectory of poses parametrized by time.
+///
+/// This is the motion model used by [`crate::query::nonlinear_time_of_impact`]. Besides
+/// the pose at any time, implementors must give conservative bounds on their speed,
+/// which are used to advance safely along the trajectory.
+pub trait RigidMotion<T: AD> {
+    /// Computes the position at time `t` of a rigid-body following this motion.
+    fn position_at_time(&self, t: T) -> Isometry<T>;
+
+    /// Conservative upper bounds on the speed of this motion during the time interval `[t0, t1]`.
+    ///
+    /// Returns `(max_linvel, max_angvel)` where `max_linvel` bounds the norm of the velocity
+    /// of the origin of the moving local frame, and `max_angvel` bounds the norm of the
+    /// angular velocity. The velocity of any point at a distance `r` from the origin of the
+    /// local frame is thus bounded by `max_linvel + max_angvel * r`.
+    fn velocity_bounds(&self, t0: T, t1: T) -> (T, T);
+
+    /// The velocity at time `t` of the point attached to the moving body at `local_pt`.
+    ///
+    /// The default implementation uses central finite differences of `position_at_time`,
+    /// with a time step scaled to the precision of `T` and to the magnitude of `t`.
+    fn local_point_velocity(&self, t: T, local_pt: &Point<T>) -> Vector<T> {
+        // The cube root of the machine epsilon balances the truncation and rounding errors
+        // of central differences.
+        let dt = T::default_epsilon().cbrt() * t.abs().max(T::one());
+        let pt_before = self.position_at_time(t - dt) * local_pt;
+        let pt_after = self.position_at_time(t + dt) * local_pt;
+        (pt_after - pt_before) / (dt * T::constant(2.0))
+    }
+}
+
+/// The greatest distance between the local origin of `shape` and any of its points.
+#[cfg(feature = "std")]
+pub(crate) fn local_radius<T: AD>(shape: &dyn Shape<T>) -> T {
+    let bs = shape.compute_local_bounding_sphere();
+    bs.center.coords.norm() + bs.radius
+}
+
+/// An upper bound of the relative speed, during `[t0, t1]`, of two points at distances at most
+/// `rad1` and `rad2` from the local origins of the bodies following `motion1` and `motion2`.
+#[cfg(feature = "std")]
+pub(crate) fn max_relative_speed<T: AD>(
+    motion1: &dyn RigidMotion<T>,
+    rad1: T,
+    motion2: &dyn RigidMotion<T>,
+    rad2: T,
+    t0: T,
+    t1: T,
+) -> T {
+    let (linvel1, angvel1) = motion1.velocity_bounds(t0, t1);
+    let (linvel2, angvel2) = motion2.velocity_bounds(t0, t1);
+    linvel1 + angvel1 * rad1 + linvel2 + angvel2 * rad2
+}
+
+impl<T: AD> RigidMotion<T> for NonlinearRigidMotion<T> {
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        NonlinearRigidMotion::position_at_time(self, t)
+    }
+
+    fn velocity_bounds(&self, _: T, _: T) -> (T, T) {
+        #[cfg(feature = "dim2")]
+        let angvel = self.angvel.abs();
+        #[cfg(feature = "dim3")]
+        let angvel = self.angvel.norm();
+        // The local origin rotates around `local_center`.
+        let linvel = self.linvel.norm() + angvel * self.local_center.coords.norm();
+        (linvel, angvel)
+    }
+
+    fn local_point_velocity(&self, t: T, local_pt: &Point<T>) -> Vector<T> {
+        let r = local_pt - self.local_center;
+        self.linvel
+            + self
+                .angvel
+                .gcross(NonlinearRigidMotion::position_at_time(self, t) * r)
+    }
+}
+
+/// A rigid motion followed by a constant isometry expressed in its local frame.
+///
+/// The position at time `t` is `motion.position_at_time(t) * shift`. This is typically used
+/// to obtain the motion of a sub-shape of a composite shape.
+pub struct PrependedRigidMotion<'a, T: AD> {
+    /// The motion being prepended.
+    pub motion: &'a dyn RigidMotion<T>,
+    /// The constant isometry prepended to the motion.
+    pub shift: Isometry<T>,
+}
+
+impl<'a, T: AD> PrependedRigidMotion<'a, T> {
+    /// Creates the motion `motion` with the constant isometry `shift` prepended to it.
+    pub fn new(motion: &'a dyn RigidMotion<T>, shift: Isometry<T>) -> Self {
+        Self { motion, shift }
+    }
+}
+
+impl<'a, T: AD> RigidMotion<T> for PrependedRigidMotion<'a, T> {
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        self.motion.position_at_time(t) * self.shift
+    }
+
+    fn velocity_bounds(&self, t0: T, t1: T) -> (T, T) {
+        let (linvel, angvel) = self.motion.velocity_bounds(t0, t1);
+        (
+            linvel + angvel * self.shift.translation.vector.norm(),
+            angvel,
+        )
+    }
+
+    fn local_point_velocity(&self, t: T, local_pt: &Point<T>) -> Vector<T> {
+        self.motion
+            .local_point_velocity(t, &(self.shift * local_pt))
+    }
+}
+
+/// A rigid motion given by an arbitrary function of time, e.g., the forward kinematics of
+/// a robot link along a joint-space trajectory.
+///
+/// The speed bounds are provided by the user and must hold over the whole trajectory. For
+/// a robot link, they can be derived from the joint velocity limits and the link geometry.
+pub struct FnRigidMotion<F, T: AD> {
+    /// The function computing the pose at a given time.
+    pub position: F,
+    /// An upper bound on the norm of the velocity of the origin of the moving local frame.
+    pub max_linvel: T,
+    /// An upper bound on the norm of the angular velocity.
+    pub max_angvel: T,
+}
+
+impl<F, T: AD> FnRigidMotion<F, T>
+where
+    F: Fn(T) -> Isometry<T>,
+{
+    /// Creates a rigid motion with the pose `position(t)` at time `t`.
+    pub fn new(position: F, max_linvel: T, max_angvel: T) -> Self {
+        Self {
+            position,
+            max_linvel,
+            max_angvel,
+        }
+    }
+}
+
+impl<F, T: AD> RigidMotion<T> for FnRigidMotion<F, T>
+where
+    F: Fn(T) -> Isometry<T>,
+{
+    fn position_at_time(&self, t: T) -> Isometry<T> {
+        (self.position)(t)
+    }
+
+    fn velocity_bounds(&self, _: T, _: T) -> (T, T) {
+        (self.max_linvel, self.max_angvel)
+    }
+}
@@ -5,6 +5,11 @@ pub use self::point_composite_shape::{
     PointCompositeShapeProjBestFirstVisitor, PointCompositeShapeProjWithFeatureBestFirstVisitor,
     PointCompositeShapeProjWithLocationBestFirstVisitor,
 };
+#[cfg(feature = "std")]
+pub use self::point_knn::{
+    knn_composite_shape, knn_point_cloud, point_cloud_qbvh, within_radius_composite_shape,
+    within_radius_point_cloud,
+};
 #[doc(inline)]
 pub use self::point_query::{PointProjection, PointQuery, PointQueryWithLocation};
 #[cfg(feature = "std")] // TODO: can’t be used without std because of EPA
@@ -22,12 +27,22 @@ mod point_cuboid;
 mod point_cylinder;
 mod point_halfspace;
 mod point_heightfield;
+#[cfg(feature = "std")]
+mod point_knn;
+mod point_minkowski_sum;
 #[doc(hidden)]
 pub mod point_query;
 mod point_round_shape;
+mod point_scaled;
 mod point_segment;
 #[cfg(feature = "std")]
 mod point_support_map;
 #[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod point_tetmesh;
+#[cfg(feature = "dim3")]
 mod point_tetrahedron;
 mod point_triangle;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod point_voxels;
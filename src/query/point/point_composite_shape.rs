@@ -61,8 +61,8 @@ impl<Storage: TriMeshStorage<T>, T: AD> PointQuery<T> for GenericTriMesh<Storage
         point: &Point<T>,
     ) -> (PointProjection<T>, FeatureId) {
         #[cfg(feature = "dim3")]
-        if self.pseudo_normals().is_some() {
-            // If we can, in 3D, take the pseudo-normals into account.
+        if self.pseudo_normals().is_some() || self.winding_numbers().is_some() {
+            // If we can, in 3D, take the pseudo-normals or winding numbers into account.
             let (proj, (id, _feature)) = self.project_local_point_and_get_location(point, false);
             let feature_id = FeatureId::Face(id);
             return (proj, feature_id);
@@ -79,6 +79,11 @@ impl<Storage: TriMeshStorage<T>, T: AD> PointQuery<T> for GenericTriMesh<Storage
 
     #[inline]
     fn contains_local_point(&self, point: &Point<T>) -> bool {
+        #[cfg(feature = "dim3")]
+        if self.winding_numbers.is_some() {
+            return self.contains_local_point_by_winding_number(point);
+        }
+
         #[cfg(feature = "dim3")]
         if self.pseudo_normals.is_some() {
             // If we can, in 3D, take the pseudo-normals into account.
@@ -176,7 +181,9 @@ impl<Storage: TriMeshStorage<T>, T: AD> PointQueryWithLocation<T> for GenericTri
                 .traverse_best_first_node(&mut visitor, 0, max_dist)
         {
             #[cfg(feature = "dim3")]
-            if let Some(pseudo_normals) = self.pseudo_normals() {
+            if self.winding_numbers().is_some() {
+                proj.is_inside = self.contains_local_point_by_winding_number(point);
+            } else if let Some(pseudo_normals) = self.pseudo_normals() {
                 let pseudo_normal = match location {
                     TrianglePointLocation::OnFace(..) | TrianglePointLocation::OnSolid => {
                         Some(self.triangle(part_id).scaled_normal())
@@ -497,14 +504,14 @@ where S: TypedSimdCompositeShape<T>,
 }
 
 
-pub struct PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T: AD> {
+pub struct PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S: ?Sized, T: AD> {
     shape: &'a S,
     point: &'a Point<T>,
     simd_point: Point<T>,
     solid: bool,
 }
 
-impl<'a, S, T: AD> PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T> {
+impl<'a, S: ?Sized, T: AD> PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T> {
     /// Initialize a visitor for the projection of a point on a composite shape.
     pub fn new(shape: &'a S, point: &'a Point<T>, solid: bool) -> Self {
         Self {
@@ -516,7 +523,7 @@ impl<'a, S, T: AD> PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T>
     }
 }
 
-impl<'a, S, T:AD> SimdBestFirstVisitor<S::PartId, SimdAabb<T>, T> for PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T>
+impl<'a, S: ?Sized, T:AD> SimdBestFirstVisitor<S::PartId, SimdAabb<T>, T> for PointCompositeShapeProjWithFeatureBestFirstVisitor<'a, S, T>
 where S: TypedSimdCompositeShape<T>, FeatureId: Copy {
     type Result = (PointProjection<T>, (S::PartId, FeatureId));
 
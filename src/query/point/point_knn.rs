@@ -0,0 +1,93 @@
+use crate::bounding_volume::Aabb;
+use crate::math::Point;
+use crate::partitioning::Qbvh;
+use crate::query::PointQuery;
+use crate::shape::SimdCompositeShape;
+use ad_trait::AD;
+
+/// The `k` sub-shapes of a composite shape (`TriMesh`, `Polyline`, `Compound`, etc.) closest
+/// to the local point `point`.
+///
+/// Returns the sub-shape indices with their distances to `point`, sorted by increasing distance.
+/// Any point inside of a sub-shape is at a distance of zero from it.
+pub fn knn_composite_shape<G: ?Sized + SimdCompositeShape<T>, T: AD>(
+    g: &G,
+    point: &Point<T>,
+    k: usize,
+) -> Vec<(u32, T)> {
+    g.qbvh().knn(point, k, |part_id| {
+        composite_part_distance(g, *part_id, point)
+    })
+}
+
+/// All the sub-shapes of a composite shape (`TriMesh`, `Polyline`, `Compound`, etc.) closer
+/// than `radius` to the local point `point`.
+///
+/// Returns the sub-shape indices with their distances to `point`, sorted by increasing distance.
+pub fn within_radius_composite_shape<G: ?Sized + SimdCompositeShape<T>, T: AD>(
+    g: &G,
+    point: &Point<T>,
+    radius: T,
+) -> Vec<(u32, T)> {
+    g.qbvh().within_radius(point, radius, |part_id| {
+        composite_part_distance(g, *part_id, point)
+    })
+}
+
+fn composite_part_distance<G: ?Sized + SimdCompositeShape<T>, T: AD>(
+    g: &G,
+    part_id: u32,
+    point: &Point<T>,
+) -> Option<T> {
+    let mut dist = None;
+    g.map_part_at(part_id, &mut |part_pos, part| {
+        dist = Some(match part_pos {
+            Some(part_pos) => part.distance_to_point(part_pos, point, true),
+            None => part.distance_to_local_point(point, true),
+        });
+    });
+    dist
+}
+
+/// Builds the Qbvh of a point cloud, suitable for [`knn_point_cloud`] and [`within_radius_point_cloud`].
+///
+/// The leaf data are the indices of the points.
+pub fn point_cloud_qbvh<T: AD>(points: &[Point<T>]) -> Qbvh<u32, T> {
+    let mut qbvh = Qbvh::new();
+    let data = points
+        .iter()
+        .enumerate()
+        .map(|(i, pt)| (i as u32, Aabb::new(*pt, *pt)));
+    qbvh.clear_and_rebuild(data, T::zero());
+    qbvh
+}
+
+/// The `k` points of a point cloud closest to `point`.
+///
+/// The `qbvh` must have been built from `points` by [`point_cloud_qbvh`]. Returns the point
+/// indices with their distances to `point`, sorted by increasing distance.
+pub fn knn_point_cloud<T: AD>(
+    qbvh: &Qbvh<u32, T>,
+    points: &[Point<T>],
+    point: &Point<T>,
+    k: usize,
+) -> Vec<(u32, T)> {
+    qbvh.knn(point, k, |i| {
+        Some(na::distance(&points[*i as usize], point))
+    })
+}
+
+/// All the points of a point cloud closer than `radius` to `point`.
+///
+/// The `qbvh` must have been built from `points` by [`point_cloud_qbvh`]. Returns the point
+/// indices with their distances to `point`, sorted by increasing distance.
+pub fn within_radius_point_cloud<T: AD>(
+    qbvh: &Qbvh<u32, T>,
+    points: &[Point<T>],
+    point: &Point<T>,
+    radius: T,
+) -> Vec<(u32, T)> {
+    qbvh.within_radius(point, radius, |i| {
+        Some(na::distance(&points[*i as usize], point))
+    })
+}
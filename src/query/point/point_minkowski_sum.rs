@@ -0,0 +1,37 @@
+use crate::math::Point;
+use crate::query::gjk::VoronoiSimplex;
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, MinkowskiDifference, MinkowskiSum, SupportMap};
+use ad_trait::AD;
+
+macro_rules! impl_point_query_for_minkowski_shape(
+    ($($Shape: ident),*) => {$(
+        impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> PointQuery<T> for $Shape<A, B, T> {
+            #[inline]
+            fn project_local_point(&self, point: &Point<T>, solid: bool) -> PointProjection<T> {
+                #[cfg(not(feature = "std"))] // FIXME: can’t be used without std because of EPA
+                return unimplemented!(
+                    "The projection of points on a Minkowski sum isn’t supported on no-std platforms yet."
+                );
+
+                #[cfg(feature = "std")] // FIXME: can’t be used without std because of EPA
+                return crate::query::details::local_point_projection_on_support_map(
+                    self,
+                    &mut VoronoiSimplex::new(),
+                    point,
+                    solid,
+                );
+            }
+
+            #[inline]
+            fn project_local_point_and_get_feature(
+                &self,
+                point: &Point<T>,
+            ) -> (PointProjection<T>, FeatureId) {
+                (self.project_local_point(point, false), FeatureId::Unknown)
+            }
+        }
+    )*}
+);
+
+impl_point_query_for_minkowski_shape!(MinkowskiSum, MinkowskiDifference);
@@ -1,9 +1,18 @@
-use crate::math::{Point};
+use crate::math::Point;
 use crate::query::gjk::VoronoiSimplex;
 use crate::query::{PointProjection, PointQuery};
 use crate::shape::{FeatureId, RoundShape, SupportMap};
 use ad_trait::AD;
 
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+use {
+    crate::bounding_volume::{Aabb, BoundingVolume},
+    crate::shape::RoundHeightField,
+};
+#[cfg(feature = "std")]
+use {crate::math::Vector, crate::shape::RoundTriMesh, na::Unit};
+
 // TODO: if PointQuery had a `project_point_with_normal` method, we could just
 // call this and adjust the projected point accordingly.
 impl<S: SupportMap<T>, T: AD> PointQuery<T> for RoundShape<S, T> {
@@ -31,3 +40,161 @@ impl<S: SupportMap<T>, T: AD> PointQuery<T> for RoundShape<S, T> {
         (self.project_local_point(point, false), FeatureId::Unknown)
     }
 }
+
+#[cfg(feature = "std")]
+impl<T: AD> PointQuery<T> for RoundTriMesh<T> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<T>, solid: bool) -> PointProjection<T> {
+        self.project_local_point_with_feature(point, solid).0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<T>,
+    ) -> (PointProjection<T>, FeatureId) {
+        self.project_local_point_with_feature(point, false)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<T>) -> bool {
+        self.project_local_point(point, true).is_inside
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: AD> RoundTriMesh<T> {
+    fn project_local_point_with_feature(
+        &self,
+        point: &Point<T>,
+        solid: bool,
+    ) -> (PointProjection<T>, FeatureId) {
+        let (inner_proj, feature) = self.inner_shape.project_local_point_and_get_feature(point);
+
+        #[cfg(feature = "dim3")]
+        let normal = match feature {
+            FeatureId::Face(i) => self.inner_shape.triangle(i).normal(),
+            _ => None,
+        };
+        #[cfg(feature = "dim2")]
+        let normal = None;
+
+        let proj = dilate_inner_projection(inner_proj, self.border_radius, point, solid, normal);
+        (proj, feature)
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> PointQuery<T> for RoundHeightField<T> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<T>, solid: bool) -> PointProjection<T> {
+        self.project_local_point_with_feature(point, solid).0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<T>,
+    ) -> (PointProjection<T>, FeatureId) {
+        self.project_local_point_with_feature(point, false)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<T>) -> bool {
+        self.project_local_point(point, true).is_inside
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> RoundHeightField<T> {
+    fn project_local_point_with_feature(
+        &self,
+        point: &Point<T>,
+        solid: bool,
+    ) -> (PointProjection<T>, FeatureId) {
+        // Look for the closest triangle in a box around the point, growing it until it
+        // contains the closest triangle found, or the whole heightfield.
+        let local_aabb = self.inner_shape.local_aabb();
+        let mut half_extent = self
+            .border_radius
+            .max(T::constant(crate::math::DEFAULT_EPSILON));
+        let mut best = None;
+
+        loop {
+            let aabb = Aabb::new(
+                point - Vector::repeat(half_extent),
+                point + Vector::repeat(half_extent),
+            );
+            let mut smallest_dist = T::constant(f64::MAX);
+
+            self.inner_shape
+                .map_elements_in_local_aabb(&aabb, &mut |id, tri| {
+                    let proj = tri.project_local_point(point, false);
+                    let dist = na::distance(point, &proj.point);
+
+                    if dist < smallest_dist {
+                        smallest_dist = dist;
+                        best = Some((proj, *tri, id));
+                    }
+                });
+
+            if smallest_dist <= half_extent || aabb.contains(&local_aabb) {
+                break;
+            }
+
+            half_extent *= T::constant(2.0);
+        }
+
+        match best {
+            Some((inner_proj, tri, id)) => {
+                // The heightfield triangles may be wound either way, but their
+                // border always faces the `+Y` axis.
+                let normal = tri.normal().map(|n| if n.y < T::zero() { -n } else { n });
+                let proj =
+                    dilate_inner_projection(inner_proj, self.border_radius, point, solid, normal);
+                (proj, FeatureId::Face(id))
+            }
+            None => (PointProjection::new(false, *point), FeatureId::Unknown),
+        }
+    }
+}
+
+/// Deduces the projection of `point` on a shape dilated by `border_radius` from its
+/// projection on the non-dilated shape.
+///
+/// The `normal` is the outward direction used when `point` lies exactly on the
+/// non-dilated shape.
+#[cfg(feature = "std")]
+fn dilate_inner_projection<T: AD>(
+    inner_proj: PointProjection<T>,
+    border_radius: T,
+    point: &Point<T>,
+    solid: bool,
+    normal: Option<Unit<Vector<T>>>,
+) -> PointProjection<T> {
+    let dpt = point - inner_proj.point;
+    let dist = dpt.norm();
+    let eps = T::constant(crate::math::DEFAULT_EPSILON);
+    let fallback_dir = normal.map(|n| n.into_inner()).unwrap_or_else(Vector::zeros);
+
+    if inner_proj.is_inside {
+        if solid {
+            return PointProjection::new(true, *point);
+        }
+
+        // The closest point of the border lies beyond the projection on the inner shape.
+        let dir = if dist > eps { -dpt / dist } else { fallback_dir };
+        return PointProjection::new(true, inner_proj.point + dir * border_radius);
+    }
+
+    let is_inside = dist <= border_radius;
+
+    if is_inside && solid {
+        return PointProjection::new(true, *point);
+    }
+
+    let dir = if dist > eps { dpt / dist } else { fallback_dir };
+    PointProjection::new(is_inside, inner_proj.point + dir * border_radius)
+}
@@ -0,0 +1,154 @@
+use crate::bounding_volume::SimdAabb;
+use crate::math::{Point, SIMD_WIDTH};
+use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor, SimdVisitStatus};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, TetMesh, Tetrahedron, Triangle};
+use ad_trait::AD;
+use simba::simd::SimdBool as _;
+
+impl<T: AD> TetMesh<T> {
+    /// Finds a tetrahedron containing the given point, expressed in the local-space of `self`.
+    ///
+    /// Returns the index of the containing tetrahedron, and the barycentric coordinates of
+    /// `point` relative to its four vertices. Returns `None` if `point` is outside of this mesh.
+    pub fn locate_local_point(&self, point: &Point<T>) -> Option<(u32, [T; 4])> {
+        let mut result = None;
+        let tolerance = T::constant(-crate::math::DEFAULT_EPSILON);
+
+        let mut visitor = |aabb: &SimdAabb<T>, data: Option<[Option<&u32>; SIMD_WIDTH]>| {
+            let mask = aabb.contains_local_point(point);
+
+            if let Some(data) = data {
+                for ii in 0..SIMD_WIDTH {
+                    if mask && data[ii].is_some() {
+                        let tet_id = *data[ii].unwrap();
+                        let bcoords = self.tetrahedron(tet_id).barycentric_coordinates(point);
+
+                        if let Some(bcoords) = bcoords {
+                            if bcoords.iter().all(|c| *c >= tolerance) {
+                                result = Some((tet_id, bcoords));
+                                return SimdVisitStatus::ExitEarly;
+                            }
+                        }
+                    }
+                }
+            }
+
+            SimdVisitStatus::MaybeContinue(mask)
+        };
+
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        result
+    }
+
+    /// Projects a point on the boundary surface of `self`.
+    ///
+    /// Returns the projection and the boundary face it lies on, identified as
+    /// `FeatureId::Face(4 * tetrahedron_id + face_id)`.
+    fn project_local_point_on_boundary(&self, point: &Point<T>) -> (PointProjection<T>, FeatureId) {
+        let is_inside = self.locate_local_point(point).is_some();
+        let mut visitor = TetMeshBoundaryProjBestFirstVisitor { mesh: self, point };
+
+        match self.qbvh().traverse_best_first(&mut visitor) {
+            Some((_, (proj, feature))) => (PointProjection::new(is_inside, proj), feature),
+            // The mesh has no boundary (e.g. it is empty).
+            None => (PointProjection::new(false, *point), FeatureId::Unknown),
+        }
+    }
+}
+
+impl<T: AD> PointQuery<T> for TetMesh<T> {
+    #[inline]
+    fn project_local_point(&self, point: &Point<T>, solid: bool) -> PointProjection<T> {
+        if solid && self.contains_local_point(point) {
+            return PointProjection::new(true, *point);
+        }
+
+        self.project_local_point_on_boundary(point).0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        point: &Point<T>,
+    ) -> (PointProjection<T>, FeatureId) {
+        self.project_local_point_on_boundary(point)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, point: &Point<T>) -> bool {
+        self.locate_local_point(point).is_some()
+    }
+}
+
+/// A visitor for the projection of a point on the boundary faces of a tetrahedral mesh.
+struct TetMeshBoundaryProjBestFirstVisitor<'a, T: AD> {
+    mesh: &'a TetMesh<T>,
+    point: &'a Point<T>,
+}
+
+impl<'a, T: AD> SimdBestFirstVisitor<u32, SimdAabb<T>, T>
+    for TetMeshBoundaryProjBestFirstVisitor<'a, T>
+{
+    type Result = (Point<T>, FeatureId);
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: T,
+        aabb: &SimdAabb<T>,
+        data: Option<[Option<&u32>; SIMD_WIDTH]>,
+    ) -> SimdBestFirstVisitStatus<Self::Result, T> {
+        let dist = aabb.distance_to_local_point(self.point);
+        let mask = dist.simd_lt(best);
+
+        if let Some(data) = data {
+            let mut weights = [T::zero(); SIMD_WIDTH];
+            let mut results = [None; SIMD_WIDTH];
+            let mut found = [false; SIMD_WIDTH];
+            let bitmask = mask.bitmask();
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
+                    let tet_id = *data[ii].unwrap();
+                    let tetrahedron = self.mesh.tetrahedron(tet_id);
+                    let mut best_dist = T::constant(f64::MAX);
+
+                    for face_id in 0..4 {
+                        if self.mesh.adjacent_tetrahedron(tet_id, face_id).is_some() {
+                            continue;
+                        }
+
+                        let (i1, i2, i3) = Tetrahedron::<T>::face_ids(face_id);
+                        let face = Triangle::new(
+                            tetrahedron.vertex(i1),
+                            tetrahedron.vertex(i2),
+                            tetrahedron.vertex(i3),
+                        );
+                        let proj = face.project_local_point(self.point, false).point;
+                        let dist = na::distance(self.point, &proj);
+
+                        if dist < best_dist {
+                            best_dist = dist;
+                            weights[ii] = dist;
+                            found[ii] = true;
+                            results[ii] = Some((proj, FeatureId::Face(tet_id * 4 + face_id)));
+                        }
+                    }
+                }
+            }
+
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: weights[0],
+                mask: found[0],
+                results,
+            }
+        } else {
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: dist,
+                mask,
+                results: [None; SIMD_WIDTH],
+            }
+        }
+    }
+}
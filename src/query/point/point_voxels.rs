@@ -0,0 +1,122 @@
+use crate::math::{Point, Vector};
+use crate::query::{PointProjection, PointQuery};
+use crate::shape::{FeatureId, Voxels};
+use ad_trait::AD;
+
+impl<T: AD> PointQuery<T> for Voxels<T> {
+    #[inline]
+    fn project_local_point(&self, pt: &Point<T>, solid: bool) -> PointProjection<T> {
+        project_local_point_on_voxels(self, pt, solid).0
+    }
+
+    #[inline]
+    fn project_local_point_and_get_feature(
+        &self,
+        pt: &Point<T>,
+    ) -> (PointProjection<T>, FeatureId) {
+        let (proj, id) = project_local_point_on_voxels(self, pt, false);
+        let feature = id.map(FeatureId::Face).unwrap_or(FeatureId::Unknown);
+        (proj, feature)
+    }
+
+    #[inline]
+    fn contains_local_point(&self, pt: &Point<T>) -> bool {
+        self.is_voxel_filled(&self.voxel_key_at_point(pt))
+    }
+}
+
+/// Projects a point on the filled voxels, and returns the identifier of the filled
+/// voxel the projection lies on.
+///
+/// The voxels are visited by layers of increasing (Chebyshev) distance from the voxel
+/// containing the point, until no unvisited voxel can be closer than the best projection.
+fn project_local_point_on_voxels<T: AD>(
+    voxels: &Voxels<T>,
+    pt: &Point<T>,
+    solid: bool,
+) -> (PointProjection<T>, Option<u32>) {
+    let (key_mins, key_maxs) = match voxels.filled_keys_bounds() {
+        Some(bounds) => bounds,
+        None => return (PointProjection::new(false, *voxels.origin()), None),
+    };
+
+    let key = voxels.voxel_key_at_point(pt);
+    let inside = voxels.is_voxel_filled(&key);
+
+    if inside && solid {
+        return (PointProjection::new(true, *pt), voxels.voxel_id(&key));
+    }
+
+    // If the point is inside, we are looking for the closest empty voxel. Otherwise
+    // we are looking for the closest filled voxel, starting from the filled bounds.
+    let center = if inside {
+        key
+    } else {
+        key.sup(&key_mins).inf(&key_maxs)
+    };
+    let extents = key_maxs - key_mins;
+    let max_radius = extents.x.max(extents.y).max(extents.z) + 1;
+
+    let mut best_sq_dist = T::constant(f64::MAX);
+    let mut best_point = *pt;
+    let mut best_key = key;
+
+    for radius in 0..=max_radius {
+        if radius > 1 {
+            // All the voxels of this layer are at least that far from the point.
+            let min_dist = voxels.voxel_size() * T::constant((radius - 1) as f64);
+
+            if min_dist * min_dist >= best_sq_dist {
+                break;
+            }
+        }
+
+        visit_layer(&center, radius, |candidate| {
+            if voxels.is_voxel_filled(&candidate) == inside {
+                return;
+            }
+
+            let proj = voxels
+                .voxel_aabb(&candidate)
+                .project_local_point(pt, true)
+                .point;
+            let sq_dist = na::distance_squared(pt, &proj);
+
+            if sq_dist < best_sq_dist {
+                best_sq_dist = sq_dist;
+                best_point = proj;
+                best_key = candidate;
+            }
+        });
+    }
+
+    // If the point is inside, the projection lies on the voxel containing it.
+    let id = if inside {
+        voxels.voxel_id(&key)
+    } else {
+        voxels.voxel_id(&best_key)
+    };
+
+    (PointProjection::new(inside, best_point), id)
+}
+
+// Calls `f` on all the keys at the Chebyshev distance `radius` from `center`.
+fn visit_layer(center: &Point<i32>, radius: i32, mut f: impl FnMut(Point<i32>)) {
+    if radius == 0 {
+        f(*center);
+        return;
+    }
+
+    for i in -radius..=radius {
+        for j in -radius..=radius {
+            if i.abs() == radius || j.abs() == radius {
+                for k in -radius..=radius {
+                    f(center + Vector::new(i, j, k));
+                }
+            } else {
+                f(center + Vector::new(i, j, -radius));
+                f(center + Vector::new(i, j, radius));
+            }
+        }
+    }
+}
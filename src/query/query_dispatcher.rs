@@ -1,7 +1,9 @@
 use crate::math::{Isometry, Vector};
 #[cfg(feature = "std")]
-use crate::query::{contact_manifolds::ContactManifoldsWorkspace, ContactManifold};
-use crate::query::{ClosestPoints, Contact, NonlinearRigidMotion, Unsupported, TOI};
+use crate::query::{
+    contact_manifolds::ContactManifoldsWorkspace, gjk::DistanceCache, ContactManifold,
+};
+use crate::query::{ClosestPoints, Contact, RigidMotion, Unsupported, TOI};
 use crate::shape::Shape;
 use ad_trait::AD;
 
@@ -34,6 +36,35 @@ pub trait PersistentQueryDispatcher<T: AD, ManifoldData = (), ContactData = ()>:
         prediction: T,
         manifold: &mut ContactManifold<ManifoldData, ContactData, T>,
     ) -> Result<(), Unsupported>;
+
+    /// Computes the minimum distance separating two shapes, warm-started with `cache`.
+    ///
+    /// The `cache` must only be used for queries on the same pair of shapes. The default
+    /// implementation ignores it.
+    fn distance_with_cache(
+        &self,
+        pos12: &Isometry<T>,
+        g1: &dyn Shape<T>,
+        g2: &dyn Shape<T>,
+        _cache: &mut DistanceCache<T>,
+    ) -> Result<T, Unsupported> {
+        self.distance(pos12, g1, g2)
+    }
+
+    /// Computes the pair of closest points between two shapes, warm-started with `cache`.
+    ///
+    /// The `cache` must only be used for queries on the same pair of shapes. The default
+    /// implementation ignores it.
+    fn closest_points_with_cache(
+        &self,
+        pos12: &Isometry<T>,
+        g1: &dyn Shape<T>,
+        g2: &dyn Shape<T>,
+        max_dist: T,
+        _cache: &mut DistanceCache<T>,
+    ) -> Result<ClosestPoints<T>, Unsupported> {
+        self.closest_points(pos12, g1, g2, max_dist)
+    }
 }
 
 /// Dispatcher for pairwise queries.
@@ -62,6 +93,22 @@ pub trait QueryDispatcher<T: AD>: Send + Sync {
         g2: &dyn Shape<T>,
     ) -> Result<T, Unsupported>;
 
+    /// Computes the minimum distance separating two shapes, if it is smaller than `max_dist`.
+    ///
+    /// Returns `None` if the objects are separated by a distance greater than `max_dist`, in
+    /// which case the exact distance may not be computed at all. The default implementation
+    /// computes the exact distance with [`QueryDispatcher::distance`].
+    fn distance_with_max(
+        &self,
+        pos12: &Isometry<T>,
+        g1: &dyn Shape<T>,
+        g2: &dyn Shape<T>,
+        max_dist: T,
+    ) -> Result<Option<T>, Unsupported> {
+        let dist = self.distance(pos12, g1, g2)?;
+        Ok(if dist <= max_dist { Some(dist) } else { None })
+    }
+
     /// Computes one pair of contact points point between two shapes.
     ///
     /// Returns `None` if the objects are separated by a distance greater than `prediction`.
@@ -133,9 +180,9 @@ pub trait QueryDispatcher<T: AD>: Send + Sync {
     ///    at a time `> start_time` that could result in tunnelling.
     fn nonlinear_time_of_impact(
         &self,
-        motion1: &NonlinearRigidMotion<T>,
+        motion1: &dyn RigidMotion<T>,
         g1: &dyn Shape<T>,
-        motion2: &NonlinearRigidMotion<T>,
+        motion2: &dyn RigidMotion<T>,
         g2: &dyn Shape<T>,
         start_time: T,
         end_time: T,
@@ -169,6 +216,13 @@ where
 
     chain_method!(distance(pos12: &Isometry<A>, g1: &dyn Shape<A>, g2: &dyn Shape<A>,) -> A);
 
+    chain_method!(distance_with_max(
+        pos12: &Isometry<A>,
+        g1: &dyn Shape<A>,
+        g2: &dyn Shape<A>,
+        max_dist: A,
+    ) -> Option<A>);
+
     chain_method!(contact(
         pos12: &Isometry<A>,
         g1: &dyn Shape<A>,
@@ -193,9 +247,9 @@ where
     ) -> Option<TOI<A>>);
 
     chain_method!(nonlinear_time_of_impact(
-        motion1: &NonlinearRigidMotion<A>,
+        motion1: &dyn RigidMotion<A>,
         g1: &dyn Shape<A>,
-        motion2: &NonlinearRigidMotion<A>,
+        motion2: &dyn RigidMotion<A>,
         g2: &dyn Shape<A>,
         start_time: A,
         end_time: A,
@@ -226,4 +280,19 @@ where
         prediction: A,
         manifold: &mut ContactManifold<ManifoldData, ContactData, A>,
     ) -> ());
+
+    chain_method!(distance_with_cache(
+        pos12: &Isometry<A>,
+        g1: &dyn Shape<A>,
+        g2: &dyn Shape<A>,
+        cache: &mut DistanceCache<A>,
+    ) -> A);
+
+    chain_method!(closest_points_with_cache(
+        pos12: &Isometry<A>,
+        g1: &dyn Shape<A>,
+        g2: &dyn Shape<A>,
+        max_dist: A,
+        cache: &mut DistanceCache<A>,
+    ) -> ClosestPoints<A>);
 }
@@ -1,14 +1,25 @@
 //! Ray-casting related definitions and implementations.
 
 #[doc(inline)]
-pub use self::ray::{Ray, RayCast, RayIntersection};
+pub use self::ray::{Ray, RayCast, RayHit, RayIntersection};
 pub use self::ray_ball::ray_toi_with_ball;
 #[cfg(feature = "std")]
+pub use self::ray_batch::{
+    cast_ray_batch, cast_ray_batch_with_qbvh, RayQbvhLeavesBestFirstVisitor,
+};
+#[cfg(all(feature = "std", feature = "parallel"))]
+pub use self::ray_batch::{par_cast_ray_batch, par_cast_ray_batch_with_qbvh};
+#[cfg(all(feature = "std", feature = "dim3"))]
+pub use self::ray_batch::RaySensor;
+#[cfg(feature = "std")]
 pub use self::ray_composite_shape::{
     RayCompositeShapeToiAndNormalBestFirstVisitor, RayCompositeShapeToiBestFirstVisitor,
 };
 pub use self::ray_halfspace::{line_toi_with_halfspace, ray_toi_with_halfspace};
-pub use self::ray_support_map::local_ray_intersection_with_support_map_with_params;
+pub use self::ray_support_map::{
+    differentiable_toi_and_normal_with_support_map,
+    local_ray_intersection_with_support_map_with_params,
+};
 #[cfg(feature = "dim3")]
 pub use self::ray_triangle::local_ray_intersection_with_triangle;
 pub use self::simd_ray::SimdRay;
@@ -17,13 +28,27 @@ pub use self::simd_ray::SimdRay;
 pub mod ray;
 mod ray_aabb;
 mod ray_ball;
+#[cfg(feature = "std")]
+mod ray_batch;
 mod ray_bounding_sphere;
 #[cfg(feature = "std")]
 mod ray_composite_shape;
+#[cfg(feature = "std")]
+mod ray_composite_shape_all_hits;
 mod ray_cuboid;
 mod ray_halfspace;
 mod ray_heightfield;
+mod ray_minkowski_sum;
 mod ray_round_shape;
+mod ray_scaled;
 mod ray_support_map;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod ray_tetmesh;
+#[cfg(feature = "dim3")]
+mod ray_tetrahedron;
 mod ray_triangle;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod ray_voxels;
 mod simd_ray;
@@ -116,6 +116,33 @@ impl<T: AD> RayIntersection<T> {
     }
 }
 
+/// One of the possibly many intersections between a ray and a composite shape.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RayHit<T: AD> {
+    /// The intersection with the sub-shape, expressed in the same space as the ray.
+    ///
+    /// Its feature identifies the hit face of the whole composite shape when the composite
+    /// shape has a global face numbering (e.g. triangle meshes and heightfields), or the hit
+    /// feature of the sub-shape otherwise.
+    pub intersection: RayIntersection<T>,
+    /// The index of the sub-shape that was hit.
+    pub subshape: u32,
+    /// Whether the ray enters the sub-shape (or hits the front face of a triangle) at this point.
+    pub is_entering: bool,
+}
+
+impl<T: AD> RayHit<T> {
+    /// Transforms this hit by the given isometry.
+    #[inline]
+    pub fn transform_by(&self, transform: &Isometry<T>) -> Self {
+        RayHit {
+            intersection: self.intersection.transform_by(transform),
+            ..*self
+        }
+    }
+}
+
 /// Traits of objects which can be transformed and tested for intersection with a ray.
 pub trait RayCast<T: AD> {
     /// Computes the time of impact between this transform shape and a ray.
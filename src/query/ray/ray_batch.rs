@@ -0,0 +1,249 @@
+//! Convenience wrappers for casting many rays at once.
+//!
+//! Each ray is cast independently of the others: there is no packet traversal since
+//! `SIMD_WIDTH` is 1 with AD scalars (a [`SimdRay`] holds a single ray). The batches are
+//! only processed in parallel, with the `parallel` feature.
+
+use crate::bounding_volume::SimdAabb;
+use crate::math::{Isometry, SIMD_WIDTH};
+use crate::partitioning::{IndexedData, Qbvh, SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::query::{Ray, RayCast, RayIntersection, SimdRay};
+use ad_trait::AD;
+use simba::simd::SimdBool as _;
+
+#[cfg(feature = "dim3")]
+use crate::math::Vector;
+
+/// A pinhole sensor (e.g. a depth camera) casting one ray per pixel.
+///
+/// The sensor looks toward the `+z` axis of its local frame, with the `+x` axis pointing
+/// toward the right of the image, and the `+y` axis pointing toward its bottom. All the rays
+/// are derived from `pose` with scalar operations only, so hits computed with AD scalars can
+/// be differentiated with respect to the sensor pose.
+#[cfg(feature = "dim3")]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RaySensor<T: AD> {
+    /// The pose of the sensor.
+    pub pose: Isometry<T>,
+    /// The vertical field of view of the sensor, in radians.
+    pub fov_y: T,
+    /// The number of pixels of a row of the image.
+    pub width: usize,
+    /// The number of pixels of a column of the image.
+    pub height: usize,
+}
+
+#[cfg(feature = "dim3")]
+impl<T: AD> RaySensor<T> {
+    /// Creates a new pinhole sensor.
+    pub fn new(pose: Isometry<T>, fov_y: T, width: usize, height: usize) -> Self {
+        Self {
+            pose,
+            fov_y,
+            width,
+            height,
+        }
+    }
+
+    /// The total number of rays cast by this sensor, i.e., its number of pixels.
+    pub fn num_rays(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// The ray going through the center of the pixel at the column `u` and the row `v`.
+    ///
+    /// The direction of the ray isn’t normalized: its projection on the optical axis
+    /// has a unit length, so the time of impact of a hit is its depth.
+    pub fn ray(&self, u: usize, v: usize) -> Ray<T> {
+        let half_h = T::constant(self.height as f64 / 2.0);
+        let half_w = T::constant(self.width as f64 / 2.0);
+        let pixel_size = (self.fov_y / T::constant(2.0)).tan() / half_h;
+        let local_dir = Vector::new(
+            (T::constant(u as f64 + 0.5) - half_w) * pixel_size,
+            (T::constant(v as f64 + 0.5) - half_h) * pixel_size,
+            T::one(),
+        );
+
+        Ray::new(
+            self.pose.translation.vector.into(),
+            self.pose.rotation * local_dir,
+        )
+    }
+
+    /// All the rays cast by this sensor, in row-major order.
+    pub fn rays(&self) -> Vec<Ray<T>> {
+        (0..self.height)
+            .flat_map(|v| (0..self.width).map(move |u| (u, v)))
+            .map(|(u, v)| self.ray(u, v))
+            .collect()
+    }
+}
+
+/// Casts every ray of `rays` on the shape `shape` positioned at `pos`, one after the other.
+///
+/// The `i`-th element of `hits` is set to the result of casting the `i`-th ray.
+///
+/// # Panics
+/// Panics if `rays` and `hits` don’t have the same length.
+pub fn cast_ray_batch<S: ?Sized + RayCast<T>, T: AD>(
+    pos: &Isometry<T>,
+    shape: &S,
+    rays: &[Ray<T>],
+    max_toi: T,
+    solid: bool,
+    hits: &mut [Option<RayIntersection<T>>],
+) {
+    assert_eq!(rays.len(), hits.len());
+
+    for (ray, hit) in rays.iter().zip(hits.iter_mut()) {
+        *hit = shape.cast_ray_and_get_normal(pos, ray, max_toi, solid);
+    }
+}
+
+/// Casts every ray of `rays` on the shape `shape` positioned at `pos`, in parallel.
+///
+/// See [`cast_ray_batch`] for details.
+#[cfg(feature = "parallel")]
+pub fn par_cast_ray_batch<S: ?Sized + RayCast<T> + Sync, T: AD + Send + Sync>(
+    pos: &Isometry<T>,
+    shape: &S,
+    rays: &[Ray<T>],
+    max_toi: T,
+    solid: bool,
+    hits: &mut [Option<RayIntersection<T>>],
+) {
+    use rayon::prelude::*;
+    assert_eq!(rays.len(), hits.len());
+
+    rays.par_iter()
+        .zip(hits.par_iter_mut())
+        .for_each(|(ray, hit)| *hit = shape.cast_ray_and_get_normal(pos, ray, max_toi, solid));
+}
+
+/// Casts every ray of `rays` on a scene of shapes stored as the leaves of `qbvh`.
+///
+/// The `qbvh` is traversed once per ray. The closure `cast_leaf` computes the intersection
+/// between a ray and the shape associated to a leaf, with the same conventions as
+/// [`RayCast::cast_ray_and_get_normal`].
+/// The `i`-th element of `hits` is set to the closest hit of the `i`-th ray, together
+/// with the leaf that was hit.
+///
+/// # Panics
+/// Panics if `rays` and `hits` don’t have the same length.
+pub fn cast_ray_batch_with_qbvh<LeafData, T, F>(
+    qbvh: &Qbvh<LeafData, T>,
+    rays: &[Ray<T>],
+    max_toi: T,
+    cast_leaf: F,
+    hits: &mut [Option<(LeafData, RayIntersection<T>)>],
+) where
+    LeafData: IndexedData,
+    T: AD,
+    F: Fn(&LeafData, &Ray<T>, T) -> Option<RayIntersection<T>>,
+{
+    assert_eq!(rays.len(), hits.len());
+
+    for (ray, hit) in rays.iter().zip(hits.iter_mut()) {
+        let mut visitor = RayQbvhLeavesBestFirstVisitor::new(ray, max_toi, &cast_leaf);
+        *hit = qbvh.traverse_best_first(&mut visitor).map(|res| res.1);
+    }
+}
+
+/// Casts every ray of `rays` on a scene of shapes stored as the leaves of `qbvh`, in parallel.
+///
+/// See [`cast_ray_batch_with_qbvh`] for details.
+#[cfg(feature = "parallel")]
+pub fn par_cast_ray_batch_with_qbvh<LeafData, T, F>(
+    qbvh: &Qbvh<LeafData, T>,
+    rays: &[Ray<T>],
+    max_toi: T,
+    cast_leaf: F,
+    hits: &mut [Option<(LeafData, RayIntersection<T>)>],
+) where
+    LeafData: IndexedData + Send + Sync,
+    T: AD + Send + Sync,
+    F: Fn(&LeafData, &Ray<T>, T) -> Option<RayIntersection<T>> + Sync,
+{
+    use rayon::prelude::*;
+    assert_eq!(rays.len(), hits.len());
+
+    rays.par_iter()
+        .zip(hits.par_iter_mut())
+        .for_each(|(ray, hit)| {
+            let mut visitor = RayQbvhLeavesBestFirstVisitor::new(ray, max_toi, &cast_leaf);
+            *hit = qbvh.traverse_best_first(&mut visitor).map(|res| res.1);
+        });
+}
+
+/// A visitor for casting a ray on the shapes associated to the leaves of a Qbvh.
+pub struct RayQbvhLeavesBestFirstVisitor<'a, F, T: AD> {
+    ray: &'a Ray<T>,
+    simd_ray: SimdRay<T>,
+    max_toi: T,
+    cast_leaf: &'a F,
+}
+
+impl<'a, F, T: AD> RayQbvhLeavesBestFirstVisitor<'a, F, T> {
+    /// Initialize a visitor for casting a ray on the shapes associated to the leaves of a Qbvh.
+    pub fn new(ray: &'a Ray<T>, max_toi: T, cast_leaf: &'a F) -> Self {
+        Self {
+            ray,
+            simd_ray: SimdRay::splat(*ray),
+            max_toi,
+            cast_leaf,
+        }
+    }
+}
+
+impl<'a, LeafData, F, T: AD> SimdBestFirstVisitor<LeafData, SimdAabb<T>, T>
+    for RayQbvhLeavesBestFirstVisitor<'a, F, T>
+where
+    LeafData: IndexedData,
+    F: Fn(&LeafData, &Ray<T>, T) -> Option<RayIntersection<T>>,
+{
+    type Result = (LeafData, RayIntersection<T>);
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: T,
+        aabb: &SimdAabb<T>,
+        data: Option<[Option<&LeafData>; SIMD_WIDTH]>,
+    ) -> SimdBestFirstVisitStatus<Self::Result, T> {
+        let (hit, toi) = aabb.cast_local_ray(&self.simd_ray, self.max_toi);
+
+        if let Some(data) = data {
+            let mut weights = [T::zero(); SIMD_WIDTH];
+            let mut mask = [false; SIMD_WIDTH];
+            let mut results = [None; SIMD_WIDTH];
+
+            let better_toi = toi.simd_lt(best);
+            let bitmask = (hit & better_toi).bitmask();
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
+                    let leaf = *data[ii].unwrap();
+
+                    if let Some(result) = (self.cast_leaf)(&leaf, self.ray, self.max_toi) {
+                        results[ii] = Some((leaf, result));
+                        mask[ii] = true;
+                        weights[ii] = result.toi;
+                    }
+                }
+            }
+
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: weights[0],
+                mask: mask[0],
+                results,
+            }
+        } else {
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: toi,
+                mask: hit,
+                results: [None; SIMD_WIDTH],
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::math::Isometry;
+use crate::query::visitors::RayIntersectionsVisitor;
+use crate::query::{Ray, RayCast, RayHit};
+use crate::shape::Compound;
+use ad_trait::AD;
+use std::cmp::Ordering;
+
+#[cfg(feature = "dim3")]
+use crate::shape::{FeatureId, HeightField, TriMesh};
+
+fn sort_hits<T: AD>(hits: &mut [RayHit<T>]) {
+    hits.sort_by(|a, b| {
+        a.intersection
+            .toi
+            .partial_cmp(&b.intersection.toi)
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+#[cfg(feature = "dim3")]
+impl<T: AD> TriMesh<T> {
+    /// Computes every intersection between this mesh and a ray, sorted by increasing time of impact.
+    ///
+    /// Each hit records the triangle it lies on as its sub-shape. Its feature is
+    /// `FeatureId::Face(triangle_id)` if the front face of the triangle was hit, or
+    /// `FeatureId::Face(triangle_id + num_triangles)` if its back face was hit, consistently
+    /// with [`RayCast::cast_local_ray_and_get_normal`].
+    ///
+    /// This is only available in 3D: 2D triangles are solid so they don’t have a front and
+    /// a back face. Cast rays on a [`Compound`] of triangles to get their entry and exit points
+    /// in 2D.
+    pub fn cast_local_ray_all(&self, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let num_triangles = self.indices().len() as u32;
+        let mut hits = vec![];
+        let mut callback = |tri_id: &u32| {
+            let tri = self.triangle(*tri_id);
+
+            if let Some(mut inter) = tri.cast_local_ray_and_get_normal(ray, max_toi, false) {
+                let is_entering = inter.feature != FeatureId::Face(1);
+                inter.feature = if is_entering {
+                    FeatureId::Face(*tri_id)
+                } else {
+                    FeatureId::Face(*tri_id + num_triangles)
+                };
+                hits.push(RayHit {
+                    intersection: inter,
+                    subshape: *tri_id,
+                    is_entering,
+                });
+            }
+
+            true
+        };
+
+        let mut visitor = RayIntersectionsVisitor::new(ray, max_toi, &mut callback);
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        sort_hits(&mut hits);
+        hits
+    }
+
+    /// Computes every intersection between this transformed mesh and a ray, sorted by increasing time of impact.
+    ///
+    /// This is only available in 3D, see [`TriMesh::cast_local_ray_all`].
+    pub fn cast_ray_all(&self, m: &Isometry<T>, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi)
+            .into_iter()
+            .map(|hit| hit.transform_by(m))
+            .collect()
+    }
+}
+
+impl<T: AD> Compound<T> {
+    /// Computes every intersection between this compound shape and a ray, sorted by increasing time of impact.
+    ///
+    /// Each part of the compound is assumed to be convex: it yields at most one entry point,
+    /// and one exit point. The features are the ones of the hit parts, and the normals of
+    /// the exit points point toward the inside of the part, facing the ray like the normals
+    /// of the entry points.
+    pub fn cast_local_ray_all(&self, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let shapes = self.shapes();
+        let aabbs = self.aabbs();
+        let mut hits = vec![];
+        let mut callback = |part_id: &u32| {
+            let (part_pos, part) = &shapes[*part_id as usize];
+
+            // The entry point, unless the ray starts inside of the part.
+            if let Some(inter) = part.cast_ray_and_get_normal(part_pos, ray, max_toi, true) {
+                if inter.toi > T::zero() {
+                    hits.push(RayHit {
+                        intersection: inter,
+                        subshape: *part_id,
+                        is_entering: true,
+                    });
+                }
+            }
+
+            // The exit point is the entry point of the reversed ray, starting
+            // beyond the part.
+            if let Some((_, far_toi)) = aabbs[*part_id as usize].clip_ray_parameters(ray) {
+                let reversed = Ray::new(ray.point_at(far_toi), -ray.dir);
+
+                if let Some(mut inter) =
+                    part.cast_ray_and_get_normal(part_pos, &reversed, far_toi, true)
+                {
+                    inter.toi = far_toi - inter.toi;
+
+                    if inter.toi <= max_toi {
+                        inter.normal = -inter.normal;
+                        hits.push(RayHit {
+                            intersection: inter,
+                            subshape: *part_id,
+                            is_entering: false,
+                        });
+                    }
+                }
+            }
+
+            true
+        };
+
+        let mut visitor = RayIntersectionsVisitor::new(ray, max_toi, &mut callback);
+        let _ = self.qbvh().traverse_depth_first(&mut visitor);
+        sort_hits(&mut hits);
+        hits
+    }
+
+    /// Computes every intersection between this transformed compound shape and a ray, sorted by increasing time of impact.
+    pub fn cast_ray_all(&self, m: &Isometry<T>, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi)
+            .into_iter()
+            .map(|hit| hit.transform_by(m))
+            .collect()
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<T: AD> HeightField<T> {
+    /// Computes every intersection between this heightfield and a ray, sorted by increasing time of impact.
+    ///
+    /// Each hit records the triangle it lies on as its sub-shape, and its feature is expressed
+    /// as a feature of the whole heightfield.
+    ///
+    /// This is only available in 3D.
+    pub fn cast_local_ray_all(&self, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let mut hits = vec![];
+
+        self.map_cells_along_local_ray(ray, max_toi, |i, j| {
+            let (left, right) = self.triangles_at(i, j);
+
+            for (tri, is_left) in [(left, true), (right, false)] {
+                if let Some(mut inter) =
+                    tri.and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, false))
+                {
+                    let is_entering = inter.feature != FeatureId::Face(1);
+                    inter.feature = self.convert_triangle_feature_id(i, j, is_left, inter.feature);
+                    hits.push(RayHit {
+                        intersection: inter,
+                        subshape: self.triangle_id(i, j, is_left),
+                        is_entering,
+                    });
+                }
+            }
+
+            true
+        });
+
+        sort_hits(&mut hits);
+        hits
+    }
+
+    /// Computes every intersection between this transformed heightfield and a ray, sorted by increasing time of impact.
+    pub fn cast_ray_all(&self, m: &Isometry<T>, ray: &Ray<T>, max_toi: T) -> Vec<RayHit<T>> {
+        let ls_ray = ray.inverse_transform_by(m);
+        self.cast_local_ray_all(&ls_ray, max_toi)
+            .into_iter()
+            .map(|hit| hit.transform_by(m))
+            .collect()
+    }
+}
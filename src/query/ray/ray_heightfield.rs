@@ -119,18 +119,21 @@ impl<Storage: HeightFieldStorage> RayCast for GenericHeightField<Storage> {
 }
 
 #[cfg(feature = "dim3")]
-impl<Storage: HeightFieldStorage<T>, T: AD> RayCast<T> for GenericHeightField<Storage, T> {
-    #[inline]
-    fn cast_local_ray_and_get_normal(
+impl<Storage: HeightFieldStorage<T>, T: AD> GenericHeightField<Storage, T> {
+    /// Applies `f` to each cell traversed by the ray, in order, until `f` returns `false`.
+    pub(crate) fn map_cells_along_local_ray(
         &self,
         ray: &Ray<T>,
         max_toi: T,
-        solid: bool,
-    ) -> Option<RayIntersection<T>> {
+        mut f: impl FnMut(usize, usize) -> bool,
+    ) {
         use num_traits::Bounded;
 
         let aabb = self.local_aabb();
-        let (min_t, mut max_t) = aabb.clip_ray_parameters(&ray)?;
+        let (min_t, mut max_t) = match aabb.clip_ray_parameters(&ray) {
+            Some(params) => params,
+            None => return,
+        };
         max_t = max_t.min(max_toi);
         let clip_ray_a = ray.point_at(min_t);
         let mut cell = match self.cell_at_point(&clip_ray_a) {
@@ -154,37 +157,8 @@ impl<Storage: HeightFieldStorage<T>, T: AD> RayCast<T> for GenericHeightField<St
         };
 
         loop {
-            let tris = self.triangles_at(cell.0, cell.1);
-            let inter1 = tris
-                .0
-                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
-            let inter2 = tris
-                .1
-                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
-
-            match (inter1, inter2) {
-                (Some(mut inter1), Some(mut inter2)) => {
-                    if inter1.toi < inter2.toi {
-                        inter1.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, true, inter1.feature);
-                        return Some(inter1);
-                    } else {
-                        inter2.feature =
-                            self.convert_triangle_feature_id(cell.0, cell.1, false, inter2.feature);
-                        return Some(inter2);
-                    }
-                }
-                (Some(mut inter), None) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, true, inter.feature);
-                    return Some(inter);
-                }
-                (None, Some(mut inter)) => {
-                    inter.feature =
-                        self.convert_triangle_feature_id(cell.0, cell.1, false, inter.feature);
-                    return Some(inter);
-                }
-                (None, None) => {}
+            if !f(cell.0, cell.1) {
+                return;
             }
 
             /*
@@ -238,7 +212,56 @@ impl<Storage: HeightFieldStorage<T>, T: AD> RayCast<T> for GenericHeightField<St
                 break;
             }
         }
+    }
+}
 
-        None
+#[cfg(feature = "dim3")]
+impl<Storage: HeightFieldStorage<T>, T: AD> RayCast<T> for GenericHeightField<Storage, T> {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        let mut result = None;
+
+        self.map_cells_along_local_ray(ray, max_toi, |i, j| {
+            let tris = self.triangles_at(i, j);
+            let inter1 = tris
+                .0
+                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
+            let inter2 = tris
+                .1
+                .and_then(|tri| tri.cast_local_ray_and_get_normal(ray, max_toi, solid));
+
+            result = match (inter1, inter2) {
+                (Some(mut inter1), Some(mut inter2)) => {
+                    if inter1.toi < inter2.toi {
+                        inter1.feature =
+                            self.convert_triangle_feature_id(i, j, true, inter1.feature);
+                        Some(inter1)
+                    } else {
+                        inter2.feature =
+                            self.convert_triangle_feature_id(i, j, false, inter2.feature);
+                        Some(inter2)
+                    }
+                }
+                (Some(mut inter), None) => {
+                    inter.feature = self.convert_triangle_feature_id(i, j, true, inter.feature);
+                    Some(inter)
+                }
+                (None, Some(mut inter)) => {
+                    inter.feature = self.convert_triangle_feature_id(i, j, false, inter.feature);
+                    Some(inter)
+                }
+                (None, None) => None,
+            };
+
+            // Stop at the first cell with a hit.
+            result.is_none()
+        });
+
+        result
     }
 }
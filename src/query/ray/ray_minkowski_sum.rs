@@ -0,0 +1,27 @@
+use crate::query::gjk::VoronoiSimplex;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{MinkowskiDifference, MinkowskiSum, SupportMap};
+use ad_trait::AD;
+
+macro_rules! impl_ray_cast_for_minkowski_shape(
+    ($($Shape: ident),*) => {$(
+        impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> RayCast<T> for $Shape<A, B, T> {
+            fn cast_local_ray_and_get_normal(
+                &self,
+                ray: &Ray<T>,
+                max_toi: T,
+                solid: bool,
+            ) -> Option<RayIntersection<T>> {
+                crate::query::details::local_ray_intersection_with_support_map_with_params(
+                    self,
+                    &mut VoronoiSimplex::new(),
+                    ray,
+                    max_toi,
+                    solid,
+                )
+            }
+        }
+    )*}
+);
+
+impl_ray_cast_for_minkowski_shape!(MinkowskiSum, MinkowskiDifference);
@@ -3,6 +3,20 @@ use crate::query::{Ray, RayCast, RayIntersection};
 use crate::shape::{RoundShape, SupportMap};
 use ad_trait::AD;
 
+#[cfg(feature = "std")]
+use {
+    crate::bounding_volume::{Aabb, SimdAabb},
+    crate::math::{Vector, SIMD_WIDTH},
+    crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor},
+    crate::query::{PointQuery, SimdRay},
+    crate::shape::{FeatureId, RoundTriMesh, Triangle},
+    simba::simd::SimdBool as _,
+};
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+use crate::{bounding_volume::BoundingVolume, shape::RoundHeightField};
+
 impl<S: SupportMap<T>, T: AD> RayCast<T> for RoundShape<S, T> {
     fn cast_local_ray_and_get_normal(
         &self,
@@ -19,3 +33,274 @@ impl<S: SupportMap<T>, T: AD> RayCast<T> for RoundShape<S, T> {
         )
     }
 }
+
+#[cfg(feature = "std")]
+impl<T: AD> RayCast<T> for RoundTriMesh<T> {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        if !solid {
+            let mut ids = vec![];
+            let exit = cast_ray_out_of_round_triangles(
+                ray,
+                max_toi,
+                self.border_radius,
+                &mut |aabb, f| {
+                    ids.clear();
+                    self.inner_shape.qbvh().intersect_aabb(aabb, &mut ids);
+
+                    for id in &ids {
+                        f(*id, &self.inner_shape.triangle(*id));
+                    }
+                },
+            );
+
+            if let Some(exit) = exit {
+                return exit;
+            }
+        }
+
+        let mut visitor = RayRoundTriMeshToiAndNormalBestFirstVisitor {
+            shape: self,
+            ray,
+            simd_ray: SimdRay::splat(*ray),
+            max_toi,
+            solid,
+        };
+
+        self.inner_shape
+            .qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (best, mut res))| {
+                res.feature = FeatureId::Face(best);
+                res
+            })
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> RayCast<T> for RoundHeightField<T> {
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        if !solid {
+            let exit = cast_ray_out_of_round_triangles(
+                ray,
+                max_toi,
+                self.border_radius,
+                &mut |aabb, f| {
+                    self.inner_shape
+                        .map_elements_in_local_aabb(aabb, &mut |id, tri| f(id, tri))
+                },
+            );
+
+            if let Some(exit) = exit {
+                return exit;
+            }
+        }
+
+        let aabb = self.inner_shape.local_aabb().loosened(self.border_radius);
+        let (min_t, max_t) = aabb.clip_ray_parameters(ray)?;
+
+        if min_t > max_toi {
+            return None;
+        }
+
+        let max_t = max_t.min(max_toi);
+        let mut best: Option<RayIntersection<T>> = None;
+
+        // Walk along the ray, one cell-sized step at a time (measured on the `xz` plane), and
+        // only test the triangles close enough to the current ray segment to be hit by it.
+        let planar_dir_norm = (ray.dir.x * ray.dir.x + ray.dir.z * ray.dir.z).sqrt();
+        let cell_size = self
+            .inner_shape
+            .cell_width()
+            .min(self.inner_shape.cell_height());
+        let step = if planar_dir_norm > T::constant(crate::math::DEFAULT_EPSILON) {
+            cell_size / planar_dir_norm
+        } else {
+            max_t - min_t
+        };
+        let mut t0 = min_t;
+
+        loop {
+            let t1 = (t0 + step).min(max_t);
+            let segment_aabb = Aabb::from_points(&[ray.point_at(t0), ray.point_at(t1)])
+                .loosened(self.border_radius);
+
+            self.inner_shape
+                .map_elements_in_local_aabb(&segment_aabb, &mut |id, triangle| {
+                    let round_triangle = RoundShape {
+                        inner_shape: *triangle,
+                        border_radius: self.border_radius,
+                    };
+
+                    if let Some(mut hit) =
+                        round_triangle.cast_local_ray_and_get_normal(ray, max_toi, solid)
+                    {
+                        if best.map(|best| hit.toi < best.toi).unwrap_or(true) {
+                            hit.feature = FeatureId::Face(id);
+                            best = Some(hit);
+                        }
+                    }
+                });
+
+            // Every hit with a time of impact smaller than `t1` has been found by now.
+            if best.map(|best| best.toi <= t1).unwrap_or(false) || t1 >= max_t {
+                break;
+            }
+
+            t0 = t1;
+        }
+
+        best
+    }
+}
+
+/// Casts a ray starting inside of a union of round triangles toward the boundary of that union.
+///
+/// Returns `None` if the ray origin isn’t inside of any round triangle. Otherwise, returns the
+/// point where the ray leaves the union, or `Some(None)` if this happens after `max_toi`.
+/// The `triangles_in_aabb` closure must call its second argument on every triangle (with its
+/// index) intersecting the given Aabb.
+#[cfg(feature = "std")]
+fn cast_ray_out_of_round_triangles<T: AD>(
+    ray: &Ray<T>,
+    max_toi: T,
+    border_radius: T,
+    triangles_in_aabb: &mut dyn FnMut(&Aabb<T>, &mut dyn FnMut(u32, &Triangle<T>)),
+) -> Option<Option<RayIntersection<T>>> {
+    let eps = T::constant(crate::math::DEFAULT_EPSILON);
+    let mut toi = T::zero();
+    let mut last_exit: Option<RayIntersection<T>> = None;
+
+    // Jump from the exit point of one round triangle to the next, as long as that exit point
+    // is still strictly inside of another round triangle.
+    loop {
+        let origin = ray.point_at(toi);
+        let aabb = Aabb::new(
+            origin - Vector::repeat(border_radius),
+            origin + Vector::repeat(border_radius),
+        );
+        let mut exit: Option<RayIntersection<T>> = None;
+
+        triangles_in_aabb(&aabb, &mut |id, triangle| {
+            if triangle.distance_to_local_point(&origin, true) < border_radius - eps {
+                let round_triangle = RoundShape {
+                    inner_shape: *triangle,
+                    border_radius,
+                };
+                let sub_ray = Ray::new(origin, ray.dir);
+
+                if let Some(mut hit) = round_triangle.cast_local_ray_and_get_normal(
+                    &sub_ray,
+                    T::constant(f64::MAX),
+                    false,
+                ) {
+                    if exit.map(|exit| hit.toi > exit.toi).unwrap_or(true) {
+                        hit.feature = FeatureId::Face(id);
+                        exit = Some(hit);
+                    }
+                }
+            }
+        });
+
+        match exit {
+            Some(exit) if exit.toi > eps => {
+                toi += exit.toi;
+                last_exit = Some(exit);
+
+                if toi > max_toi {
+                    return Some(None);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    last_exit.map(|mut exit| {
+        exit.toi = toi;
+        Some(exit)
+    })
+}
+
+/*
+ * Visitors
+ */
+/// A visitor for casting a ray on a triangle mesh dilated by a ball.
+#[cfg(feature = "std")]
+struct RayRoundTriMeshToiAndNormalBestFirstVisitor<'a, T: AD> {
+    shape: &'a RoundTriMesh<T>,
+    ray: &'a Ray<T>,
+    simd_ray: SimdRay<T>,
+    max_toi: T,
+    solid: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: AD> SimdBestFirstVisitor<u32, SimdAabb<T>, T>
+    for RayRoundTriMeshToiAndNormalBestFirstVisitor<'a, T>
+{
+    type Result = (u32, RayIntersection<T>);
+
+    #[inline]
+    fn visit(
+        &mut self,
+        best: T,
+        aabb: &SimdAabb<T>,
+        data: Option<[Option<&u32>; SIMD_WIDTH]>,
+    ) -> SimdBestFirstVisitStatus<Self::Result, T> {
+        // The Qbvh bounds the triangles of the inner mesh, not their dilation.
+        let mut aabb = *aabb;
+        aabb.loosen(self.shape.border_radius);
+        let (hit, toi) = aabb.cast_local_ray(&self.simd_ray, self.max_toi);
+
+        if let Some(data) = data {
+            let mut weights = [T::zero(); SIMD_WIDTH];
+            let mut mask = [false; SIMD_WIDTH];
+            let mut results = [None; SIMD_WIDTH];
+
+            let better_toi = toi.simd_lt(best);
+            let bitmask = (hit & better_toi).bitmask();
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
+                    let part_id = *data[ii].unwrap();
+                    let round_triangle = RoundShape {
+                        inner_shape: self.shape.inner_shape.triangle(part_id),
+                        border_radius: self.shape.border_radius,
+                    };
+
+                    if let Some(result) = round_triangle.cast_local_ray_and_get_normal(
+                        self.ray,
+                        self.max_toi,
+                        self.solid,
+                    ) {
+                        results[ii] = Some((part_id, result));
+                        mask[ii] = true;
+                        weights[ii] = result.toi;
+                    }
+                }
+            }
+
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: weights[0],
+                mask: mask[0],
+                results,
+            }
+        } else {
+            SimdBestFirstVisitStatus::MaybeContinue {
+                weights: toi,
+                mask: hit,
+                results: [None; SIMD_WIDTH],
+            }
+        }
+    }
+}
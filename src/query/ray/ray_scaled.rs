@@ -0,0 +1,41 @@
+use crate::math::Vector;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::Scaled;
+use ad_trait::AD;
+
+impl<S: RayCast<T>, T: AD> RayCast<T> for Scaled<S, T> {
+    #[inline]
+    fn cast_local_ray(&self, ray: &Ray<T>, max_toi: T, solid: bool) -> Option<T> {
+        self.inner_shape
+            .cast_local_ray(&self.unscale_ray(ray), max_toi, solid)
+    }
+
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        self.inner_shape
+            .cast_local_ray_and_get_normal(&self.unscale_ray(ray), max_toi, solid)
+            .map(|mut hit| {
+                // NOTE: the normal is zero if the ray starts inside of a solid shape.
+                hit.normal = self
+                    .scale_normal(&hit.normal)
+                    .map(|n| n.into_inner())
+                    .unwrap_or_else(Vector::zeros);
+                hit
+            })
+    }
+}
+
+impl<S, T: AD> Scaled<S, T> {
+    // The time of impact is preserved because the scaling is linear.
+    fn unscale_ray(&self, ray: &Ray<T>) -> Ray<T> {
+        Ray::new(
+            self.unscale_point(&ray.origin),
+            ray.dir.component_div(&self.scale),
+        )
+    }
+}
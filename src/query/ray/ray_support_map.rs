@@ -4,6 +4,7 @@ use na::ComplexField; // for .abs()
 
 #[cfg(feature = "dim2")]
 use crate::query;
+use crate::math::Vector;
 use crate::query::gjk::{self, CSOPoint, VoronoiSimplex};
 use crate::query::{Ray, RayCast, RayIntersection};
 #[cfg(all(feature = "std", feature = "dim2"))]
@@ -11,12 +12,113 @@ use crate::shape::ConvexPolygon;
 #[cfg(all(feature = "std", feature = "dim3"))]
 use crate::shape::ConvexPolyhedron;
 use crate::shape::{Capsule, FeatureId, Segment, SupportMap};
+use crate::utils::WBasis;
+#[cfg(feature = "dim3")]
+use na::Matrix2;
 #[cfg(feature = "dim3")]
 use crate::shape::{Cone, Cylinder};
 
 use ad_trait::AD;
 
+/// Recomputes the time of impact `toi` and the normal `normal` of a ray hitting a
+/// support-mapped shape, in closed-form at the hit point.
+///
+/// The values of `toi` and `normal` are left unchanged, but their derivatives (if `T` is an AD
+/// scalar) are replaced by the ones obtained by implicit differentiation, with respect to the
+/// ray origin and direction as well as to the parameters of the shape:
+/// - the hit point is the intersection between the ray and the plane with the outward normal
+///   `normal` passing through the support point of the shape along `normal`.
+/// - the normal is the direction along which the support point of the shape is the hit point.
+///   Its derivatives are obtained by inverting the derivative of the support map in the tangent
+///   plane, estimated with finite differences. They vanish on flat faces, and are left to zero
+///   on sharp edges and vertices, where the normal isn’t differentiable.
+///
+/// Unlike the derivatives flowing through the iterations of GJK, these don’t depend on the
+/// convergence of the algorithm. Ray casts on triangles, and thus on heightfields and triangle
+/// meshes, are already computed in closed-form and don’t need this.
+pub fn differentiable_toi_and_normal_with_support_map<G: ?Sized, T: AD>(
+    shape: &G,
+    ray: &Ray<T>,
+    toi: T,
+    normal: &Vector<T>,
+) -> (T, Vector<T>)
+where
+    G: SupportMap<T>,
+{
+    let normal = normal.map(|e| T::constant(e.to_constant()));
+    let denom = normal.dot(&ray.dir);
+
+    if denom.is_zero() {
+        return (toi, normal);
+    }
+
+    let supp = shape.local_support_point(&normal);
+    let plane_toi = normal.dot(&(supp - ray.origin)) / denom;
+    let toi = T::constant(toi.to_constant()) + derivatives_only(plane_toi);
+
+    // The hit point and the support point along `normal` coincide, but the shape parameters
+    // may move them differently: the normal must rotate to make them coincide again.
+    let dpt = (ray.point_at(toi) - supp).map(derivatives_only);
+    let tangents = normal.orthonormal_basis();
+    let step = 1.0e-4;
+    let support_derivative = |tangent: &Vector<T>| -> Vector<f64> {
+        let shift = tangent * T::constant(step);
+        let dsupp = shape.local_support_point(&(normal + shift))
+            - shape.local_support_point(&(normal - shift));
+        dsupp.map(|e| e.to_constant() / (2.0 * step))
+    };
+
+    #[cfg(feature = "dim2")]
+    let dnormal = {
+        let tangent = tangents[0];
+        let jacobian = tangent
+            .map(|e| e.to_constant())
+            .dot(&support_derivative(&tangent));
+
+        if jacobian != 0.0 {
+            tangent * (tangent.dot(&dpt) / T::constant(jacobian))
+        } else {
+            Vector::zeros()
+        }
+    };
+
+    #[cfg(feature = "dim3")]
+    let dnormal = {
+        let t = tangents.map(|t| t.map(|e| e.to_constant()));
+        let d = [
+            support_derivative(&tangents[0]),
+            support_derivative(&tangents[1]),
+        ];
+        let jacobian = Matrix2::new(
+            t[0].dot(&d[0]),
+            t[0].dot(&d[1]),
+            t[1].dot(&d[0]),
+            t[1].dot(&d[1]),
+        );
+
+        match jacobian.try_inverse() {
+            Some(inv) => {
+                let (dpt0, dpt1) = (tangents[0].dot(&dpt), tangents[1].dot(&dpt));
+                let x0 = dpt0 * T::constant(inv.m11) + dpt1 * T::constant(inv.m12);
+                let x1 = dpt0 * T::constant(inv.m21) + dpt1 * T::constant(inv.m22);
+                tangents[0] * x0 + tangents[1] * x1
+            }
+            None => Vector::zeros(),
+        }
+    };
+
+    (toi, normal + dnormal)
+}
+
+/// The value `x` with its value set to zero, but its derivatives kept.
+fn derivatives_only<T: AD>(x: T) -> T {
+    x - T::constant(x.to_constant())
+}
+
 /// Cast a ray on a shape using the GJK algorithm.
+///
+/// The derivatives of the resulting time of impact and normal are computed in closed-form with
+/// [`differentiable_toi_and_normal_with_support_map`].
 pub fn local_ray_intersection_with_support_map_with_params<G: ?Sized, T: AD>(
     shape: &G,
     simplex: &mut VoronoiSimplex<T>,
@@ -49,6 +151,9 @@ where
                     |(toi, normal)| {
                         let toi = shift - toi;
                         if toi <= max_toi {
+                            let (toi, normal) = differentiable_toi_and_normal_with_support_map(
+                                shape, ray, toi, &normal,
+                            );
                             Some(RayIntersection::new(toi, normal, FeatureId::Unknown))
                         } else {
                             None
@@ -56,11 +161,20 @@ where
                     },
                 )
             } else {
+                let (toi, normal) =
+                    differentiable_toi_and_normal_with_support_map(shape, ray, toi, &normal);
                 Some(RayIntersection::new(toi, normal, FeatureId::Unknown))
             }
         })
     } else {
-        inter.map(|(toi, normal)| RayIntersection::new(toi, normal, FeatureId::Unknown))
+        inter.map(|(toi, normal)| {
+            let (toi, normal) = if toi.is_zero() {
+                (toi, normal)
+            } else {
+                differentiable_toi_and_normal_with_support_map(shape, ray, toi, &normal)
+            };
+            RayIntersection::new(toi, normal, FeatureId::Unknown)
+        })
     }
 }
 
@@ -165,8 +279,6 @@ impl<T: AD> RayCast<T> for Segment<T> {
     ) -> Option<RayIntersection<T>> {
         #[cfg(feature = "dim2")]
         {
-            use crate::math::Vector;
-
             let seg_dir = self.scaled_direction();
             let (s, t, parallel) = query::details::closest_points_line_line_parameters_eps(
                 &ray.origin,
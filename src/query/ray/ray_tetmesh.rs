@@ -0,0 +1,87 @@
+use crate::math::Vector;
+use crate::query::ray::ray_tetrahedron::clip_line_with_tetrahedron;
+use crate::query::ray::RayCompositeShapeToiAndNormalBestFirstVisitor;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, TetMesh};
+use ad_trait::AD;
+
+impl<T: AD> RayCast<T> for TetMesh<T> {
+    /// Casts a ray on this tetrahedral mesh.
+    ///
+    /// The feature of the resulting intersection identifies the face of the mesh that was
+    /// hit as `FeatureId::Face(4 * tetrahedron_id + face_id)`.
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        if let Some((tet_id, _)) = self.locate_local_point(&ray.origin) {
+            if solid {
+                return Some(RayIntersection::new(
+                    T::zero(),
+                    Vector::zeros(),
+                    FeatureId::Unknown,
+                ));
+            } else {
+                return self.cast_local_ray_from_inside(tet_id, ray, max_toi);
+            }
+        }
+
+        // The ray starts outside of the mesh: the first tetrahedron hit gives the entry point.
+        let mut visitor =
+            RayCompositeShapeToiAndNormalBestFirstVisitor::new(self, ray, max_toi, true);
+
+        self.qbvh()
+            .traverse_best_first(&mut visitor)
+            .map(|(_, (tet_id, mut res))| {
+                if let FeatureId::Face(face_id) = res.feature {
+                    res.feature = FeatureId::Face(tet_id * 4 + face_id);
+                }
+                res
+            })
+    }
+}
+
+impl<T: AD> TetMesh<T> {
+    /// Casts a ray starting inside of the `tet_id`-th tetrahedron, and finds the point
+    /// where it leaves the volume of this mesh.
+    ///
+    /// The ray goes from one tetrahedron to the next through their shared faces, until it
+    /// reaches a face on the boundary of the mesh.
+    fn cast_local_ray_from_inside(
+        &self,
+        mut tet_id: u32,
+        ray: &Ray<T>,
+        max_toi: T,
+    ) -> Option<RayIntersection<T>> {
+        // NOTE: the number of steps is bounded to avoid infinite loops
+        //       in case of degenerate tetrahedra.
+        for _ in 0..self.num_tetrahedra() {
+            let tetrahedron = self.tetrahedron(tet_id);
+            let (toi, face_id) = clip_line_with_tetrahedron(&tetrahedron, ray)?.1?;
+
+            if toi > max_toi {
+                return None;
+            }
+
+            match self.adjacent_tetrahedron(tet_id, face_id) {
+                Some(next) => tet_id = next,
+                None => {
+                    let normal = tetrahedron
+                        .face_normal(face_id)
+                        .map(|n| -n.into_inner())
+                        .unwrap_or_else(Vector::zeros);
+                    return Some(RayIntersection::new(
+                        toi,
+                        normal,
+                        FeatureId::Face(tet_id * 4 + face_id),
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,106 @@
+use crate::math::Vector;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Tetrahedron};
+use ad_trait::AD;
+
+impl<T: AD> RayCast<T> for Tetrahedron<T> {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        let (entry, exit) = clip_line_with_tetrahedron(self, ray)?;
+
+        match entry {
+            Some((toi, face)) if toi >= T::zero() => {
+                // The ray origin is outside of the tetrahedron.
+                let normal = self.face_normal(face).map(|n| n.into_inner());
+                (toi <= max_toi).then(|| {
+                    RayIntersection::new(
+                        toi,
+                        normal.unwrap_or_else(Vector::zeros),
+                        FeatureId::Face(face),
+                    )
+                })
+            }
+            _ => {
+                if exit.map(|(toi, _)| toi < T::zero()) == Some(true) {
+                    // The tetrahedron is behind the ray.
+                    return None;
+                }
+
+                if solid {
+                    let feature = exit.map(|e| FeatureId::Face(e.1)).unwrap_or_default();
+                    return Some(RayIntersection::new(T::zero(), Vector::zeros(), feature));
+                }
+
+                let (toi, face) = exit?;
+                let normal = self.face_normal(face).map(|n| -n.into_inner());
+                (toi <= max_toi).then(|| {
+                    RayIntersection::new(
+                        toi,
+                        normal.unwrap_or_else(Vector::zeros),
+                        FeatureId::Face(face),
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Clips the line supporting `ray` with the tetrahedron.
+///
+/// Returns `None` if the line doesn’t intersect the tetrahedron. Otherwise, returns the
+/// parameters along the line of the entry and exit points, together with the index of the
+/// face they lie on. The entry (resp. exit) point is `None` if the line never enters (resp.
+/// leaves) the tetrahedron, which only happens if `ray.dir` is zero.
+pub(crate) fn clip_line_with_tetrahedron<T: AD>(
+    tetrahedron: &Tetrahedron<T>,
+    ray: &Ray<T>,
+) -> Option<(Option<(T, u32)>, Option<(T, u32)>)> {
+    let mut entry: Option<(T, u32)> = None;
+    let mut exit: Option<(T, u32)> = None;
+
+    for face in 0..4 {
+        let (ia, ib, ic) = Tetrahedron::<T>::face_ids(face);
+        let a = tetrahedron.vertex(ia);
+        let mut normal = (tetrahedron.vertex(ib) - a).cross(&(tetrahedron.vertex(ic) - a));
+
+        // The sum of all the vertex indices is 6.
+        if normal.dot(&(tetrahedron.vertex(6 - ia - ib - ic) - a)) > T::zero() {
+            normal = -normal;
+        }
+
+        // The points `x` inside of the tetrahedron satisfy `normal.dot(x - a) <= 0`
+        // for all its faces.
+        let dist = normal.dot(&(ray.origin - a));
+        let denom = normal.dot(&ray.dir);
+
+        if denom.is_zero() {
+            if dist > T::zero() {
+                // The line is parallel to the face, and outside of the tetrahedron.
+                return None;
+            }
+        } else {
+            let t = -dist / denom;
+
+            if denom < T::zero() {
+                if entry.map(|e| t > e.0).unwrap_or(true) {
+                    entry = Some((t, face));
+                }
+            } else if exit.map(|e| t < e.0).unwrap_or(true) {
+                exit = Some((t, face));
+            }
+        }
+    }
+
+    if let (Some(entry), Some(exit)) = (entry, exit) {
+        if entry.0 > exit.0 {
+            return None;
+        }
+    }
+
+    Some((entry, exit))
+}
@@ -0,0 +1,130 @@
+use crate::math::Vector;
+use crate::query::{Ray, RayCast, RayIntersection};
+use crate::shape::{FeatureId, Voxels};
+use ad_trait::AD;
+
+impl<T: AD> RayCast<T> for Voxels<T> {
+    #[inline]
+    fn cast_local_ray_and_get_normal(
+        &self,
+        ray: &Ray<T>,
+        max_toi: T,
+        solid: bool,
+    ) -> Option<RayIntersection<T>> {
+        let (key_mins, key_maxs) = self.filled_keys_bounds()?;
+        let aabb = self.local_aabb();
+        let (min_t, max_t) = aabb.clip_ray_parameters(&ray)?;
+
+        if min_t > max_toi {
+            return None;
+        }
+
+        // NOTE: the key is clamped because the entry point may be slightly
+        //       outside of the Aabb due to rounding errors.
+        let mut key = self
+            .voxel_key_at_point(&ray.point_at(min_t))
+            .sup(&key_mins)
+            .inf(&key_maxs);
+        let inside = min_t.is_zero() && self.is_voxel_filled(&key);
+
+        if inside && solid {
+            let id = self.voxel_id(&key).unwrap();
+            return Some(RayIntersection::new(
+                T::zero(),
+                Vector::zeros(),
+                FeatureId::Face(id),
+            ));
+        }
+
+        /*
+         * Setup the 3D DDA traversal (Amanatides & Woo).
+         */
+        let half_size = self.voxel_size() * T::constant(0.5);
+        let center = self.voxel_center(&key);
+        let mut step = [0i32; 3];
+        let mut t_next = [T::constant(f64::MAX); 3];
+        let mut t_delta = [T::constant(f64::MAX); 3];
+        // The axis along which the ray entered the current voxel, if known.
+        let mut entry_axis = None;
+        let mut best_entry_t = -T::constant(f64::MAX);
+
+        for i in 0..3 {
+            let t_enter = if ray.dir[i] > T::zero() {
+                step[i] = 1;
+                t_next[i] = (center[i] + half_size - ray.origin[i]) / ray.dir[i];
+                t_delta[i] = self.voxel_size() / ray.dir[i];
+                (aabb.mins[i] - ray.origin[i]) / ray.dir[i]
+            } else if ray.dir[i] < T::zero() {
+                step[i] = -1;
+                t_next[i] = (center[i] - half_size - ray.origin[i]) / ray.dir[i];
+                t_delta[i] = -self.voxel_size() / ray.dir[i];
+                (aabb.maxs[i] - ray.origin[i]) / ray.dir[i]
+            } else {
+                continue;
+            };
+
+            if !min_t.is_zero() && t_enter > best_entry_t {
+                best_entry_t = t_enter;
+                entry_axis = Some(i);
+            }
+        }
+
+        let axis_normal = |axis: Option<usize>, sign: i32| {
+            let mut normal = Vector::zeros();
+            if let Some(i) = axis {
+                normal[i] = T::constant(sign as f64);
+            }
+            normal
+        };
+
+        let mut t = min_t;
+        let mut prev_key = key;
+
+        loop {
+            if self.is_voxel_filled(&key) != inside {
+                // We crossed the boundary of the filled region.
+                return if inside {
+                    let id = self.voxel_id(&prev_key).unwrap();
+                    let normal = axis_normal(entry_axis, step[entry_axis.unwrap_or(0)]);
+                    Some(RayIntersection::new(t, normal, FeatureId::Face(id)))
+                } else {
+                    let id = self.voxel_id(&key).unwrap();
+                    let normal = axis_normal(entry_axis, -step[entry_axis.unwrap_or(0)]);
+                    Some(RayIntersection::new(t, normal, FeatureId::Face(id)))
+                };
+            }
+
+            /*
+             * Find the next voxel to cast the ray on.
+             */
+            let mut axis = 0;
+            for i in 1..3 {
+                if t_next[i] < t_next[axis] {
+                    axis = i;
+                }
+            }
+
+            if t_next[axis] > max_t {
+                // The ray leaves the Aabb of the filled voxels.
+                if inside && max_t <= max_toi {
+                    let id = self.voxel_id(&key).unwrap();
+                    let normal = axis_normal(Some(axis), step[axis]);
+                    return Some(RayIntersection::new(max_t, normal, FeatureId::Face(id)));
+                }
+
+                return None;
+            }
+
+            t = t_next[axis];
+
+            if t > max_toi {
+                return None;
+            }
+
+            prev_key = key;
+            key[axis] += step[axis];
+            t_next[axis] += t_delta[axis];
+            entry_axis = Some(axis);
+        }
+    }
+}
@@ -6,12 +6,16 @@ use ad_trait::AD;
 /// Computes the separation along the given direction,
 /// between two convex shapes implementing the `SupportMap` trait.
 #[allow(dead_code)]
-pub fn support_map_support_map_compute_separation<T: AD>(
-    sm1: &impl SupportMap<T>,
-    sm2: &impl SupportMap<T>,
+pub fn support_map_support_map_compute_separation<T: AD, G1: ?Sized, G2: ?Sized>(
+    sm1: &G1,
+    sm2: &G2,
     pos12: &Isometry<T>,
     dir1: &Unit<Vector<T>>,
-) -> T {
+) -> T
+where
+    G1: SupportMap<T>,
+    G2: SupportMap<T>,
+{
     let p1 = sm1.local_support_point_toward(dir1);
     let p2 = sm2.support_point_toward(pos12, &-*dir1);
     (p2 - p1).dot(dir1)
@@ -9,6 +9,8 @@ pub use self::time_of_impact_halfspace_support_map::{
 pub use self::{
     time_of_impact_composite_shape_shape::{
         time_of_impact_composite_shape_shape, time_of_impact_shape_composite_shape,
+        time_of_impacts_composite_shape_shape, time_of_impacts_shape_composite_shape,
+        touched_parts_composite_shape_shape, TOICompositeShapeShapeAllHitsVisitor,
         TOICompositeShapeShapeBestFirstVisitor,
     },
     time_of_impact_heightfield_shape::{
@@ -1,11 +1,14 @@
 use ad_trait::AD;
 use crate::bounding_volume::SimdAabb;
 use crate::math::{Isometry, Point, Vector, SIMD_WIDTH};
-use crate::partitioning::{SimdBestFirstVisitStatus, SimdBestFirstVisitor};
+use crate::partitioning::{
+    SimdBestFirstVisitStatus, SimdBestFirstVisitor, SimdVisitStatus, SimdVisitor,
+};
 use crate::query::{QueryDispatcher, Ray, SimdRay, TOI};
 use crate::shape::{Shape, TypedSimdCompositeShape};
 use crate::utils::DefaultStorage;
 use simba::simd::{SimdBool as _, SimdValue};
+use std::cmp::Ordering;
 
 /// Time Of Impact of a composite shape with any other shape, under translational movement.
 pub fn time_of_impact_composite_shape_shape<D: ?Sized, G1: ?Sized, T: AD>(
@@ -61,6 +64,131 @@ where
     .map(|toi| toi.swapped())
 }
 
+/// Time Of Impact of each part of a composite shape touched by any other shape, under translational movement.
+///
+/// The results are sorted by increasing time of impact. Each part is tested only if its Aabb
+/// is hit by the swept Aabb of `g2`.
+pub fn time_of_impacts_composite_shape_shape<D: ?Sized, G1: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    vel12: &Vector<T>,
+    g1: &G1,
+    g2: &dyn Shape<T>,
+    max_toi: T,
+    stop_at_penetration: bool,
+) -> Vec<(G1::PartId, TOI<T>)>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    let mut visitor = TOICompositeShapeShapeAllHitsVisitor::new(
+        dispatcher,
+        pos12,
+        vel12,
+        g1,
+        g2,
+        max_toi,
+        stop_at_penetration,
+    );
+    let _ = g1.typed_qbvh().traverse_depth_first(&mut visitor);
+    let mut hits = visitor.hits;
+    hits.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal));
+    hits
+}
+
+/// Time Of Impact of any shape with each part of a composite shape it touches, under translational movement.
+///
+/// See [`time_of_impacts_composite_shape_shape`] for details.
+pub fn time_of_impacts_shape_composite_shape<D: ?Sized, G2: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    vel12: &Vector<T>,
+    g1: &dyn Shape<T>,
+    g2: &G2,
+    max_toi: T,
+    stop_at_penetration: bool,
+) -> Vec<(G2::PartId, TOI<T>)>
+where
+    D: QueryDispatcher<T>,
+    G2: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    let mut hits = time_of_impacts_composite_shape_shape(
+        dispatcher,
+        &pos12.inverse(),
+        &-pos12.inverse_transform_vector(&vel12),
+        g2,
+        g1,
+        max_toi,
+        stop_at_penetration,
+    );
+
+    for hit in &mut hits {
+        hit.1 = hit.1.swapped();
+    }
+
+    hits
+}
+
+/// The parts of a composite shape touched by any other shape, under translational movement.
+///
+/// This is the same as [`time_of_impacts_composite_shape_shape`], but only the parts
+/// touched before `max_toi` are reported, in no particular order.
+pub fn touched_parts_composite_shape_shape<D: ?Sized, G1: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    vel12: &Vector<T>,
+    g1: &G1,
+    g2: &dyn Shape<T>,
+    max_toi: T,
+) -> Vec<G1::PartId>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    let mut visitor =
+        TOICompositeShapeShapeAllHitsVisitor::new(dispatcher, pos12, vel12, g1, g2, max_toi, true);
+    let _ = g1.typed_qbvh().traverse_depth_first(&mut visitor);
+    visitor.hits.into_iter().map(|hit| hit.0).collect()
+}
+
+/// The Time Of Impact between the `part_id`-th part of `g1` and `g2`.
+fn part_time_of_impact<D: ?Sized, G1: ?Sized, T: AD>(
+    dispatcher: &D,
+    pos12: &Isometry<T>,
+    vel12: &Vector<T>,
+    g1: &G1,
+    part_id: G1::PartId,
+    g2: &dyn Shape<T>,
+    max_toi: T,
+    stop_at_penetration: bool,
+) -> Option<TOI<T>>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    let mut toi = None;
+    g1.map_untyped_part_at(part_id, |part_pos1, g1| {
+        if let Some(part_pos1) = part_pos1 {
+            toi = dispatcher
+                .time_of_impact(
+                    &part_pos1.inv_mul(pos12),
+                    &part_pos1.inverse_transform_vector(vel12),
+                    g1,
+                    g2,
+                    max_toi,
+                    stop_at_penetration,
+                )
+                .unwrap_or(None)
+                .map(|toi| toi.transform1_by(part_pos1));
+        } else {
+            toi = dispatcher
+                .time_of_impact(pos12, vel12, g1, g2, max_toi, stop_at_penetration)
+                .unwrap_or(None);
+        }
+    });
+    toi
+}
+
 /// A visitor used to find the time-of-impact between a composite shape and a shape.
 pub struct TOICompositeShapeShapeBestFirstVisitor<'a, D: ?Sized, G1: ?Sized + 'a, T: AD> {
     msum_shift: Vector<T>,
@@ -143,35 +271,16 @@ where
             for ii in 0..SIMD_WIDTH {
                 if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
                     let part_id = *data[ii].unwrap();
-                    let mut toi = None;
-                    self.g1.map_untyped_part_at(part_id, |part_pos1, g1| {
-                        if let Some(part_pos1) = part_pos1 {
-                            toi = self
-                                .dispatcher
-                                .time_of_impact(
-                                    &part_pos1.inv_mul(&self.pos12),
-                                    &part_pos1.inverse_transform_vector(self.vel12),
-                                    g1,
-                                    self.g2,
-                                    self.max_toi,
-                                    self.stop_at_penetration,
-                                )
-                                .unwrap_or(None)
-                                .map(|toi| toi.transform1_by(part_pos1));
-                        } else {
-                            toi = self
-                                .dispatcher
-                                .time_of_impact(
-                                    &self.pos12,
-                                    self.vel12,
-                                    g1,
-                                    self.g2,
-                                    self.max_toi,
-                                    self.stop_at_penetration,
-                                )
-                                .unwrap_or(None);
-                        }
-                    });
+                    let toi = part_time_of_impact(
+                        self.dispatcher,
+                        self.pos12,
+                        self.vel12,
+                        self.g1,
+                        part_id,
+                        self.g2,
+                        self.max_toi,
+                        self.stop_at_penetration,
+                    );
 
                     if let Some(toi) = toi {
                         results[ii] = Some((part_id, toi));
@@ -195,3 +304,104 @@ where
         }
     }
 }
+
+/// A visitor used to find the times-of-impact between each part of a composite shape and a shape.
+pub struct TOICompositeShapeShapeAllHitsVisitor<'a, D: ?Sized, G1: ?Sized + 'a, T: AD>
+where
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    msum_shift: Vector<T>,
+    msum_margin: Vector<T>,
+    ray: SimdRay<T>,
+
+    dispatcher: &'a D,
+    pos12: &'a Isometry<T>,
+    vel12: &'a Vector<T>,
+    g1: &'a G1,
+    g2: &'a dyn Shape<T>,
+    max_toi: T,
+    stop_at_penetration: bool,
+    /// The parts hit so far, with their time of impact.
+    pub hits: Vec<(G1::PartId, TOI<T>)>,
+}
+
+impl<'a, D: ?Sized, G1: ?Sized, T: AD> TOICompositeShapeShapeAllHitsVisitor<'a, D, G1, T>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    /// Creates a new visitor used to find the times-of-impact between each part of a composite shape and a shape.
+    pub fn new(
+        dispatcher: &'a D,
+        pos12: &'a Isometry<T>,
+        vel12: &'a Vector<T>,
+        g1: &'a G1,
+        g2: &'a dyn Shape<T>,
+        max_toi: T,
+        stop_at_penetration: bool,
+    ) -> Self {
+        let ls_aabb2 = g2.compute_aabb(pos12);
+        let ray = Ray::new(Point::origin(), *vel12);
+
+        TOICompositeShapeShapeAllHitsVisitor {
+            dispatcher,
+            msum_shift: Vector::splat(-ls_aabb2.center().coords),
+            msum_margin: Vector::splat(ls_aabb2.half_extents()),
+            ray: SimdRay::splat(ray),
+            pos12,
+            vel12,
+            g1,
+            g2,
+            max_toi,
+            stop_at_penetration,
+            hits: vec![],
+        }
+    }
+}
+
+impl<'a, D: ?Sized, G1: ?Sized, T: AD> SimdVisitor<G1::PartId, SimdAabb<T>>
+    for TOICompositeShapeShapeAllHitsVisitor<'a, D, G1, T>
+where
+    D: QueryDispatcher<T>,
+    G1: TypedSimdCompositeShape<T, QbvhStorage = DefaultStorage>,
+{
+    #[inline]
+    fn visit(
+        &mut self,
+        bv: &SimdAabb<T>,
+        data: Option<[Option<&G1::PartId>; SIMD_WIDTH]>,
+    ) -> SimdVisitStatus {
+        // Compute the minkowski sum of the two Aabbs.
+        let msum = SimdAabb {
+            mins: bv.mins + self.msum_shift + (-self.msum_margin),
+            maxs: bv.maxs + self.msum_shift + self.msum_margin,
+        };
+        let (mask, _) = msum.cast_local_ray(&self.ray, self.max_toi);
+
+        if let Some(data) = data {
+            let bitmask = mask.bitmask();
+
+            for ii in 0..SIMD_WIDTH {
+                if (bitmask & (1 << ii)) != 0 && data[ii].is_some() {
+                    let part_id = *data[ii].unwrap();
+                    let toi = part_time_of_impact(
+                        self.dispatcher,
+                        self.pos12,
+                        self.vel12,
+                        self.g1,
+                        part_id,
+                        self.g2,
+                        self.max_toi,
+                        self.stop_at_penetration,
+                    );
+
+                    if let Some(toi) = toi {
+                        self.hits.push((part_id, toi));
+                    }
+                }
+            }
+        }
+
+        SimdVisitStatus::MaybeContinue(mask)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::math::{Isometry, Point, Vector};
+use crate::shape::SupportMap;
+use na::Unit;
+
+use ad_trait::AD;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[derive(Copy, Clone, Debug)]
+/// The Minkowski sum of two convex shapes.
+///
+/// This is the set of all the points `a + b` where `a` belongs to `shape1` and `b`
+/// belongs to `shape2` transformed by `pos12`.
+pub struct MinkowskiSum<A, B, T: AD> {
+    /// The position of the second shape relative to the first one.
+    pub pos12: Isometry<T>,
+    /// The first shape involved in the Minkowski sum.
+    pub shape1: A,
+    /// The second shape involved in the Minkowski sum.
+    pub shape2: B,
+}
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[derive(Copy, Clone, Debug)]
+/// The Minkowski difference of two convex shapes.
+///
+/// This is the set of all the points `a - b` where `a` belongs to `shape1` and `b`
+/// belongs to `shape2` transformed by `pos12`. The configuration-space obstacle
+/// of `shape2` wrt. `shape1` is the Minkowski difference of `shape1` and `shape2`.
+pub struct MinkowskiDifference<A, B, T: AD> {
+    /// The position of the second shape relative to the first one.
+    pub pos12: Isometry<T>,
+    /// The first shape involved in the Minkowski difference.
+    pub shape1: A,
+    /// The second shape involved in the Minkowski difference.
+    pub shape2: B,
+}
+
+impl<A, B, T: AD> MinkowskiSum<A, B, T> {
+    /// Creates the Minkowski sum of `shape1` and `shape2` transformed by `pos12`.
+    pub fn new(pos12: Isometry<T>, shape1: A, shape2: B) -> Self {
+        Self {
+            pos12,
+            shape1,
+            shape2,
+        }
+    }
+}
+
+impl<A, B, T: AD> MinkowskiDifference<A, B, T> {
+    /// Creates the Minkowski difference of `shape1` and `shape2` transformed by `pos12`.
+    pub fn new(pos12: Isometry<T>, shape1: A, shape2: B) -> Self {
+        Self {
+            pos12,
+            shape1,
+            shape2,
+        }
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> SupportMap<T> for MinkowskiSum<A, B, T> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<T>) -> Point<T> {
+        self.shape1.local_support_point(dir) + self.shape2.support_point(&self.pos12, dir).coords
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<T>>) -> Point<T> {
+        self.shape1.local_support_point_toward(dir)
+            + self.shape2.support_point_toward(&self.pos12, dir).coords
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> SupportMap<T> for MinkowskiDifference<A, B, T> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<T>) -> Point<T> {
+        self.shape1.local_support_point(dir) - self.shape2.support_point(&self.pos12, &-dir).coords
+    }
+
+    #[inline]
+    fn local_support_point_toward(&self, dir: &Unit<Vector<T>>) -> Point<T> {
+        self.shape1.local_support_point_toward(dir)
+            - self.shape2.support_point_toward(&self.pos12, &-*dir).coords
+    }
+}
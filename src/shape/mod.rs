@@ -7,8 +7,10 @@ pub use self::composite_shape::TypedSimdCompositeShape;
 pub use self::cuboid::Cuboid;
 pub use self::feature_id::{FeatureId, PackedFeatureId};
 pub use self::half_space::HalfSpace;
+pub use self::minkowski_sum::{MinkowskiDifference, MinkowskiSum};
 pub use self::polygonal_feature_map::PolygonalFeatureMap;
 pub use self::round_shape::RoundShape;
+pub use self::scaled::Scaled;
 pub use self::segment::{Segment, SegmentPointLocation};
 #[cfg(feature = "serde-serialize")]
 pub(crate) use self::shape::DeserializableTypedShape;
@@ -22,7 +24,7 @@ use ad_trait::AD;
 #[cfg(feature = "std")]
 pub use self::{
     composite_shape::SimdCompositeShape, compound::Compound, polyline::Polyline,
-    shared_shape::SharedShape,
+    round_trimesh::RoundTriMesh, shared_shape::SharedShape,
 };
 
 #[cfg(feature = "dim2")]
@@ -45,7 +47,16 @@ pub use self::heightfield3::*;
 #[cfg(feature = "dim3")]
 pub use self::polygonal_feature3d::PolygonalFeature;
 #[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+pub use self::round_heightfield::RoundHeightField;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+pub use self::tetmesh::TetMesh;
+#[cfg(feature = "dim3")]
 pub use self::tetrahedron::{Tetrahedron, TetrahedronPointLocation};
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+pub use self::voxels::Voxels;
 pub use self::trimesh::*;
 pub use self::trimesh_storage::TriMeshStorage;
 
@@ -76,9 +87,16 @@ pub mod composite_shape;
 mod compound;
 mod cuboid;
 mod half_space;
+mod minkowski_sum;
 #[cfg(feature = "std")]
 mod polyline;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod round_heightfield;
 mod round_shape;
+#[cfg(feature = "std")]
+mod round_trimesh;
+mod scaled;
 mod segment;
 #[doc(hidden)]
 pub mod shape;
@@ -105,7 +123,13 @@ mod heightfield3;
 mod polygonal_feature3d;
 mod polygonal_feature_map;
 #[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod tetmesh;
+#[cfg(feature = "dim3")]
 mod tetrahedron;
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+mod voxels;
 pub(crate) mod trimesh;
 // TODO: move this elsewhere?
 mod feature_id;
@@ -0,0 +1,24 @@
+use crate::shape::HeightField;
+use ad_trait::AD;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+/// A heightfield dilated by a sphere (so it has round corners).
+///
+/// This is the union of all the triangles of `inner_shape` dilated by `border_radius`.
+pub struct RoundHeightField<T: AD> {
+    /// The heightfield being rounded.
+    pub inner_shape: HeightField<T>,
+    /// The radius of the rounded border.
+    pub border_radius: T,
+}
+
+impl<T: AD> RoundHeightField<T> {
+    /// Creates a heightfield with round corners.
+    pub fn new(inner_shape: HeightField<T>, border_radius: T) -> Self {
+        Self {
+            inner_shape,
+            border_radius,
+        }
+    }
+}
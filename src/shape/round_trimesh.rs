@@ -0,0 +1,28 @@
+use crate::shape::TriMesh;
+use ad_trait::AD;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+/// A triangle mesh dilated by a sphere (so it has round corners).
+///
+/// This is the union of all the triangles of `inner_shape` dilated by `border_radius`.
+///
+/// Its mass properties ignore the border radius: they are the mass properties of `inner_shape`.
+/// This is an underestimate of the exact mass properties of the dilated mesh, which is
+/// acceptable as long as `border_radius` is small relative to the mesh.
+pub struct RoundTriMesh<T: AD> {
+    /// The triangle mesh being rounded.
+    pub inner_shape: TriMesh<T>,
+    /// The radius of the rounded border.
+    pub border_radius: T,
+}
+
+impl<T: AD> RoundTriMesh<T> {
+    /// Creates a triangle mesh with round corners.
+    pub fn new(inner_shape: TriMesh<T>, border_radius: T) -> Self {
+        Self {
+            inner_shape,
+            border_radius,
+        }
+    }
+}
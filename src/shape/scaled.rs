@@ -0,0 +1,67 @@
+use crate::math::{Point, Vector, DEFAULT_EPSILON};
+use crate::shape::SupportMap;
+use na::Unit;
+
+use ad_trait::AD;
+
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[derive(Copy, Clone, Debug)]
+#[repr(C)]
+/// A shape scaled non-uniformly along the axes of its local coordinate frame.
+///
+/// None of the scale factors should be zero. A negative scale factor mirrors the
+/// inner shape along the corresponding axis.
+pub struct Scaled<S, T: AD> {
+    /// The shape being scaled.
+    pub inner_shape: S,
+    /// The scale factor along each local coordinate axis.
+    pub scale: Vector<T>,
+}
+
+impl<S, T: AD> Scaled<S, T> {
+    /// Creates a new shape by scaling `inner_shape` by `scale` along each local axis.
+    pub fn new(inner_shape: S, scale: Vector<T>) -> Self {
+        Self { inner_shape, scale }
+    }
+
+    /// Maps a point from the local-space of `self` to the local-space of the inner shape.
+    #[inline]
+    pub fn unscale_point(&self, pt: &Point<T>) -> Point<T> {
+        pt.coords.component_div(&self.scale).into()
+    }
+
+    /// Maps a point from the local-space of the inner shape to the local-space of `self`.
+    #[inline]
+    pub fn scale_point(&self, pt: &Point<T>) -> Point<T> {
+        pt.coords.component_mul(&self.scale).into()
+    }
+
+    /// Maps a normal of the inner shape to the corresponding normal of `self`.
+    ///
+    /// Normals are transformed by the inverse-transpose of the scaling, i.e., they
+    /// are divided by the scale factors.
+    #[inline]
+    pub fn scale_normal(&self, normal: &Vector<T>) -> Option<Unit<Vector<T>>> {
+        Unit::try_new(
+            normal.component_div(&self.scale),
+            T::constant(DEFAULT_EPSILON),
+        )
+    }
+}
+
+impl<S: SupportMap<T>, T: AD> SupportMap<T> for Scaled<S, T> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<T>) -> Point<T> {
+        // The support point of the scaled shape is the scaled support point
+        // of the inner shape along the scaled direction.
+        let inner_pt = self
+            .inner_shape
+            .local_support_point(&dir.component_mul(&self.scale));
+        self.scale_point(&inner_pt)
+    }
+}
@@ -5,17 +5,20 @@ use crate::query::{PointQuery, RayCast};
 #[cfg(feature = "serde-serialize")]
 use crate::shape::SharedShape;
 #[cfg(feature = "std")]
-use crate::shape::{composite_shape::SimdCompositeShape, Compound, HeightField, Polyline, TriMesh};
 use crate::shape::{
-    Ball, Capsule, Cuboid, FeatureId, HalfSpace, PolygonalFeatureMap, RoundCuboid, RoundShape,
-    RoundTriangle, Segment, SupportMap, Triangle,
+    composite_shape::SimdCompositeShape, Compound, HeightField, Polyline, RoundTriMesh, TriMesh,
+};
+use crate::shape::{
+    Ball, Capsule, Cuboid, FeatureId, HalfSpace, MinkowskiDifference, MinkowskiSum,
+    PolygonalFeatureMap, RoundCuboid, RoundShape, RoundTriangle, Scaled, Segment, SupportMap,
+    Triangle,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, Cylinder, RoundCone, RoundCylinder};
+use crate::shape::{Cone, Cylinder, RoundCone, RoundCylinder, Tetrahedron};
 
 #[cfg(feature = "dim3")]
 #[cfg(feature = "std")]
-use crate::shape::{ConvexPolyhedron, RoundConvexPolyhedron};
+use crate::shape::{ConvexPolyhedron, RoundConvexPolyhedron, RoundHeightField, TetMesh, Voxels};
 
 #[cfg(feature = "dim2")]
 #[cfg(feature = "std")]
@@ -67,10 +70,11 @@ pub enum ShapeType {
     RoundCuboid,
     /// A triangle with rounded corners.
     RoundTriangle,
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
+    /// A triangle-mesh with rounded corners.
+    RoundTriMesh,
+    /// An heightfield with rounded corners.
+    #[cfg(feature = "dim3")]
+    RoundHeightField,
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder,
@@ -83,6 +87,21 @@ pub enum ShapeType {
     /// A convex polygon with rounded corners.
     #[cfg(feature = "dim2")]
     RoundConvexPolygon,
+    /// A set of voxels laid out on a regular grid.
+    #[cfg(feature = "dim3")]
+    Voxels,
+    /// A tetrahedron shape.
+    #[cfg(feature = "dim3")]
+    Tetrahedron,
+    /// A tetrahedral mesh shape.
+    #[cfg(feature = "dim3")]
+    TetMesh,
+    /// A convex shape scaled non-uniformly.
+    Scaled,
+    /// The Minkowski sum of two convex shapes.
+    MinkowskiSum,
+    /// The Minkowski difference of two convex shapes.
+    MinkowskiDifference,
     /// A custom user-defined shape.
     Custom,
 }
@@ -134,10 +153,13 @@ pub enum TypedShape<'a, T: AD> {
     RoundCuboid(&'a RoundCuboid<T>),
     /// A triangle with rounded corners.
     RoundTriangle(&'a RoundTriangle<T>),
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
+    /// A triangle-mesh with rounded corners.
+    #[cfg(feature = "std")]
+    RoundTriMesh(&'a RoundTriMesh<T>),
+    /// An heightfield with rounded corners.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    RoundHeightField(&'a RoundHeightField<T>),
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder(&'a RoundCylinder<T>),
@@ -152,8 +174,50 @@ pub enum TypedShape<'a, T: AD> {
     #[cfg(feature = "dim2")]
     #[cfg(feature = "std")]
     RoundConvexPolygon(&'a RoundConvexPolygon),
+    /// A set of voxels laid out on a regular grid.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    Voxels(&'a Voxels<T>),
+    /// A tetrahedron shape.
+    #[cfg(feature = "dim3")]
+    Tetrahedron(&'a Tetrahedron<T>),
+    /// A tetrahedral mesh shape.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    TetMesh(&'a TetMesh<T>),
     /// A custom user-defined shape with a type identified by a number.
     Custom(u32),
+    // NOTE: the following shapes are generic over the shapes they are made of, so they
+    //       can’t be serialized as typed shapes. They are declared after `Custom` so they
+    //       don’t change the serialized representation of the other variants.
+    /// A convex shape scaled non-uniformly.
+    #[cfg_attr(feature = "serde-serialize", serde(skip_serializing))]
+    Scaled {
+        /// The shape being scaled.
+        inner_shape: &'a dyn Shape<T>,
+        /// The scaling factors along each local axis.
+        scale: Vector<T>,
+    },
+    /// The Minkowski sum of two convex shapes.
+    #[cfg_attr(feature = "serde-serialize", serde(skip_serializing))]
+    MinkowskiSum {
+        /// The position of the second shape relative to the first one.
+        pos12: Isometry<T>,
+        /// The first shape of the sum.
+        shape1: &'a dyn Shape<T>,
+        /// The second shape of the sum.
+        shape2: &'a dyn Shape<T>,
+    },
+    /// The Minkowski difference of two convex shapes.
+    #[cfg_attr(feature = "serde-serialize", serde(skip_serializing))]
+    MinkowskiDifference {
+        /// The position of the second shape relative to the first one.
+        pos12: Isometry<T>,
+        /// The shape the second one is subtracted from.
+        shape1: &'a dyn Shape<T>,
+        /// The subtracted shape.
+        shape2: &'a dyn Shape<T>,
+    },
 }
 
 #[cfg(feature = "serde-serialize")]
@@ -204,10 +268,13 @@ pub(crate) enum DeserializableTypedShape<T: AD> {
     RoundCuboid(RoundCuboid<T>),
     /// A triangle with rounded corners.
     RoundTriangle(RoundTriangle<T>),
-    // /// A triangle-mesh with rounded corners.
-    // RoundedTriMesh,
-    // /// An heightfield with rounded corners.
-    // RoundedHeightField,
+    /// A triangle-mesh with rounded corners.
+    #[cfg(feature = "std")]
+    RoundTriMesh(RoundTriMesh<T>),
+    /// An heightfield with rounded corners.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    RoundHeightField(RoundHeightField<T>),
     /// A cylinder with rounded corners.
     #[cfg(feature = "dim3")]
     RoundCylinder(RoundCylinder<T>),
@@ -222,6 +289,17 @@ pub(crate) enum DeserializableTypedShape<T: AD> {
     #[cfg(feature = "dim2")]
     #[cfg(feature = "std")]
     RoundConvexPolygon(RoundConvexPolygon),
+    /// A set of voxels laid out on a regular grid.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    Voxels(Voxels<T>),
+    /// A tetrahedron shape.
+    #[cfg(feature = "dim3")]
+    Tetrahedron(Tetrahedron<T>),
+    /// A tetrahedral mesh shape.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    TetMesh(TetMesh<T>),
     /// A custom user-defined shape identified by a number.
     Custom(u32),
 }
@@ -257,6 +335,11 @@ impl<T: AD> DeserializableTypedShape<T> {
             DeserializableTypedShape::Cone(s) => Some(SharedShape::new(s)),
             DeserializableTypedShape::RoundCuboid(s) => Some(SharedShape::new(s)),
             DeserializableTypedShape::RoundTriangle(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "std")]
+            DeserializableTypedShape::RoundTriMesh(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "std")]
+            DeserializableTypedShape::RoundHeightField(s) => Some(SharedShape::new(s)),
             #[cfg(feature = "dim3")]
             DeserializableTypedShape::RoundCylinder(s) => Some(SharedShape::new(s)),
             #[cfg(feature = "dim3")]
@@ -267,6 +350,14 @@ impl<T: AD> DeserializableTypedShape<T> {
             #[cfg(feature = "dim2")]
             #[cfg(feature = "std")]
             DeserializableTypedShape::RoundConvexPolygon(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "std")]
+            DeserializableTypedShape::Voxels(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            DeserializableTypedShape::Tetrahedron(s) => Some(SharedShape::new(s)),
+            #[cfg(feature = "dim3")]
+            #[cfg(feature = "std")]
+            DeserializableTypedShape::TetMesh(s) => Some(SharedShape::new(s)),
             DeserializableTypedShape::Custom(_) => None,
         }
     }
@@ -487,6 +578,30 @@ impl<A: AD> dyn Shape<A> {
         self.downcast_mut()
     }
 
+    /// Converts this abstract shape to a round triangle mesh, if it is one.
+    #[cfg(feature = "std")]
+    pub fn as_round_trimesh(&self) -> Option<&RoundTriMesh<A>> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable round triangle mesh, if it is one.
+    #[cfg(feature = "std")]
+    pub fn as_round_trimesh_mut(&mut self) -> Option<&mut RoundTriMesh<A>> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a round heightfield, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_round_heightfield(&self) -> Option<&RoundHeightField<A>> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable round heightfield, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_round_heightfield_mut(&mut self) -> Option<&mut RoundHeightField<A>> {
+        self.downcast_mut()
+    }
+
     /// Converts this abstract shape to a convex polygon, if it is one.
     #[cfg(feature = "dim2")]
     #[cfg(feature = "std")]
@@ -580,6 +695,43 @@ impl<A: AD> dyn Shape<A> {
     pub fn as_round_convex_polyhedron_mut(&mut self) -> Option<&mut RoundConvexPolyhedron<A>> {
         self.downcast_mut()
     }
+
+    /// Converts this abstract shape to a set of voxels, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_voxels(&self) -> Option<&Voxels<A>> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable set of voxels, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_voxels_mut(&mut self) -> Option<&mut Voxels<A>> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a tetrahedron, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_tetrahedron(&self) -> Option<&Tetrahedron<A>> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable tetrahedron, if it is one.
+    #[cfg(feature = "dim3")]
+    pub fn as_tetrahedron_mut(&mut self) -> Option<&mut Tetrahedron<A>> {
+        self.downcast_mut()
+    }
+
+    /// Converts this abstract shape to a tetrahedral mesh, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_tetmesh(&self) -> Option<&TetMesh<A>> {
+        self.downcast_ref()
+    }
+    /// Converts this abstract shape to a mutable tetrahedral mesh, if it is one.
+    #[cfg(feature = "dim3")]
+    #[cfg(feature = "std")]
+    pub fn as_tetmesh_mut(&mut self) -> Option<&mut TetMesh<A>> {
+        self.downcast_mut()
+    }
 }
 
 impl<T: AD> Shape<T> for Ball<T> {
@@ -1053,6 +1205,156 @@ impl<T: AD> Shape<T> for HeightField<T> {
     }
 }
 
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> Shape<T> for Voxels<T> {
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: T) -> MassProperties<T> {
+        MassProperties::from_voxels(density, self)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Voxels
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::Voxels(self)
+    }
+
+    fn ccd_thickness(&self) -> T {
+        self.voxel_size() * T::constant(0.5)
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        T::constant(f64::frac_pi_2())
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<T: AD> Shape<T> for Tetrahedron<T> {
+    #[cfg(feature = "std")]
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, _density: T) -> MassProperties<T> {
+        #[cfg(feature = "std")]
+        return MassProperties::from_tetmesh(
+            _density,
+            &[self.a, self.b, self.c, self.d],
+            &[[0, 1, 2, 3]],
+        );
+        #[cfg(not(feature = "std"))]
+        return MassProperties::zero();
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Tetrahedron
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::Tetrahedron(self)
+    }
+
+    fn ccd_thickness(&self) -> T {
+        // The smallest height of the tetrahedron.
+        let max_area = (0..4)
+            .map(|i| self.face(i).area())
+            .fold(T::zero(), |a, b| a.max(b));
+
+        if max_area.is_zero() {
+            T::zero()
+        } else {
+            self.volume() * T::constant(3.0) / max_area
+        }
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        T::constant(f64::frac_pi_4())
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap<T>> {
+        Some(self as &dyn SupportMap<T>)
+    }
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> Shape<T> for TetMesh<T> {
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        *self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: T) -> MassProperties<T> {
+        MassProperties::from_tetmesh(density, self.vertices(), self.indices())
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::TetMesh
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::TetMesh(self)
+    }
+
+    fn ccd_thickness(&self) -> T {
+        T::zero()
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        // TODO: the value should depend on the dihedral angles
+        // of the boundary of the tetrahedral mesh.
+        T::constant(f64::frac_pi_4())
+    }
+
+    fn as_composite_shape(&self) -> Option<&dyn SimdCompositeShape<T>> {
+        Some(self as &dyn SimdCompositeShape<T>)
+    }
+}
+
 #[cfg(feature = "dim2")]
 #[cfg(feature = "std")]
 impl<T: AD> Shape<T> for ConvexPolygon<T> {
@@ -1330,6 +1632,155 @@ impl<T: AD> Shape<T> for HalfSpace<T> {
     }
 }
 
+impl<S, T: AD> Shape<T> for Scaled<S, T>
+where
+    S: Shape<T> + SupportMap<T> + Clone,
+{
+    #[cfg(feature = "std")]
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        self.local_aabb()
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.local_bounding_sphere()
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.aabb(position)
+    }
+
+    fn mass_properties(&self, density: T) -> MassProperties<T> {
+        // NOTE: in 2D, the mass properties of the inner shape can only be scaled uniformly,
+        //       so they are computed from a polygonal approximation otherwise.
+        #[cfg(feature = "dim2")]
+        if self.scale.x.abs() != self.scale.y.abs() {
+            #[cfg(feature = "std")]
+            return MassProperties::from_convex_polygon(density, &self.to_polyline(64));
+            #[cfg(not(feature = "std"))]
+            return MassProperties::zero();
+        }
+
+        self.inner_shape
+            .mass_properties(density)
+            .scaled(&self.scale)
+    }
+
+    fn is_convex(&self) -> bool {
+        self.inner_shape.is_convex()
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::Scaled
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::Scaled {
+            inner_shape: &self.inner_shape,
+            scale: self.scale,
+        }
+    }
+
+    fn ccd_thickness(&self) -> T {
+        self.inner_shape.ccd_thickness() * self.scale.abs().min()
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        self.inner_shape.ccd_angular_thickness()
+    }
+
+    fn as_support_map(&self) -> Option<&dyn SupportMap<T>> {
+        Some(self as &dyn SupportMap<T>)
+    }
+
+    fn feature_normal_at_point(
+        &self,
+        feature: FeatureId,
+        point: &Point<T>,
+    ) -> Option<Unit<Vector<T>>> {
+        self.inner_shape
+            .feature_normal_at_point(feature, &self.unscale_point(point))
+            .and_then(|normal| self.scale_normal(&normal))
+    }
+}
+
+macro_rules! impl_shape_for_minkowski_shape(
+    ($($Shape: ident);*) => {$(
+        impl<A, B, T: AD> Shape<T> for $Shape<A, B, T>
+        where
+            A: Shape<T> + SupportMap<T> + Clone,
+            B: Shape<T> + SupportMap<T> + Clone,
+        {
+            #[cfg(feature = "std")]
+            fn clone_box(&self) -> Box<dyn Shape<T>> {
+                Box::new(self.clone())
+            }
+
+            fn compute_local_aabb(&self) -> Aabb<T> {
+                self.local_aabb()
+            }
+
+            fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+                self.local_bounding_sphere()
+            }
+
+            fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+                self.aabb(position)
+            }
+
+            fn mass_properties(&self, density: T) -> MassProperties<T> {
+                // NOTE: the mass properties are computed from a polyhedral approximation.
+                #[cfg(feature = "dim2")]
+                #[cfg(feature = "std")]
+                return MassProperties::from_convex_polygon(density, &self.to_polyline(64));
+                #[cfg(feature = "dim3")]
+                #[cfg(feature = "std")]
+                return {
+                    let (vertices, indices) = self.to_trimesh(32, 16);
+                    MassProperties::from_convex_polyhedron(density, &vertices, &indices)
+                };
+                #[cfg(not(feature = "std"))]
+                return MassProperties::zero();
+            }
+
+            fn is_convex(&self) -> bool {
+                true
+            }
+
+            fn shape_type(&self) -> ShapeType {
+                ShapeType::$Shape
+            }
+
+            fn as_typed_shape(&self) -> TypedShape<T> {
+                TypedShape::$Shape {
+                    pos12: self.pos12,
+                    shape1: &self.shape1,
+                    shape2: &self.shape2,
+                }
+            }
+
+            fn ccd_thickness(&self) -> T {
+                self.shape1.ccd_thickness() + self.shape2.ccd_thickness()
+            }
+
+            fn ccd_angular_thickness(&self) -> T {
+                self.shape1
+                    .ccd_angular_thickness()
+                    .min(self.shape2.ccd_angular_thickness())
+            }
+
+            fn as_support_map(&self) -> Option<&dyn SupportMap<T>> {
+                Some(self as &dyn SupportMap<T>)
+            }
+        }
+    )*}
+);
+
+impl_shape_for_minkowski_shape!(MinkowskiSum; MinkowskiDifference);
+
 macro_rules! impl_shape_for_round_shape(
     ($($S: ty, $Tag: ident);*) => {$(
         impl<T: AD> Shape<T> for RoundShape<$S, T> {
@@ -1404,3 +1855,94 @@ impl_shape_for_round_shape!(
 #[cfg(feature = "dim3")]
 #[cfg(feature = "std")]
 impl_shape_for_round_shape!(ConvexPolyhedron<T>, RoundConvexPolyhedron);
+
+#[cfg(feature = "std")]
+impl<T: AD> Shape<T> for RoundTriMesh<T> {
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        self.inner_shape.local_aabb().loosened(self.border_radius)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.inner_shape
+            .local_bounding_sphere()
+            .loosened(self.border_radius)
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.inner_shape.aabb(position).loosened(self.border_radius)
+    }
+
+    fn mass_properties(&self, density: T) -> MassProperties<T> {
+        // NOTE: the border radius is ignored, see the `RoundTriMesh` documentation.
+        self.inner_shape.mass_properties(density)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundTriMesh
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::RoundTriMesh(self)
+    }
+
+    fn ccd_thickness(&self) -> T {
+        self.inner_shape.ccd_thickness() + self.border_radius
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        // The fact that the shape is round doesn't change anything
+        // to the CCD angular thickness.
+        self.inner_shape.ccd_angular_thickness()
+    }
+
+    // NOTE: this isn't a composite shape because its parts are the
+    //       dilated triangles, not the triangles of `inner_shape`.
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "std")]
+impl<T: AD> Shape<T> for RoundHeightField<T> {
+    fn clone_box(&self) -> Box<dyn Shape<T>> {
+        Box::new(self.clone())
+    }
+
+    fn compute_local_aabb(&self) -> Aabb<T> {
+        self.inner_shape.local_aabb().loosened(self.border_radius)
+    }
+
+    fn compute_local_bounding_sphere(&self) -> BoundingSphere<T> {
+        self.inner_shape
+            .local_bounding_sphere()
+            .loosened(self.border_radius)
+    }
+
+    fn compute_aabb(&self, position: &Isometry<T>) -> Aabb<T> {
+        self.inner_shape.aabb(position).loosened(self.border_radius)
+    }
+
+    fn mass_properties(&self, density: T) -> MassProperties<T> {
+        MassProperties::from_round_heightfield(density, &self.inner_shape, self.border_radius)
+    }
+
+    fn shape_type(&self) -> ShapeType {
+        ShapeType::RoundHeightField
+    }
+
+    fn as_typed_shape(&self) -> TypedShape<T> {
+        TypedShape::RoundHeightField(self)
+    }
+
+    fn ccd_thickness(&self) -> T {
+        self.inner_shape.ccd_thickness() + self.border_radius
+    }
+
+    fn ccd_angular_thickness(&self) -> T {
+        // The fact that the shape is round doesn't change anything
+        // to the CCD angular thickness.
+        self.inner_shape.ccd_angular_thickness()
+    }
+}
@@ -4,11 +4,14 @@ use crate::shape::ConvexPolygon;
 #[cfg(feature = "serde-serialize")]
 use crate::shape::DeserializableTypedShape;
 use crate::shape::{
-    Ball, Capsule, Compound, Cuboid, HalfSpace, HeightField, Polyline, RoundShape, Segment, Shape,
-    TriMesh, TriMeshFlags, Triangle,
+    Ball, Capsule, Compound, Cuboid, HalfSpace, HeightField, MinkowskiDifference, MinkowskiSum,
+    Polyline, RoundShape, RoundTriMesh, Scaled, Segment, Shape, SupportMap, TriMesh, TriMeshFlags,
+    Triangle,
 };
 #[cfg(feature = "dim3")]
-use crate::shape::{Cone, ConvexPolyhedron, Cylinder};
+use crate::shape::{
+    Cone, ConvexPolyhedron, Cylinder, RoundHeightField, TetMesh, Tetrahedron, Voxels,
+};
 use crate::transformation::vhacd::{VHACDParameters, VHACD};
 use na::Unit;
 use std::ops::Deref;
@@ -177,6 +180,34 @@ impl<T: AD> SharedShape<T> {
         }))
     }
 
+    /// Initializes a convex shape scaled non-uniformly along its local coordinate axes.
+    pub fn scaled<S>(inner_shape: S, scale: Vector<T>) -> Self
+    where
+        S: Shape<T> + SupportMap<T> + Clone,
+    {
+        SharedShape(Arc::new(Scaled::new(inner_shape, scale)))
+    }
+
+    /// Initializes the Minkowski sum of two convex shapes, the second one being
+    /// positioned by `pos12` relative to the first one.
+    pub fn minkowski_sum<A, B>(pos12: Isometry<T>, shape1: A, shape2: B) -> Self
+    where
+        A: Shape<T> + SupportMap<T> + Clone,
+        B: Shape<T> + SupportMap<T> + Clone,
+    {
+        SharedShape(Arc::new(MinkowskiSum::new(pos12, shape1, shape2)))
+    }
+
+    /// Initializes the Minkowski difference of two convex shapes, the second one being
+    /// positioned by `pos12` relative to the first one.
+    pub fn minkowski_difference<A, B>(pos12: Isometry<T>, shape1: A, shape2: B) -> Self
+    where
+        A: Shape<T> + SupportMap<T> + Clone,
+        B: Shape<T> + SupportMap<T> + Clone,
+    {
+        SharedShape(Arc::new(MinkowskiDifference::new(pos12, shape1, shape2)))
+    }
+
     /// Initializes a polyline shape defined by its vertex and index buffers.
     ///
     /// If no index buffer is provided, the polyline is assumed to describe a line strip.
@@ -189,6 +220,18 @@ impl<T: AD> SharedShape<T> {
         SharedShape(Arc::new(TriMesh::new(vertices, indices)))
     }
 
+    /// Initializes a triangle mesh shape with round corners defined by its vertex and index buffers.
+    pub fn round_trimesh(
+        vertices: Vec<Point<T>>,
+        indices: Vec<[u32; 3]>,
+        border_radius: T,
+    ) -> Self {
+        SharedShape(Arc::new(RoundTriMesh::new(
+            TriMesh::new(vertices, indices),
+            border_radius,
+        )))
+    }
+
     /// Initializes a triangle mesh shape defined by its vertex and index buffers and
     /// pre-processing flags.
     pub fn trimesh_with_flags(
@@ -361,6 +404,51 @@ impl<T: AD> SharedShape<T> {
     pub fn heightfield(heights: na::DMatrix<T>, scale: Vector<T>) -> Self {
         SharedShape(Arc::new(HeightField::new(heights, scale)))
     }
+
+    /// Initializes an heightfield shape with round corners on the x-z plane defined by its set
+    /// of height and a scale factor along each coordinate axis.
+    #[cfg(feature = "dim3")]
+    pub fn round_heightfield(heights: na::DMatrix<T>, scale: Vector<T>, border_radius: T) -> Self {
+        SharedShape(Arc::new(RoundHeightField::new(
+            HeightField::new(heights, scale),
+            border_radius,
+        )))
+    }
+
+    /// Initializes a tetrahedron shape.
+    #[cfg(feature = "dim3")]
+    pub fn tetrahedron(a: Point<T>, b: Point<T>, c: Point<T>, d: Point<T>) -> Self {
+        SharedShape(Arc::new(Tetrahedron::new(a, b, c, d)))
+    }
+
+    /// Initializes a tetrahedral mesh shape defined by its vertex and index buffers.
+    #[cfg(feature = "dim3")]
+    pub fn tetmesh(vertices: Vec<Point<T>>, indices: Vec<[u32; 4]>) -> Self {
+        SharedShape(Arc::new(TetMesh::new(vertices, indices)))
+    }
+
+    /// Initializes a voxels shape defined by the integer keys of its filled voxels.
+    ///
+    /// The voxel with key `k` is a cube of size `voxel_size` centered at `origin + k * voxel_size`.
+    #[cfg(feature = "dim3")]
+    pub fn voxels(origin: Point<T>, voxel_size: T, keys: &[Point<i32>]) -> Self {
+        SharedShape(Arc::new(Voxels::new(origin, voxel_size, keys)))
+    }
+
+    /// Initializes a voxels shape from a dense occupancy grid.
+    ///
+    /// See [`Voxels::from_occupancy`] for details on the layout of the grid.
+    #[cfg(feature = "dim3")]
+    pub fn voxels_from_occupancy(
+        origin: Point<T>,
+        voxel_size: T,
+        dims: [u32; 3],
+        occupancy: &[bool],
+    ) -> Self {
+        SharedShape(Arc::new(Voxels::from_occupancy(
+            origin, voxel_size, dims, occupancy,
+        )))
+    }
 }
 
 #[cfg(feature = "serde-serialize")]
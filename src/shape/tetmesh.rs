@@ -0,0 +1,285 @@
+//! Definition of the tetrahedral mesh shape.
+
+use crate::bounding_volume::Aabb;
+use crate::math::{Isometry, Point};
+use crate::partitioning::{Qbvh, QbvhUpdateWorkspace};
+use crate::shape::composite_shape::SimdCompositeShape;
+use crate::shape::{Shape, Tetrahedron, TriMesh, TypedSimdCompositeShape};
+use crate::utils::hashmap::{Entry, HashMap};
+use crate::utils::DefaultStorage;
+use ad_trait::AD;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+/// A volumetric mesh made of tetrahedra.
+///
+/// The faces of each tetrahedron are numbered as in [`Tetrahedron::face`]. Two tetrahedra
+/// sharing a face (with the same three vertex indices) are considered adjacent. The faces
+/// without any adjacent tetrahedron form the boundary surface of the mesh.
+pub struct TetMesh<T: AD> {
+    qbvh: Qbvh<u32, T>,
+    vertices: Vec<Point<T>>,
+    indices: Vec<[u32; 4]>,
+    adjacency: Vec<[u32; 4]>,
+}
+
+impl<T: AD> TetMesh<T> {
+    /// Marker of a tetrahedron face without any adjacent tetrahedron.
+    const NO_ADJACENT_TETRAHEDRON: u32 = u32::MAX;
+
+    /// Creates a new tetrahedral mesh from a vertex buffer and an index buffer.
+    ///
+    /// The tetrahedra may have any orientation. If a face is shared by more than two
+    /// tetrahedra, only the first two are considered adjacent.
+    pub fn new(vertices: Vec<Point<T>>, indices: Vec<[u32; 4]>) -> Self {
+        let data = indices.iter().enumerate().map(|(i, idx)| {
+            let aabb = Tetrahedron::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+                vertices[idx[3] as usize],
+            )
+            .local_aabb();
+            (i as u32, aabb)
+        });
+
+        let mut qbvh = Qbvh::new();
+        // NOTE: we apply no dilation factor because the tree is
+        //       refitted exactly when the vertices are modified.
+        qbvh.clear_and_rebuild(data, T::zero());
+
+        let adjacency = Self::compute_adjacency(&indices);
+
+        Self {
+            qbvh,
+            vertices,
+            indices,
+            adjacency,
+        }
+    }
+
+    fn compute_adjacency(indices: &[[u32; 4]]) -> Vec<[u32; 4]> {
+        let mut adjacency = vec![[Self::NO_ADJACENT_TETRAHEDRON; 4]; indices.len()];
+        let mut faces: HashMap<[u32; 3], (u32, u32)> = HashMap::default();
+
+        for (tet_id, idx) in indices.iter().enumerate() {
+            for face_id in 0..4 {
+                let (i1, i2, i3) = Tetrahedron::<T>::face_ids(face_id);
+                let mut key = [idx[i1 as usize], idx[i2 as usize], idx[i3 as usize]];
+                key.sort_unstable();
+
+                match faces.entry(key) {
+                    Entry::Vacant(e) => {
+                        let _ = e.insert((tet_id as u32, face_id));
+                    }
+                    Entry::Occupied(e) => {
+                        let (other_tet, other_face) = *e.get();
+
+                        if adjacency[other_tet as usize][other_face as usize]
+                            == Self::NO_ADJACENT_TETRAHEDRON
+                        {
+                            adjacency[other_tet as usize][other_face as usize] = tet_id as u32;
+                            adjacency[tet_id][face_id as usize] = other_tet;
+                        }
+                    }
+                }
+            }
+        }
+
+        adjacency
+    }
+
+    /// Compute the axis-aligned bounding box of this tetrahedral mesh.
+    pub fn aabb(&self, pos: &Isometry<T>) -> Aabb<T> {
+        self.qbvh.root_aabb().transform_by(pos)
+    }
+
+    /// Gets the local axis-aligned bounding box of this tetrahedral mesh.
+    pub fn local_aabb(&self) -> &Aabb<T> {
+        self.qbvh.root_aabb()
+    }
+
+    /// The acceleration structure used by this tetrahedral mesh.
+    pub fn qbvh(&self) -> &Qbvh<u32, T> {
+        &self.qbvh
+    }
+
+    /// The number of tetrahedra forming this mesh.
+    pub fn num_tetrahedra(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// An iterator through all the tetrahedra of this mesh.
+    pub fn tetrahedra(&self) -> impl ExactSizeIterator<Item = Tetrahedron<T>> + '_ {
+        (0..self.indices.len() as u32).map(move |i| self.tetrahedron(i))
+    }
+
+    /// Get the `i`-th tetrahedron of this mesh.
+    pub fn tetrahedron(&self, i: u32) -> Tetrahedron<T> {
+        let idx = self.indices[i as usize];
+        Tetrahedron::new(
+            self.vertices[idx[0] as usize],
+            self.vertices[idx[1] as usize],
+            self.vertices[idx[2] as usize],
+            self.vertices[idx[3] as usize],
+        )
+    }
+
+    /// The vertex buffer of this mesh.
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices[..]
+    }
+
+    /// The index buffer of this mesh.
+    pub fn indices(&self) -> &[[u32; 4]] {
+        &self.indices
+    }
+
+    /// The tetrahedron sharing the `face`-th face of the `tetrahedron`-th tetrahedron, if any.
+    ///
+    /// Returns `None` if this face is part of the boundary of this mesh.
+    pub fn adjacent_tetrahedron(&self, tetrahedron: u32, face: u32) -> Option<u32> {
+        let adj = self.adjacency[tetrahedron as usize][face as usize];
+        (adj != Self::NO_ADJACENT_TETRAHEDRON).then_some(adj)
+    }
+
+    /// Replaces the vertex buffer of this mesh, e.g., after a deformation.
+    ///
+    /// The topology of the mesh is kept unchanged and its acceleration structure is refitted
+    /// to the new vertex positions.
+    ///
+    /// # Panics
+    /// Panics if `vertices` doesn’t have the same length as the current vertex buffer.
+    pub fn set_vertices(&mut self, vertices: Vec<Point<T>>) {
+        assert_eq!(
+            vertices.len(),
+            self.vertices.len(),
+            "The number of vertices of a tetrahedral mesh cannot be modified."
+        );
+        self.vertices = vertices;
+
+        for i in 0..self.indices.len() as u32 {
+            self.qbvh.pre_update_or_insert(i);
+        }
+
+        let vertices = &self.vertices;
+        let indices = &self.indices;
+        let mut workspace = QbvhUpdateWorkspace::default();
+        let _ = self.qbvh.refit(T::zero(), &mut workspace, |i| {
+            let idx = indices[*i as usize];
+            Tetrahedron::new(
+                vertices[idx[0] as usize],
+                vertices[idx[1] as usize],
+                vertices[idx[2] as usize],
+                vertices[idx[3] as usize],
+            )
+            .local_aabb()
+        });
+    }
+
+    /// The faces of this mesh that are not shared by two tetrahedra.
+    ///
+    /// Each face is returned as the indices of its three vertices, ordered so
+    /// that the face normal points toward the exterior of the mesh.
+    pub fn boundary_faces(&self) -> Vec<[u32; 3]> {
+        let mut faces = vec![];
+
+        for (tet_id, idx) in self.indices.iter().enumerate() {
+            let tetrahedron = self.tetrahedron(tet_id as u32);
+
+            for face_id in 0..4 {
+                if self.adjacency[tet_id][face_id as usize] != Self::NO_ADJACENT_TETRAHEDRON {
+                    continue;
+                }
+
+                let (i1, i2, i3) = Tetrahedron::<T>::face_ids(face_id);
+                let face = [idx[i1 as usize], idx[i2 as usize], idx[i3 as usize]];
+                let (a, b, c) = (
+                    tetrahedron.vertex(i1),
+                    tetrahedron.vertex(i2),
+                    tetrahedron.vertex(i3),
+                );
+                let opposite = tetrahedron.vertex(6 - i1 - i2 - i3);
+
+                if (b - a).cross(&(c - a)).dot(&(opposite - a)) > T::zero() {
+                    faces.push([face[0], face[2], face[1]]);
+                } else {
+                    faces.push(face);
+                }
+            }
+        }
+
+        faces
+    }
+
+    /// Extracts the boundary surface of this mesh as a triangle mesh.
+    ///
+    /// The resulting triangle mesh only contains the vertices of this mesh that are part
+    /// of its boundary, and its triangles are oriented toward the exterior of the mesh.
+    pub fn to_trimesh(&self) -> TriMesh<T> {
+        let mut faces = self.boundary_faces();
+        let mut new_ids = vec![u32::MAX; self.vertices.len()];
+        let mut vertices = vec![];
+
+        for face in &mut faces {
+            for id in face.iter_mut() {
+                if new_ids[*id as usize] == u32::MAX {
+                    new_ids[*id as usize] = vertices.len() as u32;
+                    vertices.push(self.vertices[*id as usize]);
+                }
+
+                *id = new_ids[*id as usize];
+            }
+        }
+
+        TriMesh::new(vertices, faces)
+    }
+
+    /// The total volume of this mesh.
+    pub fn volume(&self) -> T {
+        self.tetrahedra()
+            .fold(T::zero(), |acc, tetrahedron| acc + tetrahedron.volume())
+    }
+}
+
+impl<T: AD> SimdCompositeShape<T> for TetMesh<T> {
+    fn map_part_at(&self, i: u32, f: &mut dyn FnMut(Option<&Isometry<T>>, &dyn Shape<T>)) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron)
+    }
+
+    fn qbvh(&self) -> &Qbvh<u32, T> {
+        &self.qbvh
+    }
+}
+
+impl<T: AD> TypedSimdCompositeShape<T> for TetMesh<T> {
+    type PartShape = Tetrahedron<T>;
+    type PartId = u32;
+    type QbvhStorage = DefaultStorage;
+
+    #[inline(always)]
+    fn map_typed_part_at(
+        &self,
+        i: u32,
+        mut f: impl FnMut(Option<&Isometry<T>>, &Self::PartShape),
+    ) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron)
+    }
+
+    #[inline(always)]
+    fn map_untyped_part_at(&self, i: u32, mut f: impl FnMut(Option<&Isometry<T>>, &dyn Shape<T>)) {
+        let tetrahedron = self.tetrahedron(i);
+        f(None, &tetrahedron)
+    }
+
+    fn typed_qbvh(&self) -> &Qbvh<u32, T> {
+        &self.qbvh
+    }
+}
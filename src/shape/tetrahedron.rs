@@ -1,9 +1,9 @@
 //! Definition of the tetrahedron shape.
 
-use crate::math::{Matrix, Point};
-use crate::shape::{Segment, Triangle};
+use crate::math::{Isometry, Matrix, Point, Vector};
+use crate::shape::{Segment, SupportMap, Triangle};
 use crate::utils;
-use na::Matrix3;
+use na::{Matrix3, Unit};
 use std::mem;
 
 use ad_trait::AD;
@@ -117,6 +117,23 @@ impl<T: AD> Tetrahedron<T> {
         unsafe { mem::transmute(arr) }
     }
 
+    /// Returns a new tetrahedron with vertices transformed by `m`.
+    #[inline]
+    pub fn transformed(&self, m: &Isometry<T>) -> Self {
+        Tetrahedron::new(m * self.a, m * self.b, m * self.c, m * self.d)
+    }
+
+    /// Returns the i-th vertex of this tetrahedron.
+    pub fn vertex(&self, i: u32) -> Point<T> {
+        match i {
+            0 => self.a,
+            1 => self.b,
+            2 => self.c,
+            3 => self.d,
+            _ => panic!("Tetrahedron vertex index out of bounds (must be < 4)."),
+        }
+    }
+
     /// Returns the i-th face of this tetrahedron.
     ///
     /// The 0-th face is the triangle ABC.
@@ -149,6 +166,24 @@ impl<T: AD> Tetrahedron<T> {
         }
     }
 
+    /// The outward unit normal of the i-th face of this tetrahedron.
+    ///
+    /// The normal points away from the vertex that isn't part of the face, independently
+    /// from the orientation of the tetrahedron. Returns `None` if the face is degenerate.
+    pub fn face_normal(&self, i: u32) -> Option<Unit<Vector<T>>> {
+        let (ia, ib, ic) = Self::face_ids(i);
+        let a = self.vertex(ia);
+        let normal = (self.vertex(ib) - a).cross(&(self.vertex(ic) - a));
+        // The sum of all the vertex indices is 6.
+        let opposite = self.vertex(6 - ia - ib - ic);
+
+        if normal.dot(&(opposite - a)) > T::zero() {
+            Unit::try_new(-normal, T::zero())
+        } else {
+            Unit::try_new(normal, T::zero())
+        }
+    }
+
     /// Returns the i-th edge of this tetrahedron.
     ///
     /// The 0-st edge is the segment AB.
@@ -238,3 +273,22 @@ impl<T: AD> Tetrahedron<T> {
         utils::center(&[self.a, self.b, self.c, self.d])
     }
 }
+
+impl<T: AD> SupportMap<T> for Tetrahedron<T> {
+    #[inline]
+    fn local_support_point(&self, dir: &Vector<T>) -> Point<T> {
+        let mut best = self.a;
+        let mut best_dot = self.a.coords.dot(dir);
+
+        for pt in [self.b, self.c, self.d] {
+            let dot = pt.coords.dot(dir);
+
+            if dot > best_dot {
+                best = pt;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
@@ -7,7 +7,10 @@ use crate::shape::{FeatureId, Shape, Triangle, TypedSimdCompositeShape};
 
 use crate::utils::{Array1, DefaultStorage, HashablePartialEq};
 #[cfg(feature = "dim3")]
-use {crate::shape::Cuboid, crate::shape::HeightFieldStorage, crate::utils::SortedPair};
+use {
+    crate::math::SIMD_WIDTH, crate::shape::Cuboid, crate::shape::HeightFieldStorage,
+    crate::utils::SortedPair,
+};
 
 #[cfg(feature = "std")]
 use {
@@ -104,6 +107,55 @@ impl TriMeshPseudoNormals<DefaultStorage> {
     }
 }
 
+/// The data needed for the fast approximation of the generalized winding number of a triangle mesh.
+///
+/// This stores, for each lane of each node of the mesh’s Qbvh, the sum of the area-weighted
+/// normals of all the triangles it contains, and the area-weighted centroid of these triangles.
+/// Far away from a node, the contribution of its triangles to the winding number is approximated
+/// by a single dipole located at that centroid, as described in the paper:
+/// "Fast winding numbers for soups and clouds", Barill, et al.
+/// DOI: 10.1145/3197517.3201337
+#[derive(Default)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize),
+    archive(check_bytes)
+)]
+#[repr(C)] // Needed for Cuda.
+#[cfg(feature = "dim3")]
+pub struct TriMeshWindingNumbers<Storage: TriMeshStorage<T>, T: AD> {
+    /// The area-weighted normal of each lane of each Qbvh node.
+    ///
+    /// The dipole of the lane `l` of the node `n` is at index `n * SIMD_WIDTH + l`.
+    pub node_dipoles: Storage::ArrayVector,
+    /// The area-weighted centroid of the triangles of each lane of each Qbvh node.
+    ///
+    /// This is the location of the dipole with the same index in `node_dipoles`.
+    pub node_centers: Storage::ArrayPoint,
+}
+
+#[cfg(all(feature = "dim3", feature = "std", feature = "cuda"))]
+impl<T: AD> TriMeshWindingNumbers<CudaStorage, T> {
+    /// Returns the winding number data usable from within a CUDA kernel.
+    fn as_device_ptr(&self) -> TriMeshWindingNumbers<CudaStoragePtr, T> {
+        TriMeshWindingNumbers {
+            node_dipoles: self.node_dipoles.as_device_ptr(),
+            node_centers: self.node_centers.as_device_ptr(),
+        }
+    }
+}
+
+#[cfg(all(feature = "dim3", feature = "std", feature = "cuda"))]
+impl<T: AD> TriMeshWindingNumbers<DefaultStorage, T> {
+    fn to_cuda(&self) -> CudaResult<TriMeshWindingNumbers<CudaStorage, T>> {
+        Ok(TriMeshWindingNumbers {
+            node_dipoles: CudaArray1::new(&self.node_dipoles)?,
+            node_centers: CudaArray1::new(&self.node_centers)?,
+        })
+    }
+}
+
 /// The connected-components of a triangle mesh.
 #[derive(Debug)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -278,7 +330,7 @@ bitflags::bitflags! {
     #[repr(C)] // Needed for Cuda.
     #[derive(Default)]
     /// The status of the cell of an heightfield.
-    pub struct TriMeshFlags: u8 {
+    pub struct TriMeshFlags: u16 {
         /// If set, the half-edge topology of the trimesh will be computed if possible.
         const HALF_EDGE_TOPOLOGY = 0b0000_0001;
         /// If set, the half-edge topology and connected components of the trimesh will be computed if possible.
@@ -310,6 +362,20 @@ bitflags::bitflags! {
         /// vertices will be merged. It will no longer be the case in the future once we decouple
         /// the computations.
         const DELETE_DUPLICATE_TRIANGLES = 0b0100_0000;
+        /// If set, the generalized winding number of the trimesh will be used for the inside-outside
+        /// test of point queries.
+        ///
+        /// Unlike the pseudo-normals computed with `ORIENTED`, this remains robust if the mesh has
+        /// holes, flipped triangles, or non-manifold parts. The winding number is approximated
+        /// hierarchically using data precomputed on the Qbvh nodes.
+        const WINDING_NUMBERS = 0b1000_0000;
+        /// If set, the trimesh will be treated as a solid by the distance and contact queries
+        /// of the `DefaultQueryDispatcher`.
+        ///
+        /// A convex shape overlapping the interior of the mesh is then reported as penetrating
+        /// it, with its true penetration depth, even if it doesn’t touch the mesh surface. This
+        /// implies `ORIENTED`, so the mesh is expected to be closed.
+        const SOLID = 0b1_0000_0000;
     }
 }
 
@@ -356,6 +422,9 @@ pub struct GenericTriMesh<Storage: TriMeshStorage<T>, T: AD> {
     indices: Storage::ArrayIdx,
     #[cfg(feature = "dim3")]
     pub(crate) pseudo_normals: Option<TriMeshPseudoNormals<Storage, T>>,
+    #[cfg(feature = "dim3")]
+    #[cfg_attr(feature = "serde-serialize", serde(default))]
+    pub(crate) winding_numbers: Option<TriMeshWindingNumbers<Storage, T>>,
     topology: Option<TriMeshTopology<Storage, T>>,
     connected_components: Option<TriMeshConnectedComponents<Storage, T>>,
     flags: TriMeshFlags,
@@ -380,6 +449,8 @@ impl<T: AD> CudaTriMesh<T> {
             indices: self.indices.as_device_ptr(),
             #[cfg(feature = "dim3")]
             pseudo_normals: self.pseudo_normals.as_ref().map(|pn| pn.as_device_ptr()),
+            #[cfg(feature = "dim3")]
+            winding_numbers: self.winding_numbers.as_ref().map(|wn| wn.as_device_ptr()),
             topology: self.topology.as_ref().map(|topo| topo.as_device_ptr()),
             connected_components: self
                 .connected_components
@@ -410,6 +481,12 @@ impl<T: AD> TriMesh<T> {
                 .as_ref()
                 .map(|pn| pn.to_cuda())
                 .transpose()?,
+            #[cfg(feature = "dim3")]
+            winding_numbers: self
+                .winding_numbers
+                .as_ref()
+                .map(|wn| wn.to_cuda())
+                .transpose()?,
             topology: self
                 .topology
                 .as_ref()
@@ -441,6 +518,8 @@ impl<T: AD> TriMesh<T> {
             indices,
             #[cfg(feature = "dim3")]
             pseudo_normals: None,
+            #[cfg(feature = "dim3")]
+            winding_numbers: None,
             topology: None,
             connected_components: None,
             flags: TriMeshFlags::empty(),
@@ -457,8 +536,13 @@ impl<T: AD> TriMesh<T> {
     }
 
     /// Sets the flags of this triangle mesh, controlling its optional associated data.
-    pub fn set_flags(&mut self, flags: TriMeshFlags) -> Result<(), TopologyError> {
+    pub fn set_flags(&mut self, mut flags: TriMeshFlags) -> Result<(), TopologyError> {
         let mut result = Ok(());
+
+        if flags.contains(TriMeshFlags::SOLID) {
+            flags |= TriMeshFlags::ORIENTED;
+        }
+
         let prev_indices_len = self.indices.len();
 
         if !flags.contains(TriMeshFlags::HALF_EDGE_TOPOLOGY) {
@@ -470,6 +554,11 @@ impl<T: AD> TriMesh<T> {
             self.pseudo_normals = None;
         }
 
+        #[cfg(feature = "dim3")]
+        if !flags.contains(TriMeshFlags::WINDING_NUMBERS) {
+            self.winding_numbers = None;
+        }
+
         if !flags.contains(TriMeshFlags::CONNECTED_COMPONENTS) {
             self.connected_components = None;
         }
@@ -503,11 +592,23 @@ impl<T: AD> TriMesh<T> {
             self.compute_pseudo_normals();
         }
 
-        if prev_indices_len != self.indices.len() {
+        // NOTE: the new flags must be set before rebuilding the Qbvh since `rebuild_qbvh`
+        //       reads them to decide whether to update the winding numbers data.
+        self.flags = flags;
+
+        let qbvh_rebuilt = prev_indices_len != self.indices.len();
+
+        if qbvh_rebuilt {
             self.rebuild_qbvh();
         }
 
-        self.flags = flags;
+        // NOTE: if the Qbvh was rebuilt, the winding numbers data were already updated
+        //       by `rebuild_qbvh`.
+        #[cfg(feature = "dim3")]
+        if difference.contains(TriMeshFlags::WINDING_NUMBERS) && !qbvh_rebuilt {
+            self.compute_winding_numbers();
+        }
+
         result
     }
 
@@ -556,16 +657,27 @@ impl<T: AD> TriMesh<T> {
             });
         }
 
-        Self {
+        #[allow(unused_mut)] // Needed in 3D.
+        let mut result = Self {
             qbvh: self.qbvh.scaled(scale),
             vertices: self.vertices,
             indices: self.indices,
             #[cfg(feature = "dim3")]
             pseudo_normals: self.pseudo_normals,
+            #[cfg(feature = "dim3")]
+            winding_numbers: self.winding_numbers,
             topology: self.topology,
             connected_components: self.connected_components,
             flags: self.flags,
+        };
+
+        // The dipoles are area-weighted normals, so they can’t just be scaled.
+        #[cfg(feature = "dim3")]
+        if result.winding_numbers.is_some() {
+            result.compute_winding_numbers();
         }
+
+        result
     }
 
     /// Appends a second triangle mesh to this triangle mesh.
@@ -614,6 +726,12 @@ impl<T: AD> TriMesh<T> {
         // NOTE: we apply no dilation factor because we won't
         // update this tree dynamically.
         self.qbvh.clear_and_rebuild(data, T::zero());
+
+        // The winding number data are attached to the Qbvh nodes.
+        #[cfg(feature = "dim3")]
+        if self.flags.contains(TriMeshFlags::WINDING_NUMBERS) {
+            self.compute_winding_numbers();
+        }
     }
 
     /// Reverse the orientation of the triangle mesh.
@@ -621,7 +739,7 @@ impl<T: AD> TriMesh<T> {
         self.indices.iter_mut().for_each(|idx| idx.swap(0, 1));
 
         // NOTE: the Qbvh, and connected components are not changed by this operation.
-        //       The pseudo-normals just have to be flipped.
+        //       The pseudo-normals and winding number dipoles just have to be flipped.
         //       The topology must be recomputed.
 
         #[cfg(feature = "dim3")]
@@ -637,6 +755,13 @@ impl<T: AD> TriMesh<T> {
             }
         }
 
+        #[cfg(feature = "dim3")]
+        if let Some(winding_numbers) = &mut self.winding_numbers {
+            for n in &mut winding_numbers.node_dipoles {
+                *n = -*n;
+            }
+        }
+
         if self.flags.contains(TriMeshFlags::HALF_EDGE_TOPOLOGY) {
             // TODO: this could be done more efficiently.
             let _ = self.compute_topology(false, false);
@@ -809,6 +934,75 @@ impl<T: AD> TriMesh<T> {
         })
     }
 
+    #[cfg(feature = "dim3")]
+    /// Computes the Qbvh node dipoles used for the fast approximation of the winding number.
+    ///
+    /// This must be called whenever the Qbvh is rebuilt.
+    fn compute_winding_numbers(&mut self) {
+        let num_lanes = self.qbvh.raw_nodes().len() * SIMD_WIDTH;
+        let mut node_dipoles = vec![Vector::zeros(); num_lanes];
+        let mut node_centers = vec![Point::origin(); num_lanes];
+
+        if num_lanes != 0 {
+            let _ = self.accumulate_node_dipoles(0, &mut node_dipoles, &mut node_centers);
+        }
+
+        self.winding_numbers = Some(TriMeshWindingNumbers {
+            node_dipoles,
+            node_centers,
+        })
+    }
+
+    /// Computes the dipoles and their centers for the lanes of the given node.
+    ///
+    /// Returns the sum of the dipoles of the node, the sum of the centroids of its triangles
+    /// weighted by their areas, and the sum of these areas.
+    #[cfg(feature = "dim3")]
+    fn accumulate_node_dipoles(
+        &self,
+        node_id: u32,
+        node_dipoles: &mut [Vector<T>],
+        node_centers: &mut [Point<T>],
+    ) -> (Vector<T>, Vector<T>, T) {
+        let nodes = self.qbvh.raw_nodes();
+        let proxies = self.qbvh.raw_proxies();
+        let node = &nodes[node_id as usize];
+        let mut total = (Vector::zeros(), Vector::zeros(), T::zero());
+
+        for ii in 0..SIMD_WIDTH {
+            let child = node.children[ii] as usize;
+            let (dipole, weighted_centroid, area) = if node.is_leaf() {
+                match proxies.get(child) {
+                    Some(proxy) => {
+                        let tri = self.triangle(proxy.data);
+                        let area = tri.area();
+                        let dipole = tri.scaled_normal() * T::constant(0.5);
+                        (dipole, tri.center().coords * area, area)
+                    }
+                    None => continue,
+                }
+            } else if child < nodes.len() {
+                self.accumulate_node_dipoles(child as u32, node_dipoles, node_centers)
+            } else {
+                continue;
+            };
+
+            let lane = node_id as usize * SIMD_WIDTH + ii;
+            node_dipoles[lane] = dipole;
+            node_centers[lane] = if area > T::zero() {
+                Point::from(weighted_centroid / area)
+            } else {
+                node.simd_aabb.extract(ii).center()
+            };
+
+            total.0 += dipole;
+            total.1 += weighted_centroid;
+            total.2 += area;
+        }
+
+        total
+    }
+
     fn delete_bad_topology_triangles(&mut self) {
         let mut half_edge_set = HashSet::new();
         let mut deleted_any = false;
@@ -1013,6 +1207,61 @@ impl<T: AD> TriMesh<T> {
             )
         })
     }
+
+    /// The signed distance between the local point `pt` and this mesh.
+    ///
+    /// The distance is negative if `pt` lies inside of the mesh. The inside test relies on
+    /// the pseudo-normals computed when [`TriMeshFlags::ORIENTED`] is set, or on the winding
+    /// numbers computed when [`TriMeshFlags::WINDING_NUMBERS`] is set. Returns `None` if none
+    /// of them have been computed, since the sign of the distance would be meaningless.
+    #[cfg(feature = "dim3")]
+    pub fn signed_distance(&self, pt: &Point<T>) -> Option<T> {
+        self.has_inside_test().then(|| self.signed_projection(pt).0)
+    }
+
+    /// Can this mesh tell whether a point lies inside of it?
+    #[cfg(feature = "dim3")]
+    pub(crate) fn has_inside_test(&self) -> bool {
+        self.pseudo_normals.is_some() || self.winding_numbers.is_some()
+    }
+
+    /// The signed distance between `pt` and this mesh, and the projection of `pt` on its surface.
+    #[cfg(feature = "dim3")]
+    pub(crate) fn signed_projection(&self, pt: &Point<T>) -> (T, Point<T>) {
+        use crate::query::PointQueryWithLocation;
+
+        let proj = self.project_local_point_and_get_location(pt, false).0;
+        let dist = na::distance(pt, &proj.point);
+
+        if proj.is_inside {
+            (-dist, proj.point)
+        } else {
+            (dist, proj.point)
+        }
+    }
+
+    /// The signed distances between each local point of `pts` and this mesh.
+    ///
+    /// See [`TriMesh::signed_distance`] for details.
+    #[cfg(feature = "dim3")]
+    pub fn signed_distances(&self, pts: &[Point<T>]) -> Option<Vec<T>> {
+        self.has_inside_test()
+            .then(|| pts.iter().map(|pt| self.signed_projection(pt).0).collect())
+    }
+
+    /// The signed distances between each local point of `pts` and this mesh, computed in parallel.
+    ///
+    /// This is typically used to sample the signed distance field of the mesh on a dense grid.
+    /// See [`TriMesh::signed_distance`] for details.
+    #[cfg(all(feature = "dim3", feature = "parallel"))]
+    pub fn par_signed_distances(&self, pts: &[Point<T>]) -> Option<Vec<T>>
+    where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+        self.has_inside_test()
+            .then(|| pts.par_iter().map(|pt| self.signed_projection(pt).0).collect())
+    }
 }
 
 impl<Storage: TriMeshStorage<T>, T: AD> GenericTriMesh<Storage, T> {
@@ -1085,6 +1334,102 @@ impl<Storage: TriMeshStorage<T>, T: AD> GenericTriMesh<Storage, T> {
     pub fn pseudo_normals(&self) -> Option<&TriMeshPseudoNormals<Storage, T>> {
         self.pseudo_normals.as_ref()
     }
+
+    /// The data used for approximating the winding number of this triangle mesh, if they have been computed.
+    #[cfg(feature = "dim3")]
+    pub fn winding_numbers(&self) -> Option<&TriMeshWindingNumbers<Storage, T>> {
+        self.winding_numbers.as_ref()
+    }
+
+    /// The exact generalized winding number of this triangle mesh at the local point `pt`.
+    ///
+    /// This is close to `1` for points inside of the mesh and close to `0` for points outside,
+    /// even if the mesh has holes, flipped triangles, or isn’t manifold. This sums the solid
+    /// angles of every triangle, see [`Self::fast_winding_number`] for a faster approximation.
+    #[cfg(feature = "dim3")]
+    pub fn winding_number(&self, pt: &Point<T>) -> T {
+        (0..self.indices.len())
+            .map(|i| triangle_winding_number(&self.triangle(i as u32), pt))
+            .fold(T::zero(), |acc, w| acc + w)
+    }
+
+    /// Approximates the generalized winding number of this triangle mesh at the local point `pt`.
+    ///
+    /// This relies on the data computed when the [`TriMeshFlags::WINDING_NUMBERS`] flag is set: the
+    /// contribution of any Qbvh node further than `2` times its radius from `pt` is approximated
+    /// by its dipole. If these data are not available, this computes the exact winding number.
+    #[cfg(feature = "dim3")]
+    pub fn fast_winding_number(&self, pt: &Point<T>) -> T {
+        self.fast_winding_number_with_accuracy(pt, T::constant(2.0))
+    }
+
+    /// Approximates the generalized winding number of this triangle mesh at the local point `pt`.
+    ///
+    /// The contribution of any Qbvh node further than `accuracy` times its radius from `pt` is
+    /// approximated by its dipole. Greater values of `accuracy` are slower but more accurate.
+    #[cfg(feature = "dim3")]
+    pub fn fast_winding_number_with_accuracy(&self, pt: &Point<T>, accuracy: T) -> T {
+        match &self.winding_numbers {
+            Some(winding_numbers) if !self.qbvh.raw_nodes_storage().is_empty() => {
+                self.fast_winding_number_node(winding_numbers, 0, pt, accuracy)
+            }
+            Some(_) => T::zero(),
+            None => self.winding_number(pt),
+        }
+    }
+
+    /// The approximate winding number at `pt` of the triangles of the given Qbvh node.
+    ///
+    /// This recurses instead of using an explicit stack so it doesn’t allocate.
+    #[cfg(feature = "dim3")]
+    fn fast_winding_number_node(
+        &self,
+        winding_numbers: &TriMeshWindingNumbers<Storage, T>,
+        node_id: u32,
+        pt: &Point<T>,
+        accuracy: T,
+    ) -> T {
+        let nodes = self.qbvh.raw_nodes_storage();
+        let proxies = self.qbvh.raw_proxies_storage();
+        let node = &nodes[node_id as usize];
+        let four_pi = T::two_pi() * T::constant(2.0);
+        let mut result = T::zero();
+
+        for ii in 0..SIMD_WIDTH {
+            let child = node.children[ii] as usize;
+            let is_leaf = node.is_leaf();
+
+            if (is_leaf && child >= proxies.len()) || (!is_leaf && child >= nodes.len()) {
+                continue;
+            }
+
+            let lane = node_id as usize * SIMD_WIDTH + ii;
+            let center = winding_numbers.node_centers[lane];
+            let aabb = node.simd_aabb.extract(ii);
+            // Every triangle of the lane is within this radius of the dipole center.
+            let radius = (aabb.center() - center).norm() + aabb.half_extents().norm();
+            let dpt = center - pt;
+            let dist = dpt.norm();
+
+            if dist > radius * accuracy {
+                let dipole = winding_numbers.node_dipoles[lane];
+                result += dipole.dot(&dpt) / (four_pi * dist * dist * dist);
+            } else if is_leaf {
+                let tri = self.triangle(proxies[child].data);
+                result += triangle_winding_number(&tri, pt);
+            } else {
+                result += self.fast_winding_number_node(winding_numbers, child as u32, pt, accuracy);
+            }
+        }
+
+        result
+    }
+
+    /// Tests if the local point `pt` is inside of this mesh, based on its generalized winding number.
+    #[cfg(feature = "dim3")]
+    pub fn contains_local_point_by_winding_number(&self, pt: &Point<T>) -> bool {
+        self.fast_winding_number(pt) > T::constant(0.5)
+    }
 }
 
 /*
@@ -1190,6 +1535,23 @@ impl<Storage: TriMeshStorage<T>, T: AD> TypedSimdCompositeShape<T> for GenericTr
     }
 }
 
+/// The solid angle of the triangle seen from `pt`, divided by `4π`.
+///
+/// This uses the formula from "The solid angle of a plane triangle", Van Oosterom, et al.
+/// DOI: 10.1109/TBME.1983.325207
+#[cfg(feature = "dim3")]
+fn triangle_winding_number<T: AD>(tri: &Triangle<T>, pt: &Point<T>) -> T {
+    let a = tri.a - pt;
+    let b = tri.b - pt;
+    let c = tri.c - pt;
+    let (la, lb, lc) = (a.norm(), b.norm(), c.norm());
+
+    let det = a.dot(&b.cross(&c));
+    let denom = la * lb * lc + a.dot(&b) * lc + b.dot(&c) * la + c.dot(&a) * lb;
+
+    det.atan2(denom) / T::two_pi()
+}
+
 /*******************************************
  *
  * BOILERPLACE Copy/Clone implementations
@@ -1230,6 +1592,40 @@ where
 {
 }
 
+#[cfg(feature = "dim3")]
+impl<Storage, T: AD> Clone for TriMeshWindingNumbers<Storage, T>
+where
+    Storage: TriMeshStorage<T>,
+    Storage::ArrayVector: Clone,
+    Storage::ArrayPoint: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            node_dipoles: self.node_dipoles.clone(),
+            node_centers: self.node_centers.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl<Storage, T: AD> Copy for TriMeshWindingNumbers<Storage, T>
+where
+    Storage: TriMeshStorage<T>,
+    Storage::ArrayVector: Copy,
+    Storage::ArrayPoint: Copy,
+{
+}
+
+#[cfg(feature = "dim3")]
+#[cfg(feature = "cuda")]
+unsafe impl<Storage, T: AD> cust_core::DeviceCopy for TriMeshWindingNumbers<Storage, T>
+where
+    Storage: TriMeshStorage<T>,
+    Storage::ArrayVector: cust_core::DeviceCopy + Copy,
+    Storage::ArrayPoint: cust_core::DeviceCopy + Copy,
+{
+}
+
 impl<Storage, T: AD> Clone for TriMeshConnectedComponents<Storage, T>
 where
     Storage: TriMeshStorage<T>,
@@ -1320,6 +1716,8 @@ where
             indices: self.indices.clone(),
             #[cfg(feature = "dim3")]
             pseudo_normals: self.pseudo_normals.clone(),
+            #[cfg(feature = "dim3")]
+            winding_numbers: self.winding_numbers.clone(),
             topology: self.topology.clone(),
             connected_components: self.connected_components.clone(),
             flags: self.flags.clone(),
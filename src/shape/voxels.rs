@@ -0,0 +1,511 @@
+use crate::bounding_volume::Aabb;
+use crate::math::{Point, Vector};
+use crate::shape::Cuboid;
+use crate::transformation::voxelization::{VoxelSet, VoxelValue, VoxelizedVolume};
+use ad_trait::AD;
+
+/// A shape made of cubic voxels of identical size, laid out on a regular grid.
+///
+/// Each voxel is identified by its integer grid coordinates (its "key"). The voxel with
+/// key `k` is centered at `origin + k * voxel_size` and extends by `voxel_size / 2` on each
+/// side of its center, which matches the convention used by [`VoxelSet`] and [`VoxelizedVolume`].
+///
+/// The occupancy of the voxels is stored densely over an axis-aligned domain of keys that is
+/// automatically enlarged when a voxel is inserted outside of it. The identifier of a voxel
+/// (used as sub-shape index and feature index by the geometric queries) is its linear index
+/// within that domain, so identifiers are invalidated whenever the domain grows.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Voxels<T: AD> {
+    origin: Point<T>,
+    voxel_size: T,
+    domain_mins: Point<i32>,
+    domain_dims: [u32; 3],
+    filled: Vec<bool>,
+    num_filled: usize,
+    // Smallest and largest keys of the filled voxels (inclusive).
+    filled_mins: Point<i32>,
+    filled_maxs: Point<i32>,
+}
+
+impl<T: AD> Voxels<T> {
+    /// Initializes a voxel shape from the keys of its filled voxels.
+    ///
+    /// The voxel with key `k` is centered at `origin + k * voxel_size`.
+    pub fn new(origin: Point<T>, voxel_size: T, keys: &[Point<i32>]) -> Self {
+        assert!(
+            voxel_size > T::zero(),
+            "The size of a voxel must be strictly positive."
+        );
+
+        let mut domain_mins = Point::new(i32::MAX, i32::MAX, i32::MAX);
+        let mut domain_maxs = Point::new(i32::MIN, i32::MIN, i32::MIN);
+
+        for key in keys {
+            domain_mins = domain_mins.inf(key);
+            domain_maxs = domain_maxs.sup(key);
+        }
+
+        let mut result = Self::empty(origin, voxel_size);
+
+        if !keys.is_empty() {
+            result.resize_domain(domain_mins, domain_maxs);
+
+            for key in keys {
+                let _ = result.insert_voxel(*key);
+            }
+        }
+
+        result
+    }
+
+    /// Initializes a voxel shape from a dense occupancy grid.
+    ///
+    /// The grid has `dims[0] * dims[1] * dims[2]` cells and the occupancy of the cell with
+    /// key `(i, j, k)` is given by `occupancy[i + j * dims[0] + k * dims[0] * dims[1]]`.
+    pub fn from_occupancy(
+        origin: Point<T>,
+        voxel_size: T,
+        dims: [u32; 3],
+        occupancy: &[bool],
+    ) -> Self {
+        assert_eq!(
+            occupancy.len(),
+            dims[0] as usize * dims[1] as usize * dims[2] as usize,
+            "The occupancy grid length must match its dimensions."
+        );
+        assert!(
+            voxel_size > T::zero(),
+            "The size of a voxel must be strictly positive."
+        );
+
+        let mut result = Self {
+            origin,
+            voxel_size,
+            domain_mins: Point::origin(),
+            domain_dims: dims,
+            filled: occupancy.to_vec(),
+            num_filled: 0,
+            filled_mins: Point::origin(),
+            filled_maxs: Point::origin(),
+        };
+        result.recompute_bounds();
+        result
+    }
+
+    /// Initializes a voxel shape from the voxels of a voxel set.
+    ///
+    /// All the voxels of the set are considered filled, including the ones marked as being
+    /// on the surface of the voxelized volume.
+    pub fn from_voxel_set(voxel_set: &VoxelSet<T>) -> Self {
+        let keys: Vec<_> = voxel_set
+            .voxels()
+            .iter()
+            .map(|voxel| voxel.coords.map(|e| e as i32))
+            .collect();
+        Self::new(voxel_set.origin, voxel_set.scale, &keys)
+    }
+
+    /// Initializes a voxel shape from a voxelized volume.
+    ///
+    /// The voxels marked as [`VoxelValue::PrimitiveInsideSurface`] or
+    /// [`VoxelValue::PrimitiveOnSurface`] are considered filled.
+    pub fn from_voxelized_volume(volume: &VoxelizedVolume<T>) -> Self {
+        let resolution = volume.resolution();
+        let mut occupancy = Vec::with_capacity(
+            resolution[0] as usize * resolution[1] as usize * resolution[2] as usize,
+        );
+
+        for k in 0..resolution[2] {
+            for j in 0..resolution[1] {
+                for i in 0..resolution[0] {
+                    let value = volume.voxel(i, j, k);
+                    occupancy.push(
+                        value == VoxelValue::PrimitiveInsideSurface
+                            || value == VoxelValue::PrimitiveOnSurface,
+                    );
+                }
+            }
+        }
+
+        Self::from_occupancy(volume.origin(), volume.scale(), resolution, &occupancy)
+    }
+
+    fn empty(origin: Point<T>, voxel_size: T) -> Self {
+        Self {
+            origin,
+            voxel_size,
+            domain_mins: Point::origin(),
+            domain_dims: [0; 3],
+            filled: Vec::new(),
+            num_filled: 0,
+            filled_mins: Point::origin(),
+            filled_maxs: Point::origin(),
+        }
+    }
+
+    /// The center of the voxel with key `(0, 0, 0)`.
+    pub fn origin(&self) -> &Point<T> {
+        &self.origin
+    }
+
+    /// The length of the edges of each voxel.
+    pub fn voxel_size(&self) -> T {
+        self.voxel_size
+    }
+
+    /// The number of filled voxels.
+    pub fn num_voxels(&self) -> usize {
+        self.num_filled
+    }
+
+    /// Does this shape contain no filled voxel at all?
+    pub fn is_empty(&self) -> bool {
+        self.num_filled == 0
+    }
+
+    /// The smallest key and the number of voxels along each axis of the domain of this shape.
+    pub fn domain(&self) -> (Point<i32>, [u32; 3]) {
+        (self.domain_mins, self.domain_dims)
+    }
+
+    /// The smallest and largest keys of the filled voxels, or `None` if there are none.
+    pub fn filled_keys_bounds(&self) -> Option<(Point<i32>, Point<i32>)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.filled_mins, self.filled_maxs))
+        }
+    }
+
+    /// The cuboid matching the geometry of a single voxel, centered at the origin.
+    pub fn voxel_cuboid(&self) -> Cuboid<T> {
+        Cuboid::new(Vector::repeat(self.voxel_size * T::constant(0.5)))
+    }
+
+    /// The center of the voxel with the given key.
+    pub fn voxel_center(&self, key: &Point<i32>) -> Point<T> {
+        let key = Vector::new(
+            T::constant(key.x as f64),
+            T::constant(key.y as f64),
+            T::constant(key.z as f64),
+        );
+        self.origin + key * self.voxel_size
+    }
+
+    /// The local-space Aabb of the voxel with the given key.
+    pub fn voxel_aabb(&self, key: &Point<i32>) -> Aabb<T> {
+        Aabb::from_half_extents(
+            self.voxel_center(key),
+            Vector::repeat(self.voxel_size * T::constant(0.5)),
+        )
+    }
+
+    /// The key of the voxel containing the given local-space point.
+    ///
+    /// The returned voxel isn't necessarily filled, nor part of the domain of this shape.
+    pub fn voxel_key_at_point(&self, pt: &Point<T>) -> Point<i32> {
+        let scaled = (pt - self.origin) / self.voxel_size;
+        scaled
+            .map(|e| (e + T::constant(0.5)).floor().to_constant() as i32)
+            .into()
+    }
+
+    /// The identifier of the voxel with the given key, if it is part of the domain of this shape.
+    pub fn voxel_id(&self, key: &Point<i32>) -> Option<u32> {
+        let local = key - self.domain_mins;
+
+        if local.x < 0
+            || local.y < 0
+            || local.z < 0
+            || local.x as u32 >= self.domain_dims[0]
+            || local.y as u32 >= self.domain_dims[1]
+            || local.z as u32 >= self.domain_dims[2]
+        {
+            return None;
+        }
+
+        Some(
+            local.x as u32
+                + local.y as u32 * self.domain_dims[0]
+                + local.z as u32 * self.domain_dims[0] * self.domain_dims[1],
+        )
+    }
+
+    /// The key of the voxel with the given identifier.
+    pub fn voxel_key(&self, id: u32) -> Point<i32> {
+        let nxy = self.domain_dims[0] * self.domain_dims[1];
+        let k = id / nxy;
+        let j = (id - k * nxy) / self.domain_dims[0];
+        let i = id - k * nxy - j * self.domain_dims[0];
+        self.domain_mins + Vector::new(i as i32, j as i32, k as i32)
+    }
+
+    /// Is the voxel with the given key filled?
+    pub fn is_voxel_filled(&self, key: &Point<i32>) -> bool {
+        self.voxel_id(key)
+            .map(|id| self.filled[id as usize])
+            .unwrap_or(false)
+    }
+
+    /// An iterator through the keys of all the filled voxels.
+    pub fn voxels<'a>(&'a self) -> impl Iterator<Item = Point<i32>> + 'a {
+        self.filled
+            .iter()
+            .enumerate()
+            .filter(|(_, filled)| **filled)
+            .map(move |(id, _)| self.voxel_key(id as u32))
+    }
+
+    /// Marks the voxel with the given key as filled.
+    ///
+    /// The domain of this shape is enlarged if needed. Returns `true` if the voxel
+    /// was empty before this call.
+    pub fn insert_voxel(&mut self, key: Point<i32>) -> bool {
+        if self.voxel_id(&key).is_none() {
+            self.grow_domain_to_include(&key);
+        }
+
+        let id = self.voxel_id(&key).unwrap() as usize;
+
+        if self.filled[id] {
+            return false;
+        }
+
+        self.filled[id] = true;
+
+        if self.num_filled == 0 {
+            self.filled_mins = key;
+            self.filled_maxs = key;
+        } else {
+            self.filled_mins = self.filled_mins.inf(&key);
+            self.filled_maxs = self.filled_maxs.sup(&key);
+        }
+
+        self.num_filled += 1;
+        true
+    }
+
+    /// Marks the voxel with the given key as empty.
+    ///
+    /// Returns `true` if the voxel was filled before this call.
+    pub fn remove_voxel(&mut self, key: Point<i32>) -> bool {
+        let id = match self.voxel_id(&key) {
+            Some(id) => id as usize,
+            None => return false,
+        };
+
+        if !self.filled[id] {
+            return false;
+        }
+
+        self.filled[id] = false;
+        self.num_filled -= 1;
+
+        // The bounds only need to be recomputed if the removed voxel was touching them.
+        if (0..3).any(|i| key[i] == self.filled_mins[i] || key[i] == self.filled_maxs[i]) {
+            self.recompute_bounds();
+        }
+
+        true
+    }
+
+    /// Marks the voxel with the given key as filled or empty.
+    ///
+    /// Returns `true` if the state of the voxel changed.
+    pub fn set_voxel(&mut self, key: Point<i32>, filled: bool) -> bool {
+        if filled {
+            self.insert_voxel(key)
+        } else {
+            self.remove_voxel(key)
+        }
+    }
+
+    /// The local-space Aabb of all the filled voxels.
+    ///
+    /// This is a degenerate Aabb located at the origin if there are no filled voxels.
+    pub fn root_aabb(&self) -> Aabb<T> {
+        if self.is_empty() {
+            Aabb::new(self.origin, self.origin)
+        } else {
+            let mins = self.voxel_aabb(&self.filled_mins).mins;
+            let maxs = self.voxel_aabb(&self.filled_maxs).maxs;
+            Aabb::new(mins, maxs)
+        }
+    }
+
+    /// The range of keys (inclusive) of all the voxels, filled or not, intersecting the given local Aabb.
+    pub fn voxel_keys_range_in_local_aabb(&self, aabb: &Aabb<T>) -> (Point<i32>, Point<i32>) {
+        let half = Vector::repeat(self.voxel_size * T::constant(0.5));
+        let mins = ((aabb.mins - self.origin) + half) / self.voxel_size;
+        let maxs = ((aabb.maxs - self.origin) + half) / self.voxel_size;
+        (
+            mins.map(|e| e.floor().to_constant() as i32).into(),
+            maxs.map(|e| e.floor().to_constant() as i32).into(),
+        )
+    }
+
+    /// Applies the function `f` to all the filled voxels intersecting the given local Aabb.
+    ///
+    /// The function is given the identifier and the center of each voxel.
+    pub fn map_elements_in_local_aabb(&self, aabb: &Aabb<T>, f: &mut impl FnMut(u32, &Point<T>)) {
+        if self.is_empty() {
+            return;
+        }
+
+        let (mins, maxs) = self.voxel_keys_range_in_local_aabb(aabb);
+        let mins = mins.sup(&self.filled_mins);
+        let maxs = maxs.inf(&self.filled_maxs);
+
+        for k in mins.z..=maxs.z {
+            for j in mins.y..=maxs.y {
+                for i in mins.x..=maxs.x {
+                    let key = Point::new(i, j, k);
+                    // NOTE: the key is within the filled bounds so it is always part of the domain.
+                    let id = self.voxel_id(&key).unwrap();
+
+                    if self.filled[id as usize] {
+                        f(id, &self.voxel_center(&key));
+                    }
+                }
+            }
+        }
+    }
+
+    fn grow_domain_to_include(&mut self, key: &Point<i32>) {
+        if self.filled.is_empty() {
+            self.resize_domain(*key, *key);
+            return;
+        }
+
+        let curr_maxs = self.domain_maxs();
+        let mut new_mins = self.domain_mins;
+        let mut new_maxs = curr_maxs;
+
+        // Grow by at least half the current extents to amortize incremental insertions.
+        for i in 0..3 {
+            let margin = (self.domain_dims[i] / 2).max(1) as i32;
+
+            if key[i] < new_mins[i] {
+                new_mins[i] = key[i].min(new_mins[i] - margin);
+            }
+            if key[i] > new_maxs[i] {
+                new_maxs[i] = key[i].max(new_maxs[i] + margin);
+            }
+        }
+
+        self.resize_domain(new_mins, new_maxs);
+    }
+
+    fn domain_maxs(&self) -> Point<i32> {
+        self.domain_mins
+            + Vector::new(
+                self.domain_dims[0] as i32 - 1,
+                self.domain_dims[1] as i32 - 1,
+                self.domain_dims[2] as i32 - 1,
+            )
+    }
+
+    // Sets the domain to the given range of keys (inclusive), preserving the
+    // occupancy of the voxels that remain inside of it.
+    fn resize_domain(&mut self, mins: Point<i32>, maxs: Point<i32>) {
+        let dims = [
+            (maxs.x - mins.x + 1) as u32,
+            (maxs.y - mins.y + 1) as u32,
+            (maxs.z - mins.z + 1) as u32,
+        ];
+        let mut new_filled = vec![false; dims[0] as usize * dims[1] as usize * dims[2] as usize];
+
+        for (id, filled) in self.filled.iter().enumerate() {
+            if *filled {
+                let local = self.voxel_key(id as u32) - mins;
+                let new_id = local.x as usize
+                    + local.y as usize * dims[0] as usize
+                    + local.z as usize * dims[0] as usize * dims[1] as usize;
+                new_filled[new_id] = true;
+            }
+        }
+
+        self.domain_mins = mins;
+        self.domain_dims = dims;
+        self.filled = new_filled;
+    }
+
+    fn recompute_bounds(&mut self) {
+        let mut num_filled = 0;
+        let mut mins = Point::new(i32::MAX, i32::MAX, i32::MAX);
+        let mut maxs = Point::new(i32::MIN, i32::MIN, i32::MIN);
+
+        for (id, filled) in self.filled.iter().enumerate() {
+            if *filled {
+                let key = self.voxel_key(id as u32);
+                mins = mins.inf(&key);
+                maxs = maxs.sup(&key);
+                num_filled += 1;
+            }
+        }
+
+        self.num_filled = num_filled;
+
+        if num_filled == 0 {
+            self.filled_mins = Point::origin();
+            self.filled_maxs = Point::origin();
+        } else {
+            self.filled_mins = mins;
+            self.filled_maxs = maxs;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::math::{Point, Vector};
+    use crate::query::{PointQuery, Ray, RayCast};
+    use crate::shape::Voxels;
+
+    #[test]
+    fn voxels_insert_remove() {
+        let mut voxels = Voxels::new(Point::origin(), 1.0, &[Point::new(0, 0, 0)]);
+        assert!(voxels.insert_voxel(Point::new(3, -2, 1)));
+        assert!(!voxels.insert_voxel(Point::new(3, -2, 1)));
+        assert_eq!(voxels.num_voxels(), 2);
+        assert!(voxels.is_voxel_filled(&Point::new(0, 0, 0)));
+
+        let aabb = voxels.local_aabb();
+        assert_relative_eq!(aabb.mins, Point::new(-0.5, -2.5, -0.5));
+        assert_relative_eq!(aabb.maxs, Point::new(3.5, 0.5, 1.5));
+
+        assert!(voxels.remove_voxel(Point::new(3, -2, 1)));
+        assert_eq!(voxels.num_voxels(), 1);
+        assert_relative_eq!(voxels.local_aabb().maxs, Point::new(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn voxels_ray_and_point_queries() {
+        let keys: Vec<_> = (0..4).map(|i| Point::new(i, 0, 0)).collect();
+        let voxels = Voxels::new(Point::origin(), 1.0, &keys);
+
+        let ray = Ray::new(Point::new(-5.0, 0.2, 0.1), Vector::x());
+        let hit = voxels
+            .cast_local_ray_and_get_normal(&ray, f64::MAX, true)
+            .unwrap();
+        assert_relative_eq!(hit.toi, 4.5);
+        assert_relative_eq!(hit.normal, -Vector::x());
+
+        // Starting inside, the ray exits through the last voxel.
+        let ray = Ray::new(Point::new(1.0, 0.0, 0.0), Vector::x());
+        let hit = voxels
+            .cast_local_ray_and_get_normal(&ray, f64::MAX, false)
+            .unwrap();
+        assert_relative_eq!(hit.toi, 2.5);
+        assert_relative_eq!(hit.normal, Vector::x());
+
+        let proj = voxels.project_local_point(&Point::new(1.5, 3.0, 0.0), false);
+        assert!(!proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(1.5, 0.5, 0.0));
+
+        let proj = voxels.project_local_point(&Point::new(1.0, 0.3, 0.0), false);
+        assert!(proj.is_inside);
+        assert_relative_eq!(proj.point, Point::new(1.0, 0.5, 0.0));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::shape::{Ball, MinkowskiDifference, MinkowskiSum, SupportMap};
+use crate::transformation;
+use na::Point2;
+use ad_trait::AD;
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiSum<A, B, T> {
+    /// Discretize the boundary of this Minkowski sum as a polygonal line.
+    ///
+    /// This computes the convex hull of the support points of `self` along
+    /// `nsubdivs` directions evenly distributed on the unit circle.
+    pub fn to_polyline(&self, nsubdivs: u32) -> Vec<Point2<T>> {
+        support_map_hull(self, nsubdivs)
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiDifference<A, B, T> {
+    /// Discretize the boundary of this Minkowski difference as a polygonal line.
+    ///
+    /// This computes the convex hull of the support points of `self` along
+    /// `nsubdivs` directions evenly distributed on the unit circle.
+    pub fn to_polyline(&self, nsubdivs: u32) -> Vec<Point2<T>> {
+        support_map_hull(self, nsubdivs)
+    }
+}
+
+/// The convex hull of the support points of `shape` along `nsubdivs` directions evenly
+/// distributed on the unit circle.
+pub(super) fn support_map_hull<T: AD>(shape: &impl SupportMap<T>, nsubdivs: u32) -> Vec<Point2<T>> {
+    let points: Vec<_> = Ball::new(T::one())
+        .to_polyline(nsubdivs)
+        .iter()
+        .map(|dir| shape.local_support_point(&dir.coords))
+        .collect();
+    transformation::convex_hull(&points)
+}
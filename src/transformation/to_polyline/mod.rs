@@ -2,5 +2,7 @@ mod ball_to_polyline;
 mod capsule_to_polyline;
 mod cuboid_to_polyline;
 mod heightfield_to_polyline;
+mod minkowski_sum_to_polyline;
 mod round_convex_polygon_to_polyline;
 mod round_cuboid_to_polyline;
+mod scaled_to_polyline;
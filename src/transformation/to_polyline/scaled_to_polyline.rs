@@ -0,0 +1,14 @@
+use super::minkowski_sum_to_polyline::support_map_hull;
+use crate::shape::{Scaled, SupportMap};
+use ad_trait::AD;
+use na::Point2;
+
+impl<S: SupportMap<T>, T: AD> Scaled<S, T> {
+    /// Discretize the boundary of this scaled shape as a polygonal line.
+    ///
+    /// This computes the convex hull of the support points of `self` along
+    /// `nsubdivs` directions evenly distributed on the unit circle.
+    pub fn to_polyline(&self, nsubdivs: u32) -> Vec<Point2<T>> {
+        support_map_hull(self, nsubdivs)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::shape::{Ball, MinkowskiDifference, MinkowskiSum, SupportMap};
+use crate::transformation;
+use na::Point3;
+use ad_trait::AD;
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiSum<A, B, T> {
+    /// Discretize the boundary of this Minkowski sum as a triangle-mesh.
+    ///
+    /// This computes the convex hull of the support points of `self` along the vertices of
+    /// a unit sphere discretized with `ntheta_subdiv` and `nphi_subdiv` subdivisions.
+    pub fn to_trimesh(
+        &self,
+        ntheta_subdiv: u32,
+        nphi_subdiv: u32,
+    ) -> (Vec<Point3<T>>, Vec<[u32; 3]>) {
+        support_map_hull(self, ntheta_subdiv, nphi_subdiv)
+    }
+}
+
+impl<A: SupportMap<T>, B: SupportMap<T>, T: AD> MinkowskiDifference<A, B, T> {
+    /// Discretize the boundary of this Minkowski difference as a triangle-mesh.
+    ///
+    /// This computes the convex hull of the support points of `self` along the vertices of
+    /// a unit sphere discretized with `ntheta_subdiv` and `nphi_subdiv` subdivisions.
+    pub fn to_trimesh(
+        &self,
+        ntheta_subdiv: u32,
+        nphi_subdiv: u32,
+    ) -> (Vec<Point3<T>>, Vec<[u32; 3]>) {
+        support_map_hull(self, ntheta_subdiv, nphi_subdiv)
+    }
+}
+
+fn support_map_hull<T: AD>(
+    shape: &impl SupportMap<T>,
+    ntheta_subdiv: u32,
+    nphi_subdiv: u32,
+) -> (Vec<Point3<T>>, Vec<[u32; 3]>) {
+    let (dirs, _) = Ball::new(T::one()).to_trimesh(ntheta_subdiv, nphi_subdiv);
+    let points: Vec<_> = dirs
+        .iter()
+        .map(|dir| shape.local_support_point(&dir.coords))
+        .collect();
+    transformation::convex_hull(&points)
+}
@@ -5,3 +5,4 @@ mod convex_polyhedron_to_trimesh;
 mod cuboid_to_trimesh;
 mod cylinder_to_trimesh;
 mod heightfield_to_trimesh;
+mod minkowski_sum_to_trimesh;
@@ -470,6 +470,11 @@ impl<T: AD> VoxelizedVolume<T> {
         result
     }
 
+    /// The center of the voxel with integer coordinates `(0, 0, 0)`.
+    pub fn origin(&self) -> Point<T> {
+        self.origin
+    }
+
     /// The number of voxel subdivisions along each coordinate axis.
     pub fn resolution(&self) -> [u32; DIM] {
         self.resolution